@@ -1,31 +1,87 @@
-use std::{str::FromStr, sync::Arc};
+use std::str::FromStr;
 
+use anyhow::anyhow;
 use clap::Parser;
 use jito_tip_cli::{
     cli_args::{Cli, ProgramCommand},
-    tip_distribution_handler::TipDistributionCliHandler,
+    tip_distribution_handler::{derive_config_account_address, TipDistributionCliHandler},
+    tip_payment_handler::TipPaymentCliHandler,
+    transaction::{broadcast_transaction, invokes_only, LazyKeypair, TransactionOptions},
 };
-use jito_tip_distribution_sdk_legacy::derive_config_account_address;
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use jito_tip_payment_sdk::error::TipPaymentError;
 use solana_client::rpc_client::RpcClient;
-use solana_keypair::read_keypair_file;
+use solana_commitment_config::CommitmentConfig;
 use solana_pubkey::Pubkey;
 
 fn main() -> anyhow::Result<()> {
     let args: Cli = Cli::parse();
 
     let program_id = Pubkey::from_str(&args.tip_distribution_program_id)?;
+    let tip_payment_program_id = args.tip_payment_program_id()?;
 
-    let client = RpcClient::new(args.rpc_url);
+    let commitment = match args.commitment.as_deref() {
+        Some(commitment) => CommitmentConfig::from_str(commitment)
+            .map_err(|_| anyhow!("Invalid commitment level: {commitment}"))?,
+        None => CommitmentConfig::default(),
+    };
+    let client = RpcClient::new_with_commitment(args.rpc_url, commitment);
+    let transaction_options = TransactionOptions {
+        dry_run: args.dry_run,
+        sign_only: args.sign_only,
+        dump_instruction: args.dump_instruction,
+        blockhash: args.blockhash,
+        fee_payer: args.fee_payer,
+        authority: args.authority,
+        encoding: args.encoding,
+    };
 
-    let keypair = read_keypair_file(args.keypair_path).expect("Failed to read keypair");
-    let keypair = Arc::new(keypair);
+    // Only read once a command signs, so that read commands run without a keypair.
+    let keypair = LazyKeypair::new(args.keypair_path);
 
     let (config_pda, config_bump) = derive_config_account_address(&program_id);
 
-    match args.command.expect("Command not found") {
-        ProgramCommand::TipDistribution { action } => {
-            TipDistributionCliHandler::new(client, keypair, program_id, config_pda, config_bump)
-                .handle(action)?
+    let command = args
+        .command
+        .ok_or_else(|| anyhow!("No command given, see --help"))?;
+    match command {
+        ProgramCommand::TipDistribution { action } => TipDistributionCliHandler::new(
+            client,
+            keypair,
+            program_id,
+            config_pda,
+            config_bump,
+            args.output,
+            transaction_options,
+        )
+        .handle(action)?,
+        ProgramCommand::TipPayment { action } => TipPaymentCliHandler::new(
+            client,
+            keypair,
+            tip_payment_program_id,
+            args.output,
+            transaction_options,
+        )
+        .handle(action)?,
+        ProgramCommand::Broadcast { transaction } => {
+            let tx = args.encoding.decode(&transaction)?;
+            if invokes_only(&tx, &tip_payment_program_id) {
+                broadcast_transaction::<TipPaymentError>(
+                    &client,
+                    &keypair,
+                    tx,
+                    args.dry_run,
+                    args.output,
+                )?
+            } else {
+                broadcast_transaction::<TipDistributionError>(
+                    &client,
+                    &keypair,
+                    tx,
+                    args.dry_run,
+                    args.output,
+                )?
+            }
         }
     }
 
@@ -1,6 +1,14 @@
+use std::str::FromStr;
+
 use clap::{Parser, Subcommand};
+use envfile::EnvFile;
+use solana_hash::Hash;
+use solana_pubkey::Pubkey;
 
-use crate::tip_distribution::TipDistributionCommands;
+use crate::{
+    output::OutputFormat, tip_distribution::TipDistributionCommands,
+    tip_payment::TipPaymentCommands, transaction::TransactionEncoding,
+};
 
 #[derive(Parser)]
 #[command(author, version, about = "A CLI for managing jito-tip-distribution operations", long_about = None)]
@@ -24,9 +32,66 @@ pub struct Cli {
     )]
     pub keypair_path: String,
 
-    #[arg(long, global = true, help = "Commitment level")]
+    #[arg(
+        long,
+        global = true,
+        help = "Commitment level to confirm transactions at: processed, confirmed or finalized"
+    )]
     pub commitment: Option<String>,
 
+    #[arg(
+        long,
+        global = true,
+        conflicts_with_all = ["sign_only", "dump_instruction"],
+        help = "Simulate transactions and print their logs instead of sending them"
+    )]
+    pub dry_run: bool,
+
+    #[arg(
+        long,
+        global = true,
+        conflicts_with = "dump_instruction",
+        help = "Sign transactions with the keypair and print them instead of sending them"
+    )]
+    pub sign_only: bool,
+
+    #[arg(
+        long,
+        global = true,
+        help = "Print the instructions, e.g. for a multisig proposal, instead of sending them"
+    )]
+    pub dump_instruction: bool,
+
+    #[arg(
+        long,
+        global = true,
+        help = "Blockhash to sign transactions with instead of fetching the latest one"
+    )]
+    pub blockhash: Option<Hash>,
+
+    #[arg(
+        long,
+        global = true,
+        help = "Fee payer of transactions, defaults to the keypair"
+    )]
+    pub fee_payer: Option<Pubkey>,
+
+    #[arg(
+        long,
+        global = true,
+        help = "Authority to build instructions for instead of the keypair, e.g. a multisig vault"
+    )]
+    pub authority: Option<Pubkey>,
+
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = TransactionEncoding::Base64,
+        help = "Encoding of signed transactions, printed by --sign-only and read by broadcast"
+    )]
+    pub encoding: TransactionEncoding,
+
     #[arg(
         long,
         global = true,
@@ -35,8 +100,49 @@ pub struct Cli {
     )]
     pub tip_distribution_program_id: String,
 
+    #[arg(
+        long,
+        global = true,
+        help = "Tip Payment Program ID, defaults to the one in config/program.env"
+    )]
+    pub tip_payment_program_id: Option<String>,
+
     #[arg(long, global = true, help = "Filepath or URL to a keypair")]
     pub signer: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        help = "Output format of the read commands"
+    )]
+    pub output: OutputFormat,
+}
+
+/// The file the default program ids are read from, relative to the working directory.
+pub const PROGRAM_ENV_PATH: &str = "config/program.env";
+
+/// The Tip Payment Program ID used when [PROGRAM_ENV_PATH] can't be read.
+pub const DEFAULT_TIP_PAYMENT_PROGRAM_ID: &str = "3YsnULkzMZ3pJcN1zX2uSyDRdY1RKKhiC32QvhPoUJ3c";
+
+impl Cli {
+    /// The `--tip-payment-program-id`, defaulting to the `JITO_TIP_PAYMENT_PROGRAM_ID` of
+    /// [PROGRAM_ENV_PATH], then to [DEFAULT_TIP_PAYMENT_PROGRAM_ID].
+    pub fn tip_payment_program_id(&self) -> anyhow::Result<Pubkey> {
+        let program_env = EnvFile::new(PROGRAM_ENV_PATH).ok();
+        let tip_payment_program_id = self
+            .tip_payment_program_id
+            .as_deref()
+            .or_else(|| {
+                program_env
+                    .as_ref()
+                    .and_then(|program_env| program_env.get("JITO_TIP_PAYMENT_PROGRAM_ID"))
+            })
+            .unwrap_or(DEFAULT_TIP_PAYMENT_PROGRAM_ID);
+
+        Ok(Pubkey::from_str(tip_payment_program_id)?)
+    }
 }
 
 #[derive(Subcommand)]
@@ -46,4 +152,16 @@ pub enum ProgramCommand {
         #[command(subcommand)]
         action: TipDistributionCommands,
     },
+
+    /// Jito Tip Payment program commands
+    TipPayment {
+        #[command(subcommand)]
+        action: TipPaymentCommands,
+    },
+
+    /// Sign with the keypair if required and send a transaction printed by --sign-only
+    Broadcast {
+        /// The serialized transaction
+        transaction: String,
+    },
 }
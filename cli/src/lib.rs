@@ -1,3 +1,9 @@
 pub mod cli_args;
+pub mod merkle_tree;
+pub mod output;
 pub mod tip_distribution;
 pub mod tip_distribution_handler;
+pub mod tip_payment;
+pub mod tip_payment_handler;
+pub mod transaction;
+pub mod views;
@@ -0,0 +1,334 @@
+//! The merkle tree file of a [TipDistributionAccount](jito_tip_distribution_core::tip_distribution_account::TipDistributionAccount).
+//!
+//! It holds the root and every claimant with their amount and proof. Pubkeys are stored as base58
+//! and hashes as hex.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::{anyhow, bail, Context};
+use jito_tip_distribution_core::merkle_proof::{intermediate, leaf};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{serde_as, DeserializeAs, DisplayFromStr, SerializeAs};
+use solana_pubkey::Pubkey;
+
+use crate::output::{from_hex, to_hex};
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MerkleTreeFile {
+    #[serde_as(as = "DisplayFromStr")]
+    pub tip_distribution_account: Pubkey,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub vote_account: Pubkey,
+
+    pub epoch: u64,
+
+    #[serde_as(as = "HexHash")]
+    pub merkle_root: [u8; 32],
+
+    pub max_total_claim: u64,
+
+    pub max_num_nodes: u64,
+
+    pub tree_nodes: Vec<TreeNode>,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TreeNode {
+    #[serde_as(as = "DisplayFromStr")]
+    pub claimant: Pubkey,
+
+    pub amount: u64,
+
+    /// Sibling hashes from the leaf up to the root
+    #[serde_as(as = "Vec<HexHash>")]
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Sibling hashes on the branch from a leaf up to the root.
+type Proof = Vec<[u8; 32]>;
+
+/// A proof file: an array of hex encoded hashes, from the leaf up to the root.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ProofFile(#[serde_as(as = "Vec<HexHash>")] pub Vec<[u8; 32]>);
+
+impl ProofFile {
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        read_json(path, "proof")
+    }
+}
+
+/// An entry of the stakes file `build-tree` reads: a claimant and the stake their share of the
+/// tips is weighted by.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClaimantStake {
+    #[serde_as(as = "DisplayFromStr")]
+    pub claimant: Pubkey,
+
+    pub stake: u64,
+}
+
+impl ClaimantStake {
+    pub fn read_all(path: &Path) -> anyhow::Result<Vec<Self>> {
+        read_json(path, "stakes")
+    }
+}
+
+impl MerkleTreeFile {
+    /// Splits `lamports` between the validator, whose vote account gets
+    /// `validator_commission_bps` of them, and the claimants pro rata to their stake. Lamports lost
+    /// to rounding down stay in the TipDistributionAccount.
+    pub fn build(
+        tip_distribution_account: Pubkey,
+        vote_account: Pubkey,
+        epoch: u64,
+        validator_commission_bps: u16,
+        lamports: u64,
+        stakes: &[ClaimantStake],
+    ) -> anyhow::Result<Self> {
+        if validator_commission_bps > 10_000 {
+            bail!("Validator commission of {validator_commission_bps} bps exceeds 100%");
+        }
+        let validator_amount = mul_div(lamports, u64::from(validator_commission_bps), 10_000)?;
+        let staker_lamports = lamports
+            .checked_sub(validator_amount)
+            .ok_or_else(|| anyhow!("Validator commission exceeds the lamports"))?;
+        let total_stake = stakes
+            .iter()
+            .try_fold(0u64, |total, stake| total.checked_add(stake.stake))
+            .ok_or_else(|| anyhow!("Total stake overflows"))?;
+
+        // Claimants can only claim once, so repeated claimants get a single node.
+        let mut amounts = BTreeMap::new();
+        amounts.insert(vote_account, validator_amount);
+        if total_stake > 0 {
+            for stake in stakes {
+                let amount = mul_div(staker_lamports, stake.stake, total_stake)?;
+                let total_amount = amounts.entry(stake.claimant).or_insert(0u64);
+                *total_amount = total_amount
+                    .checked_add(amount)
+                    .ok_or_else(|| anyhow!("Amount of {} overflows", stake.claimant))?;
+            }
+        }
+        amounts.retain(|_, amount| *amount > 0);
+
+        let leaves: Vec<[u8; 32]> = amounts
+            .iter()
+            .map(|(claimant, amount)| leaf(&claimant.to_bytes(), *amount))
+            .collect();
+        let Some((merkle_root, proofs)) = build_tree(&leaves) else {
+            bail!("There are no lamports to distribute");
+        };
+
+        let max_total_claim = amounts
+            .values()
+            .try_fold(0u64, |total, amount| total.checked_add(*amount))
+            .ok_or_else(|| anyhow!("Total claim overflows"))?;
+        let tree_nodes: Vec<TreeNode> = amounts
+            .into_iter()
+            .zip(proofs)
+            .map(|((claimant, amount), proof)| TreeNode {
+                claimant,
+                amount,
+                proof,
+            })
+            .collect();
+
+        Ok(Self {
+            tip_distribution_account,
+            vote_account,
+            epoch,
+            merkle_root,
+            max_total_claim,
+            max_num_nodes: u64::try_from(tree_nodes.len())?,
+            tree_nodes,
+        })
+    }
+
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        read_json(path, "merkle tree")
+    }
+
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write merkle tree file {}", path.display()))
+    }
+
+    /// The node of `claimant`, if it's part of the tree.
+    pub fn node(&self, claimant: &Pubkey) -> Option<&TreeNode> {
+        self.tree_nodes
+            .iter()
+            .find(|node| node.claimant == *claimant)
+    }
+}
+
+/// Builds the merkle tree over `leaves`, returning its root and the proof of every leaf for
+/// `merkle_proof::verify`. A node without a sibling is paired with itself. Returns `None` without
+/// leaves.
+fn build_tree(leaves: &[[u8; 32]]) -> Option<([u8; 32], Vec<Proof>)> {
+    let mut proofs = vec![Vec::new(); leaves.len()];
+    // Index of the ancestor of every leaf in the current level.
+    let mut positions: Vec<usize> = (0..leaves.len()).collect();
+    let mut level = leaves.to_vec();
+
+    while level.len() > 1 {
+        for (proof, position) in proofs.iter_mut().zip(positions.iter_mut()) {
+            let node = level.get(*position)?;
+            proof.push(*level.get(*position ^ 1).unwrap_or(node));
+            *position >>= 1;
+        }
+
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => intermediate(left, right),
+                [node] => intermediate(node, node),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+
+    Some((*level.first()?, proofs))
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path, kind: &str) -> anyhow::Result<T> {
+    let file = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {kind} file {}", path.display()))?;
+
+    serde_json::from_str(&file)
+        .with_context(|| format!("Failed to parse {kind} file {}", path.display()))
+}
+
+/// `a * b / c`, rounded down.
+fn mul_div(a: u64, b: u64, c: u64) -> anyhow::Result<u64> {
+    let result = u128::from(a)
+        .checked_mul(u128::from(b))
+        .and_then(|product| product.checked_div(u128::from(c)))
+        .ok_or_else(|| anyhow!("Failed to compute {a} * {b} / {c}"))?;
+
+    Ok(u64::try_from(result)?)
+}
+
+/// (De)serializes a 32 byte hash as hex.
+struct HexHash;
+
+impl SerializeAs<[u8; 32]> for HexHash {
+    fn serialize_as<S: Serializer>(source: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_hex(source))
+    }
+}
+
+impl<'de> DeserializeAs<'de, [u8; 32]> for HexHash {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 32], D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        from_hex(&hex).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_tip_distribution_core::merkle_proof::{intermediate, leaf, verify};
+    use solana_pubkey::Pubkey;
+
+    use super::{build_tree, ClaimantStake, MerkleTreeFile};
+
+    fn stake(claimant: Pubkey, stake: u64) -> ClaimantStake {
+        ClaimantStake { claimant, stake }
+    }
+
+    #[test]
+    fn build_splits_lamports_pro_rata() {
+        let vote_account = Pubkey::new_from_array([1; 32]);
+        let a = Pubkey::new_from_array([2; 32]);
+        let b = Pubkey::new_from_array([3; 32]);
+        let c = Pubkey::new_from_array([4; 32]);
+        let stakes = [stake(a, 1), stake(b, 2), stake(a, 1), stake(c, 0)];
+
+        let tree = MerkleTreeFile::build(
+            Pubkey::new_unique(),
+            vote_account,
+            7,
+            1_000,
+            10_001,
+            &stakes,
+        )
+        .unwrap();
+
+        // 10% commission rounds down to 1_000, the 9_001 left are split 1:2:1 between the stakes,
+        // each rounding down. `a` gets a single node with both of its shares, `c` gets nothing.
+        let amounts: Vec<(Pubkey, u64)> = tree
+            .tree_nodes
+            .iter()
+            .map(|node| (node.claimant, node.amount))
+            .collect();
+        assert_eq!(amounts, [(vote_account, 1_000), (a, 4_500), (b, 4_500)]);
+        assert_eq!(tree.max_total_claim, 10_000);
+        assert_eq!(tree.max_num_nodes, 3);
+        assert_eq!(tree.epoch, 7);
+
+        for node in &tree.tree_nodes {
+            assert!(verify(
+                &node.proof,
+                &tree.merkle_root,
+                leaf(&node.claimant.to_bytes(), node.amount)
+            ));
+        }
+    }
+
+    #[test]
+    fn build_without_stake_pays_the_validator() {
+        let vote_account = Pubkey::new_unique();
+        let stakes = [stake(Pubkey::new_unique(), 0)];
+
+        let tree =
+            MerkleTreeFile::build(Pubkey::new_unique(), vote_account, 0, 500, 1_000, &stakes)
+                .unwrap();
+        assert_eq!(tree.tree_nodes.len(), 1);
+        assert_eq!(tree.tree_nodes[0].claimant, vote_account);
+        assert_eq!(tree.tree_nodes[0].amount, 50);
+        assert_eq!(tree.merkle_root, leaf(&vote_account.to_bytes(), 50));
+
+        // Nothing to distribute at all.
+        assert!(
+            MerkleTreeFile::build(Pubkey::new_unique(), vote_account, 0, 0, 1_000, &stakes)
+                .is_err()
+        );
+        // A commission above 100%.
+        assert!(
+            MerkleTreeFile::build(Pubkey::new_unique(), vote_account, 0, 10_001, 1_000, &[])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn build_tree_proofs_verify() {
+        let leaves: Vec<[u8; 32]> = (1..=5u8).map(|i| leaf(&[i; 32], u64::from(i))).collect();
+        let (root, proofs) = build_tree(&leaves).unwrap();
+
+        for (leaf, proof) in leaves.iter().zip(&proofs) {
+            assert!(verify(proof, &root, *leaf));
+        }
+
+        // The fifth leaf has no sibling and is paired with itself.
+        let left = intermediate(
+            &intermediate(&leaves[0], &leaves[1]),
+            &intermediate(&leaves[2], &leaves[3]),
+        );
+        let right = intermediate(
+            &intermediate(&leaves[4], &leaves[4]),
+            &intermediate(&leaves[4], &leaves[4]),
+        );
+        assert_eq!(root, intermediate(&left, &right));
+
+        let (root, proofs) = build_tree(&leaves[..1]).unwrap();
+        assert_eq!(root, leaves[0]);
+        assert!(proofs[0].is_empty());
+
+        assert!(build_tree(&[]).is_none());
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::{Display, Write};
+
+use anyhow::anyhow;
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How the read commands print what they fetched.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+
+    /// Pretty-printed JSON
+    Json,
+
+    /// JSON on a single line
+    JsonCompact,
+}
+
+impl OutputFormat {
+    /// Prints `view` in this format.
+    pub fn print<T: Serialize + Display>(self, view: &T) -> anyhow::Result<()> {
+        match self {
+            Self::Text => print!("{view}"),
+            Self::Json => println!("{}", serde_json::to_string_pretty(view)?),
+            Self::JsonCompact => println!("{}", serde_json::to_string(view)?),
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders bytes such as a merkle root as lowercase hex.
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+/// Parses `N` bytes out of hex, with or without a `0x` prefix.
+pub fn from_hex<const N: usize>(hex: &str) -> anyhow::Result<[u8; N]> {
+    let hex = hex.trim().trim_start_matches("0x");
+    if hex.len() != N.saturating_mul(2) {
+        return Err(anyhow!("Expected {N} hex encoded bytes, got {hex:?}"));
+    }
+
+    // `from_str_radix` also accepts a leading `+`.
+    if let Some(invalid) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(anyhow!("Invalid hex character {invalid:?} in {hex:?}"));
+    }
+
+    let mut bytes = [0; N];
+    for (byte, chunk) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        let chunk = std::str::from_utf8(chunk)?;
+        *byte = u8::from_str_radix(chunk, 16)
+            .map_err(|_| anyhow!("Invalid hex byte {chunk:?} in {hex:?}"))?;
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_hex, to_hex};
+
+    #[test]
+    fn from_hex_round_trips_to_hex() {
+        let bytes: [u8; 4] = [0x00, 0x7f, 0xab, 0xff];
+        assert_eq!(to_hex(&bytes), "007fabff");
+        assert_eq!(from_hex::<4>("007fabff").unwrap(), bytes);
+        assert_eq!(from_hex::<4>(" 0x007FABFF\n").unwrap(), bytes);
+    }
+
+    #[test]
+    fn from_hex_rejects_bad_input() {
+        // Wrong length.
+        assert!(from_hex::<4>("007fab").is_err());
+        assert!(from_hex::<4>("007fabff00").is_err());
+        assert!(from_hex::<4>("").is_err());
+        // Characters that aren't hex digits.
+        assert!(from_hex::<4>("007fabfg").is_err());
+        assert!(from_hex::<4>("+07fabff").is_err());
+        assert!(from_hex::<4>("-07fabff").is_err());
+        assert!(from_hex::<4>("00 7fabf").is_err());
+        // Multi-byte characters of the right byte length.
+        assert!(from_hex::<4>("007fabé").is_err());
+    }
+}
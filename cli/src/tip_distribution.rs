@@ -1,4 +1,6 @@
-use clap::Subcommand;
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
 use solana_pubkey::Pubkey;
 
 /// The CLI handler for the jito-tip-distribution program
@@ -27,6 +29,68 @@ pub enum TipDistributionCommands {
         #[command(subcommand)]
         action: ClaimStatusActions,
     },
+
+    /// Close every expired ClaimStatus and TipDistributionAccount in batches. TipDistributionAccounts
+    /// still in the version 0 layout are skipped until they are migrated with
+    /// `tip-distribution-account migrate`
+    Crank {
+        /// Number of accounts closed per transaction
+        #[arg(long, default_value_t = 10)]
+        batch_size: usize,
+    },
+
+    /// Recompute a merkle proof the way Claim does, printing every intermediate hash
+    VerifyProof(VerifyProofArgs),
+
+    /// Reconcile the tips of an epoch across its TipDistributionAccounts. Printed as CSV, or as
+    /// JSON with `--output json`
+    Report(ReportArgs),
+}
+
+#[derive(Args)]
+pub struct VerifyProofArgs {
+    /// Claimant pubkey
+    #[arg(long)]
+    pub claimant: Pubkey,
+
+    /// Amount, looked up in the merkle tree file if not set
+    #[arg(long, required_unless_present = "merkle_tree")]
+    pub amount: Option<u64>,
+
+    /// Comma separated hex encoded proof, from the leaf up to the root
+    #[arg(long, value_delimiter = ',', conflicts_with_all = ["proof_file", "merkle_tree"])]
+    pub proof: Vec<String>,
+
+    /// JSON file holding the proof as an array of hex encoded hashes
+    #[arg(long, conflicts_with = "merkle_tree")]
+    pub proof_file: Option<PathBuf>,
+
+    /// Merkle tree file to take the proof, amount and root of the claimant from
+    #[arg(long)]
+    pub merkle_tree: Option<PathBuf>,
+
+    /// Hex encoded merkle root
+    #[arg(long, conflicts_with = "vote_account")]
+    pub root: Option<String>,
+
+    /// Validator vote account pubkey, to fetch the root uploaded to its TipDistributionAccount
+    #[arg(long, requires = "epoch")]
+    pub vote_account: Option<Pubkey>,
+
+    /// Epoch of the TipDistributionAccount to fetch the root of
+    #[arg(long, requires = "vote_account")]
+    pub epoch: Option<u64>,
+}
+
+#[derive(Args)]
+pub struct ReportArgs {
+    /// Epoch the TipDistributionAccounts were created for, the previous epoch if not set
+    #[arg(long)]
+    pub epoch: Option<u64>,
+
+    /// Also total the claimed ClaimStatus accounts, matched to the epoch by their `expires_at`
+    #[arg(long)]
+    pub scan_claim_statuses: bool,
 }
 
 /// The actions that can be performed on the tip_distribution_account config
@@ -67,6 +131,11 @@ pub enum ConfigActions {
         /// Max validator commission BPS
         #[arg(long)]
         max_validator_commission_bps: u16,
+
+        /// Epochs after a TipDistributionAccount's epoch before the merkle root upload falls
+        /// back to the override authority, 0 disables the deadline. Left unchanged when omitted
+        #[arg(long)]
+        merkle_root_upload_deadline_epochs: Option<u64>,
     },
 }
 
@@ -86,6 +155,18 @@ pub enum TipDistributionAccountActions {
         /// Validator commission BPS
         #[clap(long)]
         validator_commission_bps: u16,
+
+        /// Account refunded the rent when the TipDistributionAccount is closed, defaults to the
+        /// vote account. Setting it requires signing as the vote account's node identity or
+        /// withdrawer
+        #[clap(long)]
+        rent_refund_destination: Option<Pubkey>,
+
+        /// Roll unclaimed funds over into a later TipDistributionAccount on closure instead of
+        /// sending them to the expired funds account. Requires signing as the vote account's node
+        /// identity or withdrawer
+        #[clap(long)]
+        roll_over_unclaimed_funds: bool,
     },
 
     /// Upload merkle root
@@ -94,6 +175,10 @@ pub enum TipDistributionAccountActions {
         #[arg(long)]
         vote_account: Pubkey,
 
+        /// Epoch of the TipDistributionAccount, defaults to the previous epoch
+        #[arg(long)]
+        epoch: Option<u64>,
+
         /// Root
         #[arg(long)]
         root: String,
@@ -107,8 +192,35 @@ pub enum TipDistributionAccountActions {
         max_num_nodes: u64,
     },
 
-    /// List TipDistributionAccounts
-    List,
+    /// Build the merkle tree of the TipDistributionAccount out of a stakes file and its balance
+    BuildTree {
+        /// Validator vote account pubkey
+        #[arg(long)]
+        vote_account: Pubkey,
+
+        /// Epoch of the TipDistributionAccount, defaults to the previous epoch
+        #[arg(long)]
+        epoch: Option<u64>,
+
+        /// JSON file listing the claimants and their stake: `[{"claimant": "...", "stake": 1}]`
+        #[arg(long)]
+        stakes: PathBuf,
+
+        /// Merkle tree file to write
+        #[arg(long)]
+        merkle_tree: PathBuf,
+
+        /// Upload the merkle root of the tree
+        #[arg(long)]
+        upload: bool,
+
+        /// Replace the merkle root already uploaded to the TipDistributionAccount
+        #[arg(long, requires = "upload")]
+        force: bool,
+    },
+
+    /// List TipDistributionAccounts, newest first
+    List(ListTipDistributionAccountsArgs),
 
     /// Get the TipDistributionAccount
     Get {
@@ -131,6 +243,49 @@ pub enum TipDistributionAccountActions {
         #[arg(long)]
         epoch: u64,
     },
+
+    /// Migrate a TipDistributionAccount still in the version 0 layout, paying for the larger
+    /// account. Closing it, alone or through `crank`, requires this first
+    Migrate {
+        /// Validator vote account pubkey
+        #[arg(long)]
+        vote_account: Pubkey,
+
+        /// Epoch number
+        #[arg(long)]
+        epoch: u64,
+    },
+}
+
+#[derive(Args)]
+pub struct ListTipDistributionAccountsArgs {
+    /// Only accounts created for this epoch
+    #[arg(long)]
+    pub epoch: Option<u64>,
+
+    /// Only accounts of this validator vote account
+    #[arg(long)]
+    pub vote_account: Option<Pubkey>,
+
+    /// Only accounts with this merkle root upload authority
+    #[arg(long)]
+    pub upload_authority: Option<Pubkey>,
+
+    /// Only accounts a merkle root has been uploaded to
+    #[arg(long, conflicts_with = "no_root")]
+    pub has_root: bool,
+
+    /// Only accounts without a merkle root
+    #[arg(long)]
+    pub no_root: bool,
+
+    /// Only accounts past their `expires_at` epoch
+    #[arg(long)]
+    pub expired: bool,
+
+    /// Maximum number of accounts to print
+    #[arg(long)]
+    pub limit: Option<usize>,
 }
 
 /// The actions that can be performed on the MerkleRootUploadConfig
@@ -152,6 +307,25 @@ pub enum MerkleRootUploadConfigActions {
         #[arg(long)]
         epoch: u64,
     },
+
+    /// Migrate every TipDistributionAccount still carrying the original upload authority in batches
+    MigrateTdaMerkleRootUploadAuthorities {
+        /// Number of accounts migrated per transaction
+        #[arg(long, default_value_t = 20)]
+        batch_size: usize,
+    },
+
+    /// Permit a key to upload merkle roots on behalf of the override authority
+    AddPermittedUploader {
+        /// Uploader pubkey
+        uploader: Pubkey,
+    },
+
+    /// Revoke a permitted uploader
+    RemovePermittedUploader {
+        /// Uploader pubkey
+        uploader: Pubkey,
+    },
 }
 
 /// The actions that can be performed on the MerkleRootUploadConfig
@@ -171,9 +345,20 @@ pub enum ClaimStatusActions {
         #[arg(long)]
         claimant: Pubkey,
 
-        /// Amount
+        /// Amount, looked up in the merkle tree file if not set
+        #[arg(long, required_unless_present = "merkle_tree")]
+        amount: Option<u64>,
+
+        /// Merkle tree file to take the amount and proof of the claimant from
+        #[arg(long)]
+        merkle_tree: Option<PathBuf>,
+    },
+
+    /// Claim every node of a merkle tree file that hasn't been claimed yet
+    ClaimAll {
+        /// Merkle tree file of the tip distribution account
         #[arg(long)]
-        amount: u64,
+        merkle_tree: PathBuf,
     },
 
     /// Get claim status for a specific validator, epoch and claimant
@@ -1,38 +1,86 @@
-use std::{str::FromStr, sync::Arc};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
-use anchor_lang::{AccountDeserialize, Discriminator, InstructionData, ToAccountMetas};
-use anyhow::anyhow;
+use anyhow::{anyhow, bail};
 use base64::{engine::general_purpose, Engine};
-use jito_tip_distribution_core::load_unchecked;
-use jito_tip_distribution_legacy::state::{
-    ClaimStatus, Config, MerkleRootUploadConfig, TipDistributionAccount,
+use jito_tip_core::{
+    program_account::{ProgramAccount, DISCRIMINATOR_LEN},
+    versioned_account::VersionedAccount,
+};
+use jito_tip_distribution_core::{
+    claim_status::ClaimStatus as CoreClaimStatus, config::Config as CoreConfig,
+    merkle_root::MerkleRoot,
+    merkle_root_upload_config::MerkleRootUploadConfig as CoreMerkleRootUploadConfig,
+    tip_distribution_account::TipDistributionAccount as CoreTipDistributionAccount,
 };
-use jito_tip_distribution_sdk_legacy::{
-    derive_tip_distribution_account_address,
-    instruction::{update_config_ix, UpdateConfigAccounts, UpdateConfigArgs},
+use jito_tip_distribution_sdk::{
+    error::TipDistributionError,
+    sdk::{
+        add_permitted_uploader, claim, close_claim_status, close_claim_statuses,
+        close_tip_distribution_account, close_tip_distribution_accounts, initialize_config,
+        initialize_merkle_root_upload_config, initialize_tip_distribution_account, migrate_account,
+        migrate_tda_merkle_root_upload_authorities, migrate_tda_merkle_root_upload_authority,
+        remove_permitted_uploader, update_config, update_merkle_root_upload_config,
+        upload_merkle_root,
+    },
 };
-use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
+use solana_account::Account;
+use solana_account_decoder::UiAccountEncoding;
 use solana_client::{
     rpc_client::RpcClient,
     rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
     rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+    rpc_request::MAX_MULTIPLE_ACCOUNTS,
 };
 use solana_instruction::Instruction;
-use solana_keypair::Keypair;
 use solana_pubkey::Pubkey;
-use solana_sdk::system_program;
 use solana_signer::Signer;
-use solana_transaction::Transaction;
 
-use crate::tip_distribution::{
-    ClaimStatusActions, ConfigActions, MerkleRootUploadConfigActions,
-    TipDistributionAccountActions, TipDistributionCommands,
+use crate::{
+    merkle_tree::{ClaimantStake, MerkleTreeFile, ProofFile},
+    output::{from_hex, to_hex, OutputFormat},
+    tip_distribution::{
+        ClaimStatusActions, ConfigActions, ListTipDistributionAccountsArgs,
+        MerkleRootUploadConfigActions, ReportArgs, TipDistributionAccountActions,
+        TipDistributionCommands, VerifyProofArgs,
+    },
+    transaction::{process_transaction, LazyKeypair, TransactionOptions},
+    views::{
+        ClaimStatusTotalsView, ClaimStatusView, ConfigView, EpochReportRowView, EpochReportView,
+        ListView, ProofVerificationView, ReportFlag, TipDistributionAccountView,
+    },
 };
 
+pub fn derive_config_account_address(tip_distribution_program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CoreConfig::SEED], tip_distribution_program_id)
+}
+
+fn derive_tip_distribution_account_address(
+    tip_distribution_program_id: &Pubkey,
+    vote_account: &Pubkey,
+    epoch: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            CoreTipDistributionAccount::SEED,
+            vote_account.as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        tip_distribution_program_id,
+    )
+}
+
 fn derive_merkle_root_upload_config_account_address(
     tip_distribution_program_id: &Pubkey,
 ) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[MerkleRootUploadConfig::SEED], tip_distribution_program_id)
+    Pubkey::find_program_address(
+        &[CoreMerkleRootUploadConfig::ROOT_UPLOAD_CONFIG_SEED],
+        tip_distribution_program_id,
+    )
 }
 
 fn derive_claim_status_account_address(
@@ -42,7 +90,7 @@ fn derive_claim_status_account_address(
 ) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[
-            ClaimStatus::SEED,
+            CoreClaimStatus::SEED,
             claimant.to_bytes().as_ref(),
             tip_distribution_account.to_bytes().as_ref(),
         ],
@@ -55,7 +103,7 @@ pub struct TipDistributionCliHandler {
     client: RpcClient,
 
     /// Keypair
-    keypair: Arc<Keypair>,
+    keypair: LazyKeypair,
 
     /// The Pubkey of Jito Restaking Program ID
     program_id: Pubkey,
@@ -65,15 +113,23 @@ pub struct TipDistributionCliHandler {
 
     /// Config Bump
     config_bump: u8,
+
+    /// How read commands print accounts
+    output: OutputFormat,
+
+    /// How mutating commands submit transactions
+    transaction_options: TransactionOptions,
 }
 
 impl TipDistributionCliHandler {
     pub const fn new(
         client: RpcClient,
-        keypair: Arc<Keypair>,
+        keypair: LazyKeypair,
         program_id: Pubkey,
         config_pda: Pubkey,
         config_bump: u8,
+        output: OutputFormat,
+        transaction_options: TransactionOptions,
     ) -> Self {
         Self {
             client,
@@ -81,6 +137,8 @@ impl TipDistributionCliHandler {
             program_id,
             config_pda,
             config_bump,
+            output,
+            transaction_options,
         }
     }
 
@@ -111,12 +169,14 @@ impl TipDistributionCliHandler {
                         expired_funds_account,
                         num_epochs_valid,
                         max_validator_commission_bps,
+                        merkle_root_upload_deadline_epochs,
                     },
             } => self.update_config(
                 authority,
                 expired_funds_account,
                 num_epochs_valid,
                 max_validator_commission_bps,
+                merkle_root_upload_deadline_epochs,
             ),
             TipDistributionCommands::TipDistributionAccount {
                 action:
@@ -124,21 +184,26 @@ impl TipDistributionCliHandler {
                         vote_account,
                         merkle_root_upload_authority,
                         validator_commission_bps,
+                        rent_refund_destination,
+                        roll_over_unclaimed_funds,
                     },
             } => self.initialize_tip_distribution_account(
                 vote_account,
                 merkle_root_upload_authority,
                 validator_commission_bps,
+                rent_refund_destination,
+                roll_over_unclaimed_funds,
             ),
             TipDistributionCommands::TipDistributionAccount {
                 action:
                     TipDistributionAccountActions::UploadMerkleRoot {
                         vote_account,
+                        epoch,
                         root,
                         max_total_claim,
                         max_num_nodes,
                     },
-            } => self.upload_merkle_root(vote_account, root, max_total_claim, max_num_nodes),
+            } => self.upload_merkle_root(vote_account, epoch, root, max_total_claim, max_num_nodes),
             TipDistributionCommands::TipDistributionAccount {
                 action:
                     TipDistributionAccountActions::Get {
@@ -147,8 +212,19 @@ impl TipDistributionCliHandler {
                     },
             } => self.get_tip_distribution_account(vote_account, epoch),
             TipDistributionCommands::TipDistributionAccount {
-                action: TipDistributionAccountActions::List,
-            } => self.list_tip_distribution_accounts(),
+                action:
+                    TipDistributionAccountActions::BuildTree {
+                        vote_account,
+                        epoch,
+                        stakes,
+                        merkle_tree,
+                        upload,
+                        force,
+                    },
+            } => self.build_tree(vote_account, epoch, &stakes, &merkle_tree, upload, force),
+            TipDistributionCommands::TipDistributionAccount {
+                action: TipDistributionAccountActions::List(args),
+            } => self.list_tip_distribution_accounts(args),
             TipDistributionCommands::TipDistributionAccount {
                 action:
                     TipDistributionAccountActions::Close {
@@ -156,6 +232,13 @@ impl TipDistributionCliHandler {
                         epoch,
                     },
             } => self.close_tip_distribution_account(vote_account, epoch),
+            TipDistributionCommands::TipDistributionAccount {
+                action:
+                    TipDistributionAccountActions::Migrate {
+                        vote_account,
+                        epoch,
+                    },
+            } => self.migrate_tip_distribution_account(vote_account, epoch),
             TipDistributionCommands::MerkleRootUploadConfig {
                 action: MerkleRootUploadConfigActions::Initialize,
             } => self.initialize_merkle_root_upload_config(),
@@ -169,6 +252,16 @@ impl TipDistributionCliHandler {
                         epoch,
                     },
             } => self.migrate_merkle_root_upload_config_authority(vote_account, epoch),
+            TipDistributionCommands::MerkleRootUploadConfig {
+                action:
+                    MerkleRootUploadConfigActions::MigrateTdaMerkleRootUploadAuthorities { batch_size },
+            } => self.migrate_tda_merkle_root_upload_authorities(batch_size),
+            TipDistributionCommands::MerkleRootUploadConfig {
+                action: MerkleRootUploadConfigActions::AddPermittedUploader { uploader },
+            } => self.add_permitted_uploader(uploader),
+            TipDistributionCommands::MerkleRootUploadConfig {
+                action: MerkleRootUploadConfigActions::RemovePermittedUploader { uploader },
+            } => self.remove_permitted_uploader(uploader),
             TipDistributionCommands::ClaimStatus {
                 action:
                     ClaimStatusActions::Claim {
@@ -176,8 +269,12 @@ impl TipDistributionCliHandler {
                         epoch,
                         claimant,
                         amount,
+                        merkle_tree,
                     },
-            } => self.claim(vote_account, epoch, claimant, amount),
+            } => self.claim(vote_account, epoch, claimant, amount, merkle_tree),
+            TipDistributionCommands::ClaimStatus {
+                action: ClaimStatusActions::ClaimAll { merkle_tree },
+            } => self.claim_all(&merkle_tree),
             TipDistributionCommands::ClaimStatus {
                 action:
                     ClaimStatusActions::GetClaimStatus {
@@ -194,27 +291,19 @@ impl TipDistributionCliHandler {
                         claimant,
                     },
             } => self.close_claim_status(vote_account, epoch, claimant),
+            TipDistributionCommands::Crank { batch_size } => self.crank(batch_size),
+            TipDistributionCommands::VerifyProof(args) => self.verify_proof(args),
+            TipDistributionCommands::Report(args) => self.report(args),
         }
     }
 
     /// Get TipDistribution config
     pub fn get_config(&self) -> anyhow::Result<()> {
-        println!("Config Account Address: {}", self.config_pda);
-
         let config_data = self.client.get_account(&self.config_pda)?.data;
-        let config: Config = Config::try_deserialize(&mut config_data.as_slice())?;
-
-        println!("Config Account Data:");
-        println!("  Authority: {}", config.authority);
-        println!("  Expired Funds Account: {}", config.expired_funds_account);
-        println!("  Num Epochs Valid: {}", config.num_epochs_valid);
-        println!(
-            "  Max Validator Commission BPS: {}",
-            config.max_validator_commission_bps
-        );
-        println!("  Bump: {}", config.bump);
+        let config = decode_program_account::<CoreConfig>(&config_data)?;
 
-        Ok(())
+        self.output
+            .print(&ConfigView::new(&self.config_pda, &config))
     }
 
     /// Initialize config
@@ -225,35 +314,18 @@ impl TipDistributionCliHandler {
         num_epochs_valid: u64,
         max_validator_commission_bps: u16,
     ) -> anyhow::Result<()> {
-        let ix = Instruction {
-            program_id: self.program_id,
-            data: jito_tip_distribution_legacy::instruction::Initialize {
-                authority,
-                expired_funds_account,
-                num_epochs_valid,
-                max_validator_commission_bps,
-                bump: self.config_bump,
-            }
-            .data(),
-            accounts: jito_tip_distribution_legacy::accounts::Initialize {
-                config: self.config_pda,
-                system_program: system_program::ID,
-                initializer: self.keypair.pubkey(),
-            }
-            .to_account_metas(None),
-        };
-
-        let blockhash = self.client.get_latest_blockhash()?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&self.keypair.pubkey()),
-            &[self.keypair.clone()],
-            blockhash,
+        let ix = initialize_config(
+            &self.program_id,
+            &self.config_pda,
+            &self.authority()?,
+            &authority,
+            &expired_funds_account,
+            num_epochs_valid,
+            max_validator_commission_bps,
+            self.config_bump,
         );
 
-        self.client.send_transaction(&tx)?;
-
-        Ok(())
+        self.process_transaction(&[ix])
     }
 
     /// Update config
@@ -263,40 +335,23 @@ impl TipDistributionCliHandler {
         expired_funds_account: String,
         num_epochs_valid: u64,
         max_validator_commission_bps: u16,
+        merkle_root_upload_deadline_epochs: Option<u64>,
     ) -> anyhow::Result<()> {
         let authority_pubkey = Pubkey::from_str(&authority)?;
         let expired_funds_account_pubkey = Pubkey::from_str(&expired_funds_account)?;
 
-        let config = Config {
-            authority: authority_pubkey,
-            expired_funds_account: expired_funds_account_pubkey,
+        let instruction = update_config(
+            &self.program_id,
+            &self.config_pda,
+            &self.authority()?,
+            &authority_pubkey,
+            &expired_funds_account_pubkey,
             num_epochs_valid,
             max_validator_commission_bps,
-            bump: self.config_bump,
-        };
-
-        let accounts = UpdateConfigAccounts {
-            config: Pubkey::default(),
-            authority: authority_pubkey,
-        };
-
-        let instruction = update_config_ix(
-            self.program_id,
-            UpdateConfigArgs { new_config: config },
-            accounts,
-        );
-
-        let blockhash = self.client.get_latest_blockhash()?;
-        let tx = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&self.keypair.pubkey()),
-            &[self.keypair.clone()],
-            blockhash,
+            merkle_root_upload_deadline_epochs,
         );
 
-        self.client.send_transaction(&tx)?;
-
-        Ok(())
+        self.process_transaction(&[instruction])
     }
 
     /// Initialize TipDistributionAccount account
@@ -305,46 +360,34 @@ impl TipDistributionCliHandler {
         vote_account: Pubkey,
         merkle_root_upload_authority: Pubkey,
         validator_commission_bps: u16,
+        rent_refund_destination: Option<Pubkey>,
+        roll_over_unclaimed_funds: bool,
     ) -> anyhow::Result<()> {
         let epoch = self.client.get_epoch_info()?.epoch;
         let (tip_distribution_pubkey, tip_distribution_bump) =
             derive_tip_distribution_account_address(&self.program_id, &vote_account, epoch);
 
-        let ix = Instruction {
-            program_id: self.program_id,
-            data: jito_tip_distribution_legacy::instruction::InitializeTipDistributionAccount {
-                merkle_root_upload_authority,
-                validator_commission_bps,
-                bump: tip_distribution_bump,
-            }
-            .data(),
-            accounts: jito_tip_distribution_legacy::accounts::InitializeTipDistributionAccount {
-                config: self.config_pda,
-                tip_distribution_account: tip_distribution_pubkey,
-                validator_vote_account: vote_account,
-                signer: self.keypair.pubkey(),
-                system_program: system_program::ID,
-            }
-            .to_account_metas(None),
-        };
-
-        let blockhash = self.client.get_latest_blockhash()?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&self.keypair.pubkey()),
-            &[self.keypair.clone()],
-            blockhash,
+        let ix = initialize_tip_distribution_account(
+            &self.program_id,
+            &self.config_pda,
+            &tip_distribution_pubkey,
+            &vote_account,
+            &self.authority()?,
+            &merkle_root_upload_authority,
+            validator_commission_bps,
+            tip_distribution_bump,
+            rent_refund_destination.as_ref(),
+            roll_over_unclaimed_funds,
         );
 
-        self.client.send_transaction(&tx)?;
-
-        Ok(())
+        self.process_transaction(&[ix])
     }
 
-    /// Upload merkle root
+    /// Upload merkle root, to the TipDistributionAccount of the previous epoch by default
     pub fn upload_merkle_root(
         &self,
         vote_account: Pubkey,
+        epoch: Option<u64>,
         root: String,
         max_total_claim: u64,
         max_num_nodes: u64,
@@ -369,38 +412,122 @@ impl TipDistributionCliHandler {
         let mut source: [u8; 32] = [0; 32];
         source.copy_from_slice(&root_bytes);
 
-        //let epoch = self.client.get_epoch_info()?.epoch;
-        let epoch = 944;
+        let epoch = self.epoch_or_previous(epoch)?;
         let (tip_distribution_pubkey, _tip_distribution_bump) =
             derive_tip_distribution_account_address(&self.program_id, &vote_account, epoch);
 
-        let ix = Instruction {
-            program_id: self.program_id,
-            data: jito_tip_distribution_legacy::instruction::UploadMerkleRoot {
-                root: source,
-                max_total_claim,
-                max_num_nodes,
-            }
-            .data(),
-            accounts: jito_tip_distribution_legacy::accounts::UploadMerkleRoot {
-                config: self.config_pda,
-                merkle_root_upload_authority: self.keypair.pubkey(),
-                tip_distribution_account: tip_distribution_pubkey,
+        let ix = self.upload_merkle_root_instruction(
+            &tip_distribution_pubkey,
+            source,
+            max_total_claim,
+            max_num_nodes,
+        )?;
+
+        self.process_transaction(&[ix])
+    }
+
+    /// Build the merkle tree of a TipDistributionAccount and optionally upload its root
+    pub fn build_tree(
+        &self,
+        vote_account: Pubkey,
+        epoch: Option<u64>,
+        stakes: &Path,
+        merkle_tree: &Path,
+        upload: bool,
+        force: bool,
+    ) -> anyhow::Result<()> {
+        let epoch = self.epoch_or_previous(epoch)?;
+        let (tip_distribution_pubkey, _tip_distribution_bump) =
+            derive_tip_distribution_account_address(&self.program_id, &vote_account, epoch);
+
+        let account = self.client.get_account(&tip_distribution_pubkey)?;
+        let tip_dist = decode_versioned_account::<CoreTipDistributionAccount>(&account.data)?;
+        if upload && !force {
+            if let Some(merkle_root) = tip_dist.merkle_root() {
+                bail!(
+                    "{tip_distribution_pubkey} already has the merkle root {}, pass --force to \
+                     replace it",
+                    to_hex(&merkle_root.root)
+                );
             }
-            .to_account_metas(None),
-        };
+        }
 
-        let blockhash = self.client.get_latest_blockhash()?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&self.keypair.pubkey()),
-            &[self.keypair.clone()],
-            blockhash,
+        let rent = self
+            .client
+            .get_minimum_balance_for_rent_exemption(account.data.len())?;
+        let lamports = account.lamports.saturating_sub(rent);
+
+        let tree = MerkleTreeFile::build(
+            tip_distribution_pubkey,
+            vote_account,
+            epoch,
+            tip_dist.validator_commission_bps(),
+            lamports,
+            &ClaimantStake::read_all(stakes)?,
+        )?;
+        tree.write(merkle_tree)?;
+        println!(
+            "Wrote the merkle tree of {tip_distribution_pubkey} to {}",
+            merkle_tree.display()
         );
+        println!("  Root: {}", to_hex(&tree.merkle_root));
+        println!("  Max Total Claim: {}", tree.max_total_claim);
+        println!("  Max Num Nodes: {}", tree.max_num_nodes);
 
-        self.client.send_transaction(&tx)?;
+        if !upload {
+            return Ok(());
+        }
 
-        Ok(())
+        let ix = self.upload_merkle_root_instruction(
+            &tip_distribution_pubkey,
+            tree.merkle_root,
+            tree.max_total_claim,
+            tree.max_num_nodes,
+        )?;
+
+        self.process_transaction(&[ix])
+    }
+
+    fn upload_merkle_root_instruction(
+        &self,
+        tip_distribution_account: &Pubkey,
+        root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+    ) -> anyhow::Result<Instruction> {
+        // Permitted uploaders and the override authority are checked against the
+        // MerkleRootUploadConfig, if the program has one.
+        let (merkle_root_upload_config_pda, _merkle_root_upload_config_bump) =
+            derive_merkle_root_upload_config_account_address(&self.program_id);
+        let merkle_root_upload_config = self
+            .client
+            .get_account(&merkle_root_upload_config_pda)
+            .is_ok()
+            .then_some(merkle_root_upload_config_pda);
+
+        Ok(upload_merkle_root(
+            &self.program_id,
+            &self.config_pda,
+            tip_distribution_account,
+            &self.authority()?,
+            merkle_root_upload_config.as_ref(),
+            root,
+            max_total_claim,
+            max_num_nodes,
+        ))
+    }
+
+    /// `epoch`, or the previous epoch if not set
+    fn epoch_or_previous(&self, epoch: Option<u64>) -> anyhow::Result<u64> {
+        match epoch {
+            Some(epoch) => Ok(epoch),
+            None => self
+                .client
+                .get_epoch_info()?
+                .epoch
+                .checked_sub(1)
+                .ok_or_else(|| anyhow!("No epoch has ended yet")),
+        }
     }
 
     /// Get TipDistributionAccount account
@@ -412,123 +539,66 @@ impl TipDistributionCliHandler {
         let vote_pubkey = Pubkey::from_str(&vote_account)?;
         let (tip_dist_pda, _) =
             derive_tip_distribution_account_address(&self.program_id, &vote_pubkey, epoch);
-        println!("Tip Distribution Account Address: {}", tip_dist_pda);
 
-        let account_data = self.client.get_account(&tip_dist_pda)?.data;
-        let tip_dist: TipDistributionAccount =
-            TipDistributionAccount::try_deserialize(&mut account_data.as_slice())?;
-
-        println!("Tip Distribution Account Data:");
-        println!("  Vote Account: {}", tip_dist.validator_vote_account);
-        println!(
-            "  Merkle Root Upload Authority: {}",
-            tip_dist.merkle_root_upload_authority
-        );
-        println!("  Epoch Created At: {}", tip_dist.epoch_created_at);
-        println!(
-            "  Validator Commission BPS: {}",
-            tip_dist.validator_commission_bps
-        );
-        println!("  Expires At: {}", tip_dist.expires_at);
-        println!("  Bump: {}", tip_dist.bump);
-
-        if let Some(merkle_root) = tip_dist.merkle_root {
-            println!("  Merkle Root:");
-            println!("    Root: {:?}", merkle_root.root);
-            println!("    Max Total Claim: {}", merkle_root.max_total_claim);
-            println!("    Max Num Nodes: {}", merkle_root.max_num_nodes);
-            println!(
-                "    Total Funds Claimed: {}",
-                merkle_root.total_funds_claimed
-            );
-            println!("    Num Nodes Claimed: {}", merkle_root.num_nodes_claimed);
-        } else {
-            println!("  Merkle Root: None");
-        }
+        let account = self.client.get_account(&tip_dist_pda)?;
+        let tip_dist = decode_versioned_account::<CoreTipDistributionAccount>(&account.data)?;
 
-        Ok(())
+        self.output.print(&TipDistributionAccountView::new(
+            &tip_dist_pda,
+            account.lamports,
+            &tip_dist,
+        ))
     }
 
-    pub fn list_tip_distribution_accounts(&self) -> anyhow::Result<()> {
-        // let data_size = std::mem::size_of::<TipDistributionAccount>()
-        //     .checked_add(8)
-        //     .ok_or_else(|| anyhow!("Failed to add"))?;
-
-        let data_size = TipDistributionAccount::SIZE
-            .checked_add(8)
-            .ok_or_else(|| anyhow!("Failed to add"))?;
-        let encoded_discriminator =
-            general_purpose::STANDARD.encode(TipDistributionAccount::DISCRIMINATOR);
-        let discriminator_filter = RpcFilterType::Memcmp(Memcmp::new(
-            0,
-            MemcmpEncodedBytes::Base64(encoded_discriminator),
-        ));
-
-        let filters = vec![
-            RpcFilterType::DataSize(data_size as u64),
-            discriminator_filter,
-        ];
+    pub fn list_tip_distribution_accounts(
+        &self,
+        args: ListTipDistributionAccountsArgs,
+    ) -> anyhow::Result<()> {
+        let mut filters = Vec::new();
+        if let Some(vote_account) = args.vote_account {
+            filters.push(memcmp(
+                DISCRIMINATOR_LEN + CoreTipDistributionAccount::VALIDATOR_VOTE_ACCOUNT_OFFSET,
+                vote_account.as_ref(),
+            ));
+        }
+        if let Some(upload_authority) = args.upload_authority {
+            filters.push(memcmp(
+                DISCRIMINATOR_LEN + CoreTipDistributionAccount::MERKLE_ROOT_UPLOAD_AUTHORITY_OFFSET,
+                upload_authority.as_ref(),
+            ));
+        }
 
-        let config = RpcProgramAccountsConfig {
-            filters: Some(filters),
-            account_config: RpcAccountInfoConfig {
-                encoding: Some(UiAccountEncoding::Base64),
-                data_slice: Some(UiDataSliceConfig {
-                    offset: 0,
-                    length: data_size,
-                }),
-                commitment: None,
-                min_context_slot: None,
-            },
-            with_context: Some(false),
-            sort_results: Some(false),
+        let has_root = match (args.has_root, args.no_root) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
         };
+        let mut tip_distribution_accounts =
+            self.get_tip_distribution_accounts(filters, has_root, args.epoch)?;
 
-        let accounts = self
-            .client
-            .get_program_accounts_with_config(&self.program_id, config)?;
-
-        for (_pubkey, account) in accounts {
-            // let tip_dist: TipDistributionAccount =
-            //     TipDistributionAccount::try_deserialize(&mut account.data.as_slice())?;
-
-            let tip_dist = unsafe {
-                load_unchecked::<
-                    jito_tip_distribution_core::tip_distribution_account::TipDistributionAccount,
-                >(&account.data[8..])
-                .unwrap()
-            };
-
-            println!("Tip Distribution Account Data:");
-            // println!("  Vote Account: {}", tip_dist.validator_vote_account);
-            // println!(
-            //     "  Merkle Root Upload Authority: {}",
-            //     tip_dist.merkle_root_upload_authority
-            // );
-            println!("  Epoch Created At: {}", tip_dist.epoch_created_at);
-            println!(
-                "  Validator Commission BPS: {}",
-                tip_dist.validator_commission_bps
-            );
-            println!("  Expires At: {}", tip_dist.expires_at);
-            println!("  Bump: {}", tip_dist.bump);
-
-            if let Some(merkle_root) = tip_dist.merkle_root {
-                println!("  Merkle Root:");
-                println!("    Root: {:?}", merkle_root.root);
-                println!("    Max Total Claim: {}", merkle_root.max_total_claim);
-                println!("    Max Num Nodes: {}", merkle_root.max_num_nodes);
-                println!(
-                    "    Total Funds Claimed: {}",
-                    merkle_root.total_funds_claimed
-                );
-                println!("    Num Nodes Claimed: {}", merkle_root.num_nodes_claimed);
-            } else {
-                println!("  Merkle Root: None");
-            }
+        if args.expired {
+            let epoch = self.client.get_epoch_info()?.epoch;
+            tip_distribution_accounts.retain(|(_, _, _, tip_dist)| epoch > tip_dist.expires_at());
+        }
+        tip_distribution_accounts.sort_by_key(|(pubkey, _, _, tip_dist)| {
+            (
+                Reverse(tip_dist.epoch_created_at()),
+                tip_dist.validator_vote_account,
+                *pubkey,
+            )
+        });
+        if let Some(limit) = args.limit {
+            tip_distribution_accounts.truncate(limit);
         }
 
-        Ok(())
+        let views = tip_distribution_accounts
+            .iter()
+            .map(|(pubkey, lamports, _, tip_dist)| {
+                TipDistributionAccountView::new(pubkey, *lamports, tip_dist)
+            })
+            .collect();
+
+        self.output.print(&ListView(views))
     }
 
     /// Close TipDistributionAccount account
@@ -540,33 +610,56 @@ impl TipDistributionCliHandler {
         let (tip_distribution_pda, _tip_distribution_bump) =
             derive_tip_distribution_account_address(&self.program_id, &vote_account, epoch);
 
-        let ix = Instruction {
-            program_id: self.program_id,
-            data: jito_tip_distribution_legacy::instruction::CloseTipDistributionAccount {
-                _epoch: epoch,
-            }
-            .data(),
-            accounts: jito_tip_distribution_legacy::accounts::CloseTipDistributionAccount {
-                config: self.config_pda,
-                expired_funds_account: self.keypair.pubkey(),
-                tip_distribution_account: tip_distribution_pda,
-                validator_vote_account: vote_account,
-                signer: self.keypair.pubkey(),
-            }
-            .to_account_metas(None),
+        let config_data = self.client.get_account(&self.config_pda)?.data;
+        let config = decode_program_account::<CoreConfig>(&config_data)?;
+
+        let tip_distribution_data = self.client.get_account(&tip_distribution_pda)?.data;
+        let tip_distribution_account =
+            decode_versioned_account::<CoreTipDistributionAccount>(&tip_distribution_data)?;
+
+        // Unclaimed funds roll over into the validator's TDA for the current epoch, which can't
+        // have a merkle root yet.
+        let current_epoch = self.client.get_epoch_info()?.epoch;
+        let roll_over_tip_distribution_account = if tip_distribution_account
+            .roll_over_unclaimed_funds()
+            && current_epoch
+                <= tip_distribution_account.roll_over_deadline(config.num_epochs_valid)?
+        {
+            let (roll_over_tip_distribution_account, _) = derive_tip_distribution_account_address(
+                &self.program_id,
+                &vote_account,
+                current_epoch,
+            );
+            Some(roll_over_tip_distribution_account)
+        } else {
+            None
         };
 
-        let blockhash = self.client.get_latest_blockhash()?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&self.keypair.pubkey()),
-            &[self.keypair.clone()],
-            blockhash,
+        let ix = close_tip_distribution_account(
+            &self.program_id,
+            &self.config_pda,
+            &Pubkey::new_from_array(config.expired_funds_account),
+            &tip_distribution_pda,
+            &Pubkey::new_from_array(tip_distribution_account.rent_refund_destination),
+            &self.authority()?,
+            roll_over_tip_distribution_account.as_ref(),
         );
 
-        self.client.send_transaction(&tx)?;
+        self.process_transaction(&[ix])
+    }
 
-        Ok(())
+    /// Migrate the TipDistributionAccount to the current layout
+    pub fn migrate_tip_distribution_account(
+        &self,
+        vote_account: Pubkey,
+        epoch: u64,
+    ) -> anyhow::Result<()> {
+        let (tip_distribution_pda, _tip_distribution_bump) =
+            derive_tip_distribution_account_address(&self.program_id, &vote_account, epoch);
+
+        let ix = migrate_account(&self.program_id, &tip_distribution_pda, &self.authority()?);
+
+        self.process_transaction(&[ix])
     }
 
     /// Initialize Merkle Root Upload Config
@@ -574,34 +667,17 @@ impl TipDistributionCliHandler {
         let (merkle_root_upload_upload_config_pda, _merkle_root_upload_upload_config_bump) =
             derive_merkle_root_upload_config_account_address(&self.program_id);
 
-        let ix = Instruction {
-            program_id: self.program_id,
-            data: jito_tip_distribution_legacy::instruction::InitializeMerkleRootUploadConfig {
-                authority: self.keypair.pubkey(),
-                original_authority: self.keypair.pubkey(),
-            }
-            .data(),
-            accounts: jito_tip_distribution_legacy::accounts::InitializeMerkleRootUploadConfig {
-                config: self.config_pda,
-                merkle_root_upload_config: merkle_root_upload_upload_config_pda,
-                authority: self.keypair.pubkey(),
-                payer: self.keypair.pubkey(),
-                system_program: system_program::ID,
-            }
-            .to_account_metas(None),
-        };
-
-        let blockhash = self.client.get_latest_blockhash()?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&self.keypair.pubkey()),
-            &[self.keypair.clone()],
-            blockhash,
+        let ix = initialize_merkle_root_upload_config(
+            &self.program_id,
+            &self.config_pda,
+            &merkle_root_upload_upload_config_pda,
+            &self.authority()?,
+            &self.authority()?,
+            &self.authority()?,
+            &self.authority()?,
         );
 
-        self.client.send_transaction(&tx)?;
-
-        Ok(())
+        self.process_transaction(&[ix])
     }
 
     /// Update merkle root upload config
@@ -609,33 +685,16 @@ impl TipDistributionCliHandler {
         let (merkle_root_upload_config_pda, _merkle_root_upload_config_bump) =
             derive_merkle_root_upload_config_account_address(&self.program_id);
 
-        let ix = Instruction {
-            program_id: self.program_id,
-            data: jito_tip_distribution_legacy::instruction::UpdateMerkleRootUploadConfig {
-                authority: self.keypair.pubkey(),
-                original_authority: self.keypair.pubkey(),
-            }
-            .data(),
-            accounts: jito_tip_distribution_legacy::accounts::UpdateMerkleRootUploadConfig {
-                config: self.config_pda,
-                merkle_root_upload_config: merkle_root_upload_config_pda,
-                authority: self.keypair.pubkey(),
-                system_program: system_program::ID,
-            }
-            .to_account_metas(None),
-        };
-
-        let blockhash = self.client.get_latest_blockhash()?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&self.keypair.pubkey()),
-            &[self.keypair.clone()],
-            blockhash,
+        let ix = update_merkle_root_upload_config(
+            &self.program_id,
+            &self.config_pda,
+            &merkle_root_upload_config_pda,
+            &self.authority()?,
+            &self.authority()?,
+            &self.authority()?,
         );
 
-        self.client.send_transaction(&tx)?;
-
-        Ok(())
+        self.process_transaction(&[ix])
     }
 
     /// Migrate merkle root upload config authority
@@ -649,74 +708,256 @@ impl TipDistributionCliHandler {
         let (merkle_root_upload_config_pda, _merkle_root_upload_config_bump) =
             derive_merkle_root_upload_config_account_address(&self.program_id);
 
-        let ix = Instruction {
-            program_id: self.program_id,
-            data: jito_tip_distribution_legacy::instruction::MigrateTdaMerkleRootUploadAuthority
-                .data(),
-            accounts: jito_tip_distribution_legacy::accounts::MigrateTdaMerkleRootUploadAuthority {
-                tip_distribution_account: tip_distribution_pda,
-                merkle_root_upload_config: merkle_root_upload_config_pda,
+        let ix = migrate_tda_merkle_root_upload_authority(
+            &self.program_id,
+            &tip_distribution_pda,
+            &merkle_root_upload_config_pda,
+        );
+
+        self.process_transaction(&[ix])
+    }
+
+    /// Migrate every TDA still carrying the original upload authority and no merkle root
+    pub fn migrate_tda_merkle_root_upload_authorities(
+        &self,
+        batch_size: usize,
+    ) -> anyhow::Result<()> {
+        if batch_size == 0 {
+            return Err(anyhow!("Batch size must be greater than zero"));
+        }
+
+        let (merkle_root_upload_config_pda, _merkle_root_upload_config_bump) =
+            derive_merkle_root_upload_config_account_address(&self.program_id);
+        let merkle_root_upload_config_data = self
+            .client
+            .get_account(&merkle_root_upload_config_pda)?
+            .data;
+        let merkle_root_upload_config =
+            decode_program_account::<CoreMerkleRootUploadConfig>(&merkle_root_upload_config_data)?;
+
+        let filters = vec![
+            RpcFilterType::Memcmp(Memcmp::new(
+                DISCRIMINATOR_LEN + CoreTipDistributionAccount::MERKLE_ROOT_UPLOAD_AUTHORITY_OFFSET,
+                MemcmpEncodedBytes::Base64(
+                    general_purpose::STANDARD
+                        .encode(merkle_root_upload_config.original_upload_authority),
+                ),
+            )),
+            // `None` merkle root
+            RpcFilterType::Memcmp(Memcmp::new(
+                DISCRIMINATOR_LEN + CoreTipDistributionAccount::MERKLE_ROOT_OFFSET,
+                MemcmpEncodedBytes::Base64(general_purpose::STANDARD.encode([0])),
+            )),
+        ];
+        let mut tip_distribution_accounts = Vec::new();
+        for (pubkey, account) in
+            self.get_versioned_program_accounts_with_filters::<CoreTipDistributionAccount>(filters)?
+        {
+            if account.data.len() < CoreTipDistributionAccount::SPACE {
+                println!(
+                    "Skipping {pubkey}: it still has the version 0 layout, run \
+                     `tip-distribution-account migrate` first"
+                );
+                continue;
             }
-            .to_account_metas(None),
-        };
+            tip_distribution_accounts.push(pubkey);
+        }
 
-        let blockhash = self.client.get_latest_blockhash()?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&self.keypair.pubkey()),
-            &[self.keypair.clone()],
-            blockhash,
+        println!(
+            "Migrating {} TipDistributionAccount accounts",
+            tip_distribution_accounts.len()
         );
-
-        self.client.send_transaction(&tx)?;
+        for batch in tip_distribution_accounts.chunks(batch_size) {
+            let ix = migrate_tda_merkle_root_upload_authorities(
+                &self.program_id,
+                &merkle_root_upload_config_pda,
+                batch,
+            );
+            self.send_crank_transaction(ix, batch.len())?;
+        }
 
         Ok(())
     }
 
+    /// Add permitted uploader
+    pub fn add_permitted_uploader(&self, uploader: Pubkey) -> anyhow::Result<()> {
+        let (merkle_root_upload_config_pda, _merkle_root_upload_config_bump) =
+            derive_merkle_root_upload_config_account_address(&self.program_id);
+
+        let ix = add_permitted_uploader(
+            &self.program_id,
+            &self.config_pda,
+            &merkle_root_upload_config_pda,
+            &self.authority()?,
+            &uploader,
+        );
+
+        self.process_transaction(&[ix])
+    }
+
+    /// Remove permitted uploader
+    pub fn remove_permitted_uploader(&self, uploader: Pubkey) -> anyhow::Result<()> {
+        let (merkle_root_upload_config_pda, _merkle_root_upload_config_bump) =
+            derive_merkle_root_upload_config_account_address(&self.program_id);
+
+        let ix = remove_permitted_uploader(
+            &self.program_id,
+            &self.config_pda,
+            &merkle_root_upload_config_pda,
+            &self.authority()?,
+            &uploader,
+        );
+
+        self.process_transaction(&[ix])
+    }
+
     /// Claim
     pub fn claim(
         &self,
         vote_account: Pubkey,
         epoch: u64,
         claimant: Pubkey,
-        amount: u64,
+        amount: Option<u64>,
+        merkle_tree: Option<PathBuf>,
     ) -> anyhow::Result<()> {
         let (tip_distribution_pda, _tip_distribution_bump) =
             derive_tip_distribution_account_address(&self.program_id, &vote_account, epoch);
-        let (claim_status_pda, claim_status_bump) =
-            derive_claim_status_account_address(&self.program_id, &claimant, &tip_distribution_pda);
-
-        let proof = vec![];
 
-        let ix = Instruction {
-            program_id: self.program_id,
-            data: jito_tip_distribution_legacy::instruction::Claim {
-                bump: claim_status_bump,
-                amount,
-                proof,
-            }
-            .data(),
-            accounts: jito_tip_distribution_legacy::accounts::Claim {
-                config: self.config_pda,
-                tip_distribution_account: tip_distribution_pda,
-                merkle_root_upload_authority: self.keypair.pubkey(),
-                claim_status: claim_status_pda,
-                claimant,
-                payer: self.keypair.pubkey(),
-                system_program: system_program::ID,
+        let (amount, proof) = match merkle_tree {
+            Some(merkle_tree) => {
+                let tree = MerkleTreeFile::read(&merkle_tree)?;
+                if tree.tip_distribution_account != tip_distribution_pda {
+                    bail!(
+                        "The merkle tree is for {}, not {tip_distribution_pda}",
+                        tree.tip_distribution_account
+                    );
+                }
+                self.check_merkle_root(&tree)?;
+
+                let node = tree
+                    .node(&claimant)
+                    .ok_or_else(|| anyhow!("{claimant} isn't part of the merkle tree"))?;
+                if amount.is_some_and(|amount| amount != node.amount) {
+                    bail!(
+                        "The merkle tree entitles {claimant} to {} lamports",
+                        node.amount
+                    );
+                }
+                (node.amount, node.proof.clone())
             }
-            .to_account_metas(None),
+            None => (
+                amount.ok_or_else(|| anyhow!("Either --amount or --merkle-tree is required"))?,
+                Vec::new(),
+            ),
         };
 
-        let blockhash = self.client.get_latest_blockhash()?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&self.keypair.pubkey()),
-            &[self.keypair.clone()],
-            blockhash,
+        let ix = self.claim_instruction(
+            &tip_distribution_pda,
+            &vote_account,
+            &claimant,
+            amount,
+            &proof,
+        )?;
+
+        self.process_transaction(&[ix])
+    }
+
+    /// Claim every node of the merkle tree whose ClaimStatus doesn't exist yet
+    pub fn claim_all(&self, merkle_tree: &Path) -> anyhow::Result<()> {
+        let tree = MerkleTreeFile::read(merkle_tree)?;
+        self.check_merkle_root(&tree)?;
+
+        let mut num_claimed: usize = 0;
+        let mut num_skipped: usize = 0;
+        for nodes in tree.tree_nodes.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let claim_statuses: Vec<Pubkey> = nodes
+                .iter()
+                .map(|node| {
+                    derive_claim_status_account_address(
+                        &self.program_id,
+                        &node.claimant,
+                        &tree.tip_distribution_account,
+                    )
+                    .0
+                })
+                .collect();
+            let accounts = self.client.get_multiple_accounts(&claim_statuses)?;
+
+            for (node, account) in nodes.iter().zip(accounts) {
+                if account.is_some() {
+                    num_skipped = num_skipped.saturating_add(1);
+                    continue;
+                }
+
+                println!("Claiming {} lamports for {}", node.amount, node.claimant);
+                let ix = self.claim_instruction(
+                    &tree.tip_distribution_account,
+                    &tree.vote_account,
+                    &node.claimant,
+                    node.amount,
+                    &node.proof,
+                )?;
+                self.process_transaction(&[ix])?;
+                num_claimed = num_claimed.saturating_add(1);
+            }
+        }
+
+        println!("Claimed {num_claimed} nodes, skipped {num_skipped} already claimed");
+
+        Ok(())
+    }
+
+    fn claim_instruction(
+        &self,
+        tip_distribution_account: &Pubkey,
+        vote_account: &Pubkey,
+        claimant: &Pubkey,
+        amount: u64,
+        proof: &[[u8; 32]],
+    ) -> anyhow::Result<Instruction> {
+        let (claim_status_pda, claim_status_bump) = derive_claim_status_account_address(
+            &self.program_id,
+            claimant,
+            tip_distribution_account,
         );
 
-        self.client.send_transaction(&tx)?;
+        Ok(claim(
+            &self.program_id,
+            &self.config_pda,
+            tip_distribution_account,
+            &self.authority()?,
+            &claim_status_pda,
+            claimant,
+            &self.authority()?,
+            vote_account,
+            None,
+            claim_status_bump,
+            amount,
+            proof,
+        ))
+    }
+
+    /// Fails unless the root of `tree` is the one uploaded to its TipDistributionAccount
+    fn check_merkle_root(&self, tree: &MerkleTreeFile) -> anyhow::Result<()> {
+        let account_data = self
+            .client
+            .get_account(&tree.tip_distribution_account)?
+            .data;
+        let tip_dist = decode_versioned_account::<CoreTipDistributionAccount>(&account_data)?;
+        let merkle_root = tip_dist.merkle_root().ok_or_else(|| {
+            anyhow!(
+                "No merkle root has been uploaded to {}",
+                tree.tip_distribution_account
+            )
+        })?;
+
+        if merkle_root.root != tree.merkle_root {
+            bail!(
+                "The merkle tree root {} doesn't match the uploaded root {}",
+                to_hex(&tree.merkle_root),
+                to_hex(&merkle_root.root)
+            );
+        }
 
         Ok(())
     }
@@ -738,27 +979,18 @@ impl TipDistributionCliHandler {
         // Then derive claim status PDA using same seeds as in the program
         let (claim_status_pda, _) = Pubkey::find_program_address(
             &[
-                ClaimStatus::SEED,
+                CoreClaimStatus::SEED,
                 claimant_pubkey.as_ref(),
                 tip_dist_pda.as_ref(),
             ],
             &self.program_id,
         );
-        println!("Claim Status Account Address: {}", claim_status_pda);
 
         let account_data = self.client.get_account(&claim_status_pda)?.data;
-        let claim_status: ClaimStatus = ClaimStatus::try_deserialize(&mut account_data.as_slice())?;
-
-        println!("Claim Status Data:");
-        println!("  Is Claimed: {}", claim_status.is_claimed);
-        println!("  Claimant: {}", claim_status.claimant);
-        println!("  Claim Status Payer: {}", claim_status.claim_status_payer);
-        println!("  Slot Claimed At: {}", claim_status.slot_claimed_at);
-        println!("  Amount: {}", claim_status.amount);
-        println!("  Expires At: {}", claim_status.expires_at);
-        println!("  Bump: {}", claim_status.bump);
+        let claim_status = decode_program_account::<CoreClaimStatus>(&account_data)?;
 
-        Ok(())
+        self.output
+            .print(&ClaimStatusView::new(&claim_status_pda, &claim_status))
     }
 
     /// Close claim status
@@ -773,27 +1005,506 @@ impl TipDistributionCliHandler {
         let (claim_status_pda, _claim_status_bump) =
             derive_claim_status_account_address(&self.program_id, &claimant, &tip_distribution_pda);
 
-        let ix = Instruction {
-            program_id: self.program_id,
-            data: jito_tip_distribution_legacy::instruction::CloseClaimStatus.data(),
-            accounts: jito_tip_distribution_legacy::accounts::CloseClaimStatus {
-                config: self.config_pda,
-                claim_status: claim_status_pda,
-                claim_status_payer: self.keypair.pubkey(),
+        // The rent goes back to whoever paid for the ClaimStatus.
+        let claim_status_data = self.client.get_account(&claim_status_pda)?.data;
+        let claim_status = decode_program_account::<CoreClaimStatus>(&claim_status_data)?;
+
+        let ix = close_claim_status(
+            &self.program_id,
+            &self.config_pda,
+            &claim_status_pda,
+            &Pubkey::new_from_array(claim_status.claim_status_payer),
+            &claimant,
+            &tip_distribution_pda,
+        );
+
+        self.process_transaction(&[ix])
+    }
+
+    /// Close every expired ClaimStatus and TipDistributionAccount with the batch close
+    /// instructions, `batch_size` accounts per transaction.
+    ///
+    /// TipDistributionAccounts that have token vaults or roll over unclaimed funds are left alone
+    /// since they can only be closed one at a time.
+    pub fn crank(&self, batch_size: usize) -> anyhow::Result<()> {
+        if batch_size == 0 {
+            return Err(anyhow!("Batch size must be greater than zero"));
+        }
+
+        let epoch = self.client.get_epoch_info()?.epoch;
+
+        let config_data = self.client.get_account(&self.config_pda)?.data;
+        let config = decode_program_account::<CoreConfig>(&config_data)?;
+        let expired_funds_account = Pubkey::new_from_array(config.expired_funds_account);
+
+        let mut claim_statuses = Vec::new();
+        for (pubkey, data) in self.get_program_accounts::<CoreClaimStatus>()? {
+            let claim_status = decode_program_account::<CoreClaimStatus>(&data)?;
+            if epoch > claim_status.expires_at {
+                claim_statuses.push((
+                    pubkey,
+                    Pubkey::new_from_array(claim_status.claim_status_payer),
+                ));
             }
-            .to_account_metas(None),
-        };
+        }
 
-        let blockhash = self.client.get_latest_blockhash()?;
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&self.keypair.pubkey()),
-            &[self.keypair.clone()],
-            blockhash,
+        println!(
+            "Closing {} expired ClaimStatus accounts",
+            claim_statuses.len()
         );
+        for batch in claim_statuses.chunks(batch_size) {
+            let ix = close_claim_statuses(&self.program_id, &self.config_pda, batch);
+            self.send_crank_transaction(ix, batch.len())?;
+        }
 
-        self.client.send_transaction(&tx)?;
+        let mut tip_distribution_accounts = Vec::new();
+        for (pubkey, account) in self
+            .get_versioned_program_accounts_with_filters::<CoreTipDistributionAccount>(Vec::new())?
+        {
+            let tip_distribution_account =
+                decode_versioned_account::<CoreTipDistributionAccount>(&account.data)?;
+            if epoch <= tip_distribution_account.expires_at() {
+                continue;
+            }
+            if tip_distribution_account.roll_over_unclaimed_funds()
+                && epoch <= tip_distribution_account.roll_over_deadline(config.num_epochs_valid)?
+            {
+                println!("Skipping {pubkey}: it rolls over unclaimed funds, close it individually");
+                continue;
+            }
+            if tip_distribution_account.num_token_vaults() > 0 {
+                println!("Skipping {pubkey}: it holds tokens, close it individually");
+                continue;
+            }
+
+            tip_distribution_accounts.push((
+                pubkey,
+                Pubkey::new_from_array(tip_distribution_account.rent_refund_destination),
+            ));
+        }
+
+        println!(
+            "Closing {} expired TipDistributionAccount accounts",
+            tip_distribution_accounts.len()
+        );
+        for batch in tip_distribution_accounts.chunks(batch_size) {
+            let ix = close_tip_distribution_accounts(
+                &self.program_id,
+                &self.config_pda,
+                &expired_funds_account,
+                batch,
+            );
+            self.send_crank_transaction(ix, batch.len())?;
+        }
 
         Ok(())
     }
+
+    /// Recompute the leaf and root of a claim the way the program does and report whether the
+    /// proof matches
+    pub fn verify_proof(&self, args: VerifyProofArgs) -> anyhow::Result<()> {
+        let tree = args
+            .merkle_tree
+            .as_deref()
+            .map(MerkleTreeFile::read)
+            .transpose()?;
+        let node = match &tree {
+            Some(tree) => Some(
+                tree.node(&args.claimant)
+                    .ok_or_else(|| anyhow!("{} isn't part of the merkle tree", args.claimant))?,
+            ),
+            None => None,
+        };
+
+        let amount = match (args.amount, node) {
+            (Some(amount), _) => amount,
+            (None, Some(node)) => node.amount,
+            (None, None) => bail!("Either --amount or --merkle-tree is required"),
+        };
+        let proof = match (&args.proof_file, node) {
+            (Some(proof_file), _) => ProofFile::read(proof_file)?.0,
+            (None, Some(node)) => node.proof.clone(),
+            (None, None) => args
+                .proof
+                .iter()
+                .map(|hash| from_hex(hash))
+                .collect::<anyhow::Result<_>>()?,
+        };
+        let root = match (&args.root, args.vote_account, args.epoch, &tree) {
+            (Some(root), _, _, _) => from_hex(root)?,
+            (None, Some(vote_account), Some(epoch), _) => {
+                let (tip_distribution_pubkey, _tip_distribution_bump) =
+                    derive_tip_distribution_account_address(&self.program_id, &vote_account, epoch);
+                let account_data = self.client.get_account(&tip_distribution_pubkey)?.data;
+                let tip_dist =
+                    decode_versioned_account::<CoreTipDistributionAccount>(&account_data)?;
+                tip_dist
+                    .merkle_root()
+                    .ok_or_else(|| {
+                        anyhow!("No merkle root has been uploaded to {tip_distribution_pubkey}")
+                    })?
+                    .root
+            }
+            (None, _, _, Some(tree)) => tree.merkle_root,
+            _ => bail!("One of --root, --vote-account and --epoch, or --merkle-tree is required"),
+        };
+
+        self.output.print(&ProofVerificationView::new(
+            &args.claimant,
+            amount,
+            &proof,
+            &root,
+        ))
+    }
+
+    /// Reconcile the lamports tipped to the TipDistributionAccounts of an epoch with what has
+    /// been claimed, can still be claimed and is left to roll over or expire
+    pub fn report(&self, args: ReportArgs) -> anyhow::Result<()> {
+        let epoch = self.epoch_or_previous(args.epoch)?;
+        let current_epoch = self.client.get_epoch_info()?.epoch;
+
+        let config_data = self.client.get_account(&self.config_pda)?.data;
+        let config = decode_program_account::<CoreConfig>(&config_data)?;
+
+        let mut tip_distribution_accounts =
+            self.get_tip_distribution_accounts(Vec::new(), None, Some(epoch))?;
+        tip_distribution_accounts
+            .sort_by_key(|(pubkey, _, _, tip_dist)| (tip_dist.validator_vote_account, *pubkey));
+
+        // Accounts that haven't been migrated yet are smaller, so rent is fetched per size.
+        let mut rent_by_len = BTreeMap::new();
+        for (_, _, data_len, _) in &tip_distribution_accounts {
+            if !rent_by_len.contains_key(data_len) {
+                let rent = self
+                    .client
+                    .get_minimum_balance_for_rent_exemption(*data_len)?;
+                rent_by_len.insert(*data_len, rent);
+            }
+        }
+
+        let rows = tip_distribution_accounts
+            .iter()
+            .map(|(pubkey, lamports, data_len, tip_dist)| {
+                let unclaimed = lamports.saturating_sub(rent_by_len[data_len]);
+                let merkle_root = tip_dist.merkle_root();
+                let claimed = merkle_root.map_or(0, |root| root.total_funds_claimed);
+                let expired = current_epoch > tip_dist.expires_at();
+
+                let mut row = EpochReportRowView {
+                    tip_distribution_account: pubkey.to_string(),
+                    vote_account: Pubkey::new_from_array(tip_dist.validator_vote_account)
+                        .to_string(),
+                    lamports: *lamports,
+                    merkle_root: merkle_root.map(|root| to_hex(&root.root)),
+                    max_total_claim: merkle_root.map_or(0, |root| root.max_total_claim),
+                    max_num_nodes: merkle_root.map_or(0, |root| root.max_num_nodes),
+                    num_nodes_claimed: merkle_root.map_or(0, |root| root.num_nodes_claimed),
+                    tipped: unclaimed.saturating_add(claimed),
+                    claimed,
+                    claimable: 0,
+                    unallocated: 0,
+                    rolling_over: 0,
+                    expired: 0,
+                    expires_at: tip_dist.expires_at(),
+                    flags: Vec::new(),
+                };
+
+                if merkle_root.is_none() {
+                    row.flags.push(ReportFlag::NoMerkleRoot);
+                }
+
+                if expired {
+                    row.flags.push(ReportFlag::ExpiredNotClosed);
+                    // Unclaimed funds go to the expired funds account on closure, unless they
+                    // still roll over into a later account of the validator.
+                    if tip_dist.roll_over_unclaimed_funds()
+                        && current_epoch <= tip_dist.roll_over_deadline(config.num_epochs_valid)?
+                    {
+                        row.rolling_over = unclaimed;
+                    } else {
+                        row.expired = unclaimed;
+                    }
+                } else {
+                    let left_to_claim = row.max_total_claim.saturating_sub(claimed);
+                    row.claimable = left_to_claim.min(unclaimed);
+                    row.unallocated = unclaimed.saturating_sub(row.claimable);
+                    if left_to_claim > unclaimed {
+                        row.flags.push(ReportFlag::Underfunded);
+                    }
+                }
+
+                Ok(row)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let claim_statuses = if args.scan_claim_statuses {
+            // ClaimStatus accounts don't store their TipDistributionAccount, but copy its
+            // `expires_at`.
+            let expires_at: BTreeSet<u64> = tip_distribution_accounts
+                .iter()
+                .map(|(_, _, _, tip_dist)| tip_dist.expires_at())
+                .collect();
+
+            const EXPIRES_AT_OFFSET: usize =
+                DISCRIMINATOR_LEN + core::mem::offset_of!(CoreClaimStatus, expires_at);
+
+            let mut totals = ClaimStatusTotalsView::default();
+            for expires_at in expires_at {
+                let filters = vec![memcmp(EXPIRES_AT_OFFSET, &expires_at.to_le_bytes())];
+                for (_pubkey, account) in
+                    self.get_program_accounts_with_filters::<CoreClaimStatus>(filters)?
+                {
+                    let claim_status = decode_program_account::<CoreClaimStatus>(&account.data)?;
+                    if claim_status.is_claimed {
+                        totals.claim_statuses = totals.claim_statuses.saturating_add(1);
+                        totals.claimed = totals.claimed.saturating_add(claim_status.amount);
+                    }
+                }
+            }
+            Some(totals)
+        } else {
+            None
+        };
+
+        self.output.print(&EpochReportView::new(
+            epoch,
+            current_epoch,
+            rows,
+            claim_statuses,
+        ))
+    }
+
+    /// Fetch and decode the TipDistributionAccounts matching `filters` along with their lamports
+    /// and data length,
+    /// narrowed down to those with or without a merkle root and created for `epoch`.
+    fn get_tip_distribution_accounts(
+        &self,
+        filters: Vec<RpcFilterType>,
+        has_root: Option<bool>,
+        epoch: Option<u64>,
+    ) -> anyhow::Result<Vec<(Pubkey, u64, usize, CoreTipDistributionAccount)>> {
+        // The epoch moves back by the size of a merkle root once one is uploaded, so accounts
+        // with and without a root are fetched separately when filtering by epoch.
+        let root_states: &[bool] = match (has_root, epoch) {
+            (Some(true), _) => &[true],
+            (Some(false), _) => &[false],
+            (None, Some(_)) => &[false, true],
+            (None, None) => &[],
+        };
+        let accounts = if root_states.is_empty() {
+            self.get_versioned_program_accounts_with_filters::<CoreTipDistributionAccount>(filters)?
+        } else {
+            let mut accounts = Vec::new();
+            for has_root in root_states {
+                let mut filters = filters.clone();
+                filters.push(memcmp(MERKLE_ROOT_TAG_OFFSET, &[u8::from(*has_root)]));
+                if let Some(epoch) = epoch {
+                    filters.push(memcmp(
+                        epoch_created_at_offset(*has_root),
+                        &epoch.to_le_bytes(),
+                    ));
+                }
+                accounts.extend(
+                    self.get_versioned_program_accounts_with_filters::<CoreTipDistributionAccount>(
+                        filters,
+                    )?,
+                );
+            }
+            accounts
+        };
+
+        accounts
+            .into_iter()
+            .map(|(pubkey, account)| {
+                let tip_dist =
+                    decode_versioned_account::<CoreTipDistributionAccount>(&account.data)?;
+                Ok((pubkey, account.lamports, account.data.len(), tip_dist))
+            })
+            .collect()
+    }
+
+    /// Fetch the raw data of every program account of type `T`
+    fn get_program_accounts<T: ProgramAccount>(&self) -> anyhow::Result<Vec<(Pubkey, Vec<u8>)>> {
+        Ok(self
+            .get_program_accounts_with_filters::<T>(Vec::new())?
+            .into_iter()
+            .map(|(pubkey, account)| (pubkey, account.data))
+            .collect())
+    }
+
+    /// Fetch every program account of type `T` matching `filters`.
+    fn get_program_accounts_with_filters<T: ProgramAccount>(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> anyhow::Result<Vec<(Pubkey, Account)>> {
+        self.get_program_accounts_with_size::<T>(filters, T::SPACE)
+    }
+
+    /// Like [Self::get_program_accounts_with_filters], but also fetches the accounts that haven't
+    /// been migrated off the version 0 layout yet. The memcmp `filters` must fall within it.
+    fn get_versioned_program_accounts_with_filters<T: VersionedAccount>(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> anyhow::Result<Vec<(Pubkey, Account)>> {
+        let mut accounts = self.get_program_accounts_with_size::<T>(filters.clone(), T::SPACE)?;
+        accounts.extend(self.get_program_accounts_with_size::<T>(filters, T::V0_SPACE)?);
+        Ok(accounts)
+    }
+
+    /// Fetch every program account of type `T` matching `filters` whose data is `space` bytes.
+    fn get_program_accounts_with_size<T: ProgramAccount>(
+        &self,
+        mut filters: Vec<RpcFilterType>,
+        space: usize,
+    ) -> anyhow::Result<Vec<(Pubkey, Account)>> {
+        let encoded_discriminator = general_purpose::STANDARD.encode(T::DISCRIMINATOR);
+        filters.push(RpcFilterType::DataSize(space as u64));
+        filters.push(RpcFilterType::Memcmp(Memcmp::new(
+            0,
+            MemcmpEncodedBytes::Base64(encoded_discriminator),
+        )));
+
+        let config = RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: None,
+                commitment: None,
+                min_context_slot: None,
+            },
+            with_context: Some(false),
+            sort_results: Some(false),
+        };
+
+        Ok(self
+            .client
+            .get_program_accounts_with_config(&self.program_id, config)?)
+    }
+
+    fn send_crank_transaction(&self, ix: Instruction, num_accounts: usize) -> anyhow::Result<()> {
+        println!("Processing a batch of {num_accounts} accounts");
+
+        self.process_transaction(&[ix])
+    }
+
+    /// The authority the instructions are built for: `--authority` or the keypair
+    fn authority(&self) -> anyhow::Result<Pubkey> {
+        match self.transaction_options.authority {
+            Some(authority) => Ok(authority),
+            None => Ok(self.keypair.get()?.pubkey()),
+        }
+    }
+
+    fn process_transaction(&self, instructions: &[Instruction]) -> anyhow::Result<()> {
+        process_transaction::<TipDistributionError>(
+            &self.client,
+            &self.keypair,
+            instructions,
+            self.transaction_options,
+            self.output,
+        )
+    }
+}
+
+/// Matches accounts holding `bytes` at `offset`.
+/// Offset of the merkle root's `Option` tag in [CoreTipDistributionAccount] data.
+const MERKLE_ROOT_TAG_OFFSET: usize =
+    DISCRIMINATOR_LEN + CoreTipDistributionAccount::MERKLE_ROOT_OFFSET;
+
+/// Offset of `epoch_created_at` in [CoreTipDistributionAccount] data, which moves back by
+/// [MerkleRoot::LEN] bytes once a merkle root has been uploaded.
+const fn epoch_created_at_offset(has_root: bool) -> usize {
+    const WITHOUT_ROOT: usize =
+        MERKLE_ROOT_TAG_OFFSET + 1 + CoreTipDistributionAccount::EPOCH_CREATED_AT_OFFSET;
+    const WITH_ROOT: usize = WITHOUT_ROOT + MerkleRoot::LEN;
+
+    if has_root {
+        WITH_ROOT
+    } else {
+        WITHOUT_ROOT
+    }
+}
+
+fn memcmp(offset: usize, bytes: &[u8]) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new(
+        offset,
+        MemcmpEncodedBytes::Base64(general_purpose::STANDARD.encode(bytes)),
+    ))
+}
+
+/// Copies the zero-copy program account `T` out of raw account data, checking its length and
+/// discriminator. RPC buffers aren't guaranteed to be aligned for `T`.
+pub(crate) fn decode_program_account<T: ProgramAccount>(data: &[u8]) -> anyhow::Result<T> {
+    if data.len() != T::SPACE || data[..DISCRIMINATOR_LEN] != T::DISCRIMINATOR {
+        return Err(anyhow!("Account data is not a valid program account"));
+    }
+
+    Ok(unsafe { std::ptr::read_unaligned(data[DISCRIMINATOR_LEN..].as_ptr() as *const T) })
+}
+
+/// Like [decode_program_account], but also accepts the version 0 layout, decoding it with the
+/// fields added since then backfilled the way `MigrateAccount` would.
+pub(crate) fn decode_versioned_account<T: VersionedAccount>(data: &[u8]) -> anyhow::Result<T> {
+    if data.len() != T::V0_SPACE {
+        return decode_program_account(data);
+    }
+
+    let mut migrated = data.to_vec();
+    migrated.resize(T::SPACE, 0);
+    let mut account = decode_program_account::<T>(&migrated)?;
+    account.backfill(0);
+    Ok(account)
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::program_account::{ProgramAccount, DISCRIMINATOR_LEN};
+    use jito_tip_distribution_core::{
+        merkle_root::MerkleRoot,
+        tip_distribution_account::TipDistributionAccount as CoreTipDistributionAccount,
+    };
+
+    use super::{epoch_created_at_offset, MERKLE_ROOT_TAG_OFFSET};
+
+    fn account_data(tip_distribution_account: CoreTipDistributionAccount) -> Vec<u8> {
+        let mut data = vec![0; CoreTipDistributionAccount::SPACE];
+        data[..DISCRIMINATOR_LEN].copy_from_slice(&CoreTipDistributionAccount::DISCRIMINATOR);
+        unsafe {
+            core::ptr::write_unaligned(
+                data[DISCRIMINATOR_LEN..].as_mut_ptr() as *mut CoreTipDistributionAccount,
+                tip_distribution_account,
+            );
+        }
+        data
+    }
+
+    #[test]
+    fn epoch_filter_offsets_match_layouts() {
+        let epoch = 0x0102_0304_0506_0708u64;
+        let mut tip_distribution_account: CoreTipDistributionAccount =
+            unsafe { core::mem::zeroed() };
+        tip_distribution_account
+            .initialize([1; 32], epoch, 500, [2; 32], 255, 3, [3; 32], false)
+            .unwrap();
+
+        let data = account_data(tip_distribution_account);
+        assert_eq!(data[MERKLE_ROOT_TAG_OFFSET], 0);
+        let offset = epoch_created_at_offset(false);
+        assert_eq!(data[offset..offset + 8], epoch.to_le_bytes());
+
+        let mut tip_distribution_account: CoreTipDistributionAccount =
+            unsafe { core::ptr::read_unaligned(data[DISCRIMINATOR_LEN..].as_ptr() as *const _) };
+        tip_distribution_account.set_merkle_root(&MerkleRoot {
+            root: [9; 32],
+            max_total_claim: 1,
+            max_num_nodes: 1,
+            total_funds_claimed: 0,
+            num_nodes_claimed: 0,
+        });
+
+        let data = account_data(tip_distribution_account);
+        assert_eq!(data[MERKLE_ROOT_TAG_OFFSET], 1);
+        let offset = epoch_created_at_offset(true);
+        assert_eq!(data[offset..offset + 8], epoch.to_le_bytes());
+    }
 }
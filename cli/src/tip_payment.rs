@@ -0,0 +1,44 @@
+use clap::Subcommand;
+use solana_pubkey::Pubkey;
+
+/// The CLI handler for the jito-tip-payment program
+#[derive(Subcommand)]
+pub enum TipPaymentCommands {
+    /// Get the config struct
+    Config {
+        #[command(subcommand)]
+        action: ConfigActions,
+    },
+
+    /// Initialize the config and the tip payment accounts
+    Initialize,
+
+    /// Pay out the tips and hand the following tips to a new tip receiver
+    ChangeTipReceiver {
+        /// New tip receiver pubkey
+        new_tip_receiver: Pubkey,
+    },
+
+    /// Pay out the tips and hand the block builder commission to a new block builder
+    ChangeBlockBuilder {
+        /// New block builder pubkey
+        new_block_builder: Pubkey,
+
+        /// Block builder commission percentage
+        #[arg(long)]
+        commission: u64,
+    },
+
+    /// Pay out the tips to the current tip receiver and block builder
+    ClaimTips,
+
+    /// List the tip payment accounts and their claimable lamports
+    TipAccounts,
+}
+
+/// The actions that can be performed on the tip payment config
+#[derive(Subcommand)]
+pub enum ConfigActions {
+    /// Get the config
+    Get,
+}
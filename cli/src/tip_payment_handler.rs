@@ -0,0 +1,235 @@
+use anyhow::anyhow;
+use jito_tip_payment_core::{config::Config, tip_payment_account::TIP_ACCOUNT_SEEDS};
+use jito_tip_payment_sdk::{
+    error::TipPaymentError,
+    sdk::{change_block_builder, change_tip_receiver, initialize_config},
+};
+use solana_client::rpc_client::RpcClient;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+use crate::{
+    output::OutputFormat,
+    tip_distribution_handler::decode_program_account,
+    tip_payment::{ConfigActions, TipPaymentCommands},
+    transaction::{process_transaction, LazyKeypair, TransactionOptions},
+    views::{TipAccountView, TipAccountsView, TipPaymentConfigView},
+};
+
+pub struct TipPaymentCliHandler {
+    /// RPC Client
+    client: RpcClient,
+
+    /// Keypair
+    keypair: LazyKeypair,
+
+    /// The Pubkey of Jito Tip Payment Program ID
+    program_id: Pubkey,
+
+    /// Config PDA
+    config_pda: Pubkey,
+
+    /// Tip payment account PDAs
+    tip_payment_accounts: [Pubkey; 8],
+
+    /// How read commands print accounts
+    output: OutputFormat,
+
+    /// How mutating commands submit transactions
+    transaction_options: TransactionOptions,
+}
+
+impl TipPaymentCliHandler {
+    pub fn new(
+        client: RpcClient,
+        keypair: LazyKeypair,
+        program_id: Pubkey,
+        output: OutputFormat,
+        transaction_options: TransactionOptions,
+    ) -> Self {
+        let (config_pda, _config_bump) = Pubkey::find_program_address(Config::seeds(), &program_id);
+        let tip_payment_accounts =
+            TIP_ACCOUNT_SEEDS.map(|seed| Pubkey::find_program_address(&[seed], &program_id).0);
+
+        Self {
+            client,
+            keypair,
+            program_id,
+            config_pda,
+            tip_payment_accounts,
+            output,
+            transaction_options,
+        }
+    }
+
+    /// Handle tip_payment_program operations
+    pub fn handle(&self, action: TipPaymentCommands) -> anyhow::Result<()> {
+        match action {
+            TipPaymentCommands::Config {
+                action: ConfigActions::Get,
+            } => self.get_config(),
+            TipPaymentCommands::Initialize => self.initialize(),
+            TipPaymentCommands::ChangeTipReceiver { new_tip_receiver } => {
+                self.change_tip_receiver(new_tip_receiver)
+            }
+            TipPaymentCommands::ChangeBlockBuilder {
+                new_block_builder,
+                commission,
+            } => self.change_block_builder(new_block_builder, commission),
+            TipPaymentCommands::ClaimTips => self.claim_tips(),
+            TipPaymentCommands::TipAccounts => self.get_tip_accounts(),
+        }
+    }
+
+    /// Get TipPayment config
+    pub fn get_config(&self) -> anyhow::Result<()> {
+        let config = self.fetch_config()?;
+
+        self.output
+            .print(&TipPaymentConfigView::new(&self.config_pda, &config))
+    }
+
+    /// Initialize the config and the tip payment accounts, the signer becomes the tip receiver
+    /// and block builder
+    pub fn initialize(&self) -> anyhow::Result<()> {
+        let [a0, a1, a2, a3, a4, a5, a6, a7] = &self.tip_payment_accounts;
+        let ix = initialize_config(
+            &self.program_id,
+            &self.config_pda,
+            a0,
+            a1,
+            a2,
+            a3,
+            a4,
+            a5,
+            a6,
+            a7,
+            &self.authority()?,
+        );
+
+        self.send_transaction(ix)
+    }
+
+    /// Change tip receiver
+    pub fn change_tip_receiver(&self, new_tip_receiver: Pubkey) -> anyhow::Result<()> {
+        let config = self.fetch_config()?;
+
+        let [a0, a1, a2, a3, a4, a5, a6, a7] = &self.tip_payment_accounts;
+        let ix = change_tip_receiver(
+            &self.program_id,
+            &self.config_pda,
+            &Pubkey::new_from_array(config.tip_receiver),
+            &new_tip_receiver,
+            &Pubkey::new_from_array(config.block_builder),
+            a0,
+            a1,
+            a2,
+            a3,
+            a4,
+            a5,
+            a6,
+            a7,
+            &self.authority()?,
+        );
+
+        self.send_transaction(ix)
+    }
+
+    /// Change block builder
+    pub fn change_block_builder(
+        &self,
+        new_block_builder: Pubkey,
+        commission: u64,
+    ) -> anyhow::Result<()> {
+        let config = self.fetch_config()?;
+
+        let [a0, a1, a2, a3, a4, a5, a6, a7] = &self.tip_payment_accounts;
+        let ix = change_block_builder(
+            &self.program_id,
+            &self.config_pda,
+            &Pubkey::new_from_array(config.tip_receiver),
+            &Pubkey::new_from_array(config.block_builder),
+            &new_block_builder,
+            a0,
+            a1,
+            a2,
+            a3,
+            a4,
+            a5,
+            a6,
+            a7,
+            &self.authority()?,
+            commission,
+        );
+
+        self.send_transaction(ix)
+    }
+
+    /// Pay out the tips by handing them to the current tip receiver again
+    pub fn claim_tips(&self) -> anyhow::Result<()> {
+        let config = self.fetch_config()?;
+
+        self.change_tip_receiver(Pubkey::new_from_array(config.tip_receiver))
+    }
+
+    /// List the tip payment accounts and their lamports above the rent-exempt minimum
+    pub fn get_tip_accounts(&self) -> anyhow::Result<()> {
+        let accounts = self
+            .client
+            .get_multiple_accounts(&self.tip_payment_accounts)?;
+
+        let mut tip_accounts = Vec::with_capacity(accounts.len());
+        let mut total_claimable_lamports: u64 = 0;
+        for (index, (pubkey, account)) in self.tip_payment_accounts.iter().zip(accounts).enumerate()
+        {
+            let claimable_lamports = match account {
+                Some(account) => {
+                    let rent = self
+                        .client
+                        .get_minimum_balance_for_rent_exemption(account.data.len())?;
+                    let claimable_lamports = account.lamports.saturating_sub(rent);
+                    total_claimable_lamports = total_claimable_lamports
+                        .checked_add(claimable_lamports)
+                        .ok_or_else(|| anyhow!("Claimable lamports overflow"))?;
+                    Some(claimable_lamports)
+                }
+                None => None,
+            };
+
+            tip_accounts.push(TipAccountView {
+                index,
+                address: pubkey.to_string(),
+                claimable_lamports,
+            });
+        }
+
+        self.output.print(&TipAccountsView {
+            tip_accounts,
+            total_claimable_lamports,
+        })
+    }
+
+    fn fetch_config(&self) -> anyhow::Result<Config> {
+        let config_data = self.client.get_account(&self.config_pda)?.data;
+        decode_program_account::<Config>(&config_data)
+    }
+
+    /// The authority the instructions are built for: `--authority` or the keypair
+    fn authority(&self) -> anyhow::Result<Pubkey> {
+        match self.transaction_options.authority {
+            Some(authority) => Ok(authority),
+            None => Ok(self.keypair.get()?.pubkey()),
+        }
+    }
+
+    fn send_transaction(&self, ix: Instruction) -> anyhow::Result<()> {
+        process_transaction::<TipPaymentError>(
+            &self.client,
+            &self.keypair,
+            &[ix],
+            self.transaction_options,
+            self.output,
+        )
+    }
+}
@@ -0,0 +1,275 @@
+//! Submits the transactions of the mutating commands, or simulates them with `--dry-run`. With
+//! `--sign-only` or `--dump-instruction` they are printed for someone else to sign or propose.
+
+use std::{cell::OnceCell, fmt};
+
+use anyhow::{anyhow, bail};
+use base64::{engine::general_purpose, Engine};
+use clap::ValueEnum;
+use solana_client::rpc_client::RpcClient;
+use solana_hash::Hash;
+use solana_instruction::{error::InstructionError, Instruction};
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_pubkey::Pubkey;
+use solana_signature::Signature;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use solana_transaction_error::TransactionError;
+
+use crate::{
+    output::OutputFormat,
+    views::{InstructionView, ListView, SignedTransactionView, SimulationView},
+};
+
+/// How signed transactions are serialized
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum TransactionEncoding {
+    Base58,
+
+    #[default]
+    Base64,
+}
+
+impl TransactionEncoding {
+    pub fn encode(self, tx: &Transaction) -> anyhow::Result<String> {
+        let bytes = bincode::serialize(tx)?;
+
+        Ok(match self {
+            Self::Base58 => bs58::encode(bytes).into_string(),
+            Self::Base64 => general_purpose::STANDARD.encode(bytes),
+        })
+    }
+
+    pub fn decode(self, encoded: &str) -> anyhow::Result<Transaction> {
+        let bytes = match self {
+            Self::Base58 => bs58::decode(encoded.trim()).into_vec()?,
+            Self::Base64 => general_purpose::STANDARD.decode(encoded.trim())?,
+        };
+
+        Ok(bincode::deserialize(&bytes)?)
+    }
+}
+
+/// The keypair of `--keypair-path`, read the first time a command signs or builds instructions for
+/// it, so that read commands and `--authority` instruction dumps run without one.
+pub struct LazyKeypair {
+    path: String,
+    keypair: OnceCell<Keypair>,
+}
+
+impl LazyKeypair {
+    pub const fn new(path: String) -> Self {
+        Self {
+            path,
+            keypair: OnceCell::new(),
+        }
+    }
+
+    pub fn get(&self) -> anyhow::Result<&Keypair> {
+        if let Some(keypair) = self.keypair.get() {
+            return Ok(keypair);
+        }
+
+        let keypair = read_keypair_file(&self.path)
+            .map_err(|e| anyhow!("Failed to read keypair {}: {e}", self.path))?;
+        Ok(self.keypair.get_or_init(|| keypair))
+    }
+}
+
+/// How mutating commands submit their transactions
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TransactionOptions {
+    /// Simulate the transaction and print the outcome instead of sending it
+    pub dry_run: bool,
+
+    /// Sign with the keypair and print the serialized transaction instead of sending it
+    pub sign_only: bool,
+
+    /// Print the instructions instead of building a transaction
+    pub dump_instruction: bool,
+
+    /// Blockhash to sign with instead of fetching the latest one
+    pub blockhash: Option<Hash>,
+
+    /// Fee payer of the transaction, the keypair if not set
+    pub fee_payer: Option<Pubkey>,
+
+    /// Authority the instructions are built for, the keypair if not set
+    pub authority: Option<Pubkey>,
+
+    /// Encoding of `--sign-only` transactions
+    pub encoding: TransactionEncoding,
+}
+
+/// Builds a transaction out of `instructions` and handles it according to `options`.
+///
+/// It prints the instructions or the signed transaction, simulates it, or sends it and waits for
+/// the commitment of `client`. Custom program errors are decoded as `E`.
+pub fn process_transaction<E>(
+    client: &RpcClient,
+    keypair: &LazyKeypair,
+    instructions: &[Instruction],
+    options: TransactionOptions,
+    output: OutputFormat,
+) -> anyhow::Result<()>
+where
+    E: TryFrom<u32> + fmt::Debug + fmt::Display,
+{
+    if options.dump_instruction {
+        let views = instructions.iter().map(InstructionView::from).collect();
+        return output.print(&ListView(views));
+    }
+
+    let keypair = keypair.get()?;
+    let blockhash = match options.blockhash {
+        Some(blockhash) => blockhash,
+        None => client.get_latest_blockhash()?,
+    };
+    let fee_payer = options.fee_payer.unwrap_or_else(|| keypair.pubkey());
+    let mut tx = Transaction::new_with_payer(instructions, Some(&fee_payer));
+    sign_transaction(&mut tx, keypair, blockhash)?;
+
+    if options.sign_only {
+        return output.print(&SignedTransactionView::new(
+            &tx,
+            &absent_signers(&tx),
+            options.encoding,
+        )?);
+    }
+
+    submit_transaction::<E>(client, &tx, options.dry_run, output)
+}
+
+/// Adds the signature of `keypair` to a previously signed transaction if it's one of the signers
+/// still missing, then submits it like [process_transaction].
+pub fn broadcast_transaction<E>(
+    client: &RpcClient,
+    keypair: &LazyKeypair,
+    mut tx: Transaction,
+    dry_run: bool,
+    output: OutputFormat,
+) -> anyhow::Result<()>
+where
+    E: TryFrom<u32> + fmt::Debug + fmt::Display,
+{
+    if !absent_signers(&tx).is_empty() {
+        let blockhash = tx.message.recent_blockhash;
+        sign_transaction(&mut tx, keypair.get()?, blockhash)?;
+    }
+
+    submit_transaction::<E>(client, &tx, dry_run, output)
+}
+
+/// Whether every instruction of `tx` invokes `program_id`.
+pub fn invokes_only(tx: &Transaction, program_id: &Pubkey) -> bool {
+    tx.message.instructions.iter().all(|ix| {
+        tx.message
+            .account_keys
+            .get(usize::from(ix.program_id_index))
+            == Some(program_id)
+    })
+}
+
+/// Signs `tx` with `keypair` if it's one of its signers, keeping the signatures of the others.
+fn sign_transaction(
+    tx: &mut Transaction,
+    keypair: &Keypair,
+    blockhash: Hash,
+) -> anyhow::Result<()> {
+    let pubkey = keypair.pubkey();
+    if tx.message.signer_keys().contains(&&pubkey) {
+        tx.try_partial_sign(&[keypair], blockhash)?;
+    } else if tx.message.recent_blockhash != blockhash {
+        tx.message.recent_blockhash = blockhash;
+        tx.signatures.fill(Signature::default());
+    }
+
+    Ok(())
+}
+
+/// The signers of `tx` whose signature is still missing.
+fn absent_signers(tx: &Transaction) -> Vec<Pubkey> {
+    tx.message
+        .signer_keys()
+        .into_iter()
+        .zip(&tx.signatures)
+        .filter(|(_, signature)| **signature == Signature::default())
+        .map(|(pubkey, _)| *pubkey)
+        .collect()
+}
+
+fn submit_transaction<E>(
+    client: &RpcClient,
+    tx: &Transaction,
+    dry_run: bool,
+    output: OutputFormat,
+) -> anyhow::Result<()>
+where
+    E: TryFrom<u32> + fmt::Debug + fmt::Display,
+{
+    if dry_run {
+        return simulate_transaction::<E>(client, tx, output);
+    }
+
+    let absent_signers = absent_signers(tx);
+    if !absent_signers.is_empty() {
+        let absent_signers: Vec<String> = absent_signers.iter().map(Pubkey::to_string).collect();
+        bail!(
+            "Transaction is missing signatures from {}, export it with --sign-only",
+            absent_signers.join(", ")
+        );
+    }
+
+    match client.send_and_confirm_transaction(tx) {
+        Ok(signature) => {
+            println!("Transaction confirmed: {signature}");
+            Ok(())
+        }
+        Err(error) => Err(error.get_transaction_error().map_or_else(
+            || error.into(),
+            |tx_error| {
+                anyhow!(
+                    "Transaction failed: {}",
+                    describe_transaction_error::<E>(&tx_error)
+                )
+            },
+        )),
+    }
+}
+
+/// Simulates `tx` and prints the outcome, failing if the simulation did.
+fn simulate_transaction<E>(
+    client: &RpcClient,
+    tx: &Transaction,
+    output: OutputFormat,
+) -> anyhow::Result<()>
+where
+    E: TryFrom<u32> + fmt::Debug + fmt::Display,
+{
+    let result = client.simulate_transaction(tx)?.value;
+    let error = result.err.as_ref().map(describe_transaction_error::<E>);
+
+    output.print(&SimulationView {
+        logs: result.logs.unwrap_or_default(),
+        units_consumed: result.units_consumed,
+        error: error.clone(),
+    })?;
+
+    error.map_or(Ok(()), |error| Err(anyhow!("Simulation failed: {error}")))
+}
+
+/// Renders `tx_error`, naming custom program errors after their `E` variant.
+fn describe_transaction_error<E>(tx_error: &TransactionError) -> String
+where
+    E: TryFrom<u32> + fmt::Debug + fmt::Display,
+{
+    match tx_error {
+        TransactionError::InstructionError(index, InstructionError::Custom(code)) => {
+            E::try_from(*code).map_or_else(
+                |_| format!("instruction {index} failed with custom error {code}"),
+                |error| format!("instruction {index} failed with {error:?} ({code}): {error}"),
+            )
+        }
+        tx_error => tx_error.to_string(),
+    }
+}
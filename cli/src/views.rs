@@ -0,0 +1,751 @@
+//! Serializable views of the program accounts printed by the read commands. Pubkeys are rendered
+//! as base58 and merkle roots as hex.
+
+use std::fmt;
+
+use jito_tip_distribution_core::{
+    claim_status::ClaimStatus, config::Config, merkle_proof, merkle_root::MerkleRoot,
+    tip_distribution_account::TipDistributionAccount,
+};
+use jito_tip_payment_core::config::Config as TipPaymentConfig;
+use serde::Serialize;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use solana_transaction::Transaction;
+
+use crate::{output::to_hex, transaction::TransactionEncoding};
+
+fn pubkey(bytes: [u8; 32]) -> String {
+    Pubkey::new_from_array(bytes).to_string()
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConfigView {
+    pub address: String,
+    pub authority: String,
+    pub expired_funds_account: String,
+    pub num_epochs_valid: u64,
+    pub max_validator_commission_bps: u16,
+    pub merkle_root_upload_deadline_epochs: u64,
+    pub bump: u8,
+}
+
+impl ConfigView {
+    pub fn new(address: &Pubkey, config: &Config) -> Self {
+        Self {
+            address: address.to_string(),
+            authority: pubkey(config.authority),
+            expired_funds_account: pubkey(config.expired_funds_account),
+            num_epochs_valid: config.num_epochs_valid,
+            max_validator_commission_bps: config.max_validator_commission_bps,
+            merkle_root_upload_deadline_epochs: config.merkle_root_upload_deadline_epochs,
+            bump: config.bump,
+        }
+    }
+}
+
+impl fmt::Display for ConfigView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Config Account Address: {}", self.address)?;
+        writeln!(f, "Config Account Data:")?;
+        writeln!(f, "  Authority: {}", self.authority)?;
+        writeln!(f, "  Expired Funds Account: {}", self.expired_funds_account)?;
+        writeln!(f, "  Num Epochs Valid: {}", self.num_epochs_valid)?;
+        writeln!(
+            f,
+            "  Max Validator Commission BPS: {}",
+            self.max_validator_commission_bps
+        )?;
+        writeln!(
+            f,
+            "  Merkle Root Upload Deadline Epochs: {}",
+            self.merkle_root_upload_deadline_epochs
+        )?;
+        writeln!(f, "  Bump: {}", self.bump)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MerkleRootView {
+    pub root: String,
+    pub max_total_claim: u64,
+    pub max_num_nodes: u64,
+    pub total_funds_claimed: u64,
+    pub num_nodes_claimed: u64,
+}
+
+impl From<&MerkleRoot> for MerkleRootView {
+    fn from(merkle_root: &MerkleRoot) -> Self {
+        Self {
+            root: to_hex(&merkle_root.root),
+            max_total_claim: merkle_root.max_total_claim,
+            max_num_nodes: merkle_root.max_num_nodes,
+            total_funds_claimed: merkle_root.total_funds_claimed,
+            num_nodes_claimed: merkle_root.num_nodes_claimed,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TipDistributionAccountView {
+    pub address: String,
+    pub lamports: u64,
+    pub validator_vote_account: String,
+    pub merkle_root_upload_authority: String,
+    pub epoch_created_at: u64,
+    pub validator_commission_bps: u16,
+    pub expires_at: u64,
+    pub rent_refund_destination: String,
+    pub roll_over_unclaimed_funds: bool,
+    pub bump: u8,
+    pub merkle_root: Option<MerkleRootView>,
+}
+
+impl TipDistributionAccountView {
+    pub fn new(
+        address: &Pubkey,
+        lamports: u64,
+        tip_distribution_account: &TipDistributionAccount,
+    ) -> Self {
+        Self {
+            address: address.to_string(),
+            lamports,
+            validator_vote_account: pubkey(tip_distribution_account.validator_vote_account),
+            merkle_root_upload_authority: pubkey(
+                tip_distribution_account.merkle_root_upload_authority,
+            ),
+            epoch_created_at: tip_distribution_account.epoch_created_at(),
+            validator_commission_bps: tip_distribution_account.validator_commission_bps(),
+            expires_at: tip_distribution_account.expires_at(),
+            rent_refund_destination: pubkey(tip_distribution_account.rent_refund_destination),
+            roll_over_unclaimed_funds: tip_distribution_account.roll_over_unclaimed_funds(),
+            bump: tip_distribution_account.bump(),
+            merkle_root: tip_distribution_account
+                .merkle_root()
+                .as_ref()
+                .map(MerkleRootView::from),
+        }
+    }
+}
+
+impl fmt::Display for TipDistributionAccountView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Tip Distribution Account Address: {}", self.address)?;
+        writeln!(f, "Tip Distribution Account Data:")?;
+        writeln!(f, "  Lamports: {}", self.lamports)?;
+        writeln!(f, "  Vote Account: {}", self.validator_vote_account)?;
+        writeln!(
+            f,
+            "  Merkle Root Upload Authority: {}",
+            self.merkle_root_upload_authority
+        )?;
+        writeln!(f, "  Epoch Created At: {}", self.epoch_created_at)?;
+        writeln!(
+            f,
+            "  Validator Commission BPS: {}",
+            self.validator_commission_bps
+        )?;
+        writeln!(f, "  Expires At: {}", self.expires_at)?;
+        writeln!(
+            f,
+            "  Rent Refund Destination: {}",
+            self.rent_refund_destination
+        )?;
+        writeln!(
+            f,
+            "  Roll Over Unclaimed Funds: {}",
+            self.roll_over_unclaimed_funds
+        )?;
+        writeln!(f, "  Bump: {}", self.bump)?;
+
+        match &self.merkle_root {
+            Some(merkle_root) => {
+                writeln!(f, "  Merkle Root:")?;
+                writeln!(f, "    Root: {}", merkle_root.root)?;
+                writeln!(f, "    Max Total Claim: {}", merkle_root.max_total_claim)?;
+                writeln!(f, "    Max Num Nodes: {}", merkle_root.max_num_nodes)?;
+                writeln!(
+                    f,
+                    "    Total Funds Claimed: {}",
+                    merkle_root.total_funds_claimed
+                )?;
+                writeln!(
+                    f,
+                    "    Num Nodes Claimed: {}",
+                    merkle_root.num_nodes_claimed
+                )
+            }
+            None => writeln!(f, "  Merkle Root: None"),
+        }
+    }
+}
+
+/// A list of views, printed one after another as text and as an array as JSON.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct ListView<T>(pub Vec<T>);
+
+impl<T: fmt::Display> fmt::Display for ListView<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for view in &self.0 {
+            write!(f, "{view}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClaimStatusView {
+    pub address: String,
+    pub is_claimed: bool,
+    pub claimant: String,
+    pub claim_status_payer: String,
+    pub slot_claimed_at: u64,
+    pub amount: u64,
+    pub expires_at: u64,
+    pub bump: u8,
+}
+
+impl ClaimStatusView {
+    pub fn new(address: &Pubkey, claim_status: &ClaimStatus) -> Self {
+        Self {
+            address: address.to_string(),
+            is_claimed: claim_status.is_claimed,
+            claimant: pubkey(claim_status.claimant),
+            claim_status_payer: pubkey(claim_status.claim_status_payer),
+            slot_claimed_at: claim_status.slot_claimed_at,
+            amount: claim_status.amount,
+            expires_at: claim_status.expires_at,
+            bump: claim_status.bump,
+        }
+    }
+}
+
+impl fmt::Display for ClaimStatusView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Claim Status Account Address: {}", self.address)?;
+        writeln!(f, "Claim Status Data:")?;
+        writeln!(f, "  Is Claimed: {}", self.is_claimed)?;
+        writeln!(f, "  Claimant: {}", self.claimant)?;
+        writeln!(f, "  Claim Status Payer: {}", self.claim_status_payer)?;
+        writeln!(f, "  Slot Claimed At: {}", self.slot_claimed_at)?;
+        writeln!(f, "  Amount: {}", self.amount)?;
+        writeln!(f, "  Expires At: {}", self.expires_at)?;
+        writeln!(f, "  Bump: {}", self.bump)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TipPaymentConfigView {
+    pub address: String,
+    pub tip_receiver: String,
+    pub block_builder: String,
+    pub block_builder_commission_pct: u64,
+    pub version: u8,
+    pub config_bump: u8,
+    pub tip_payment_account_bumps: [u8; 8],
+}
+
+impl TipPaymentConfigView {
+    pub fn new(address: &Pubkey, config: &TipPaymentConfig) -> Self {
+        let bumps = &config.bumps;
+        Self {
+            address: address.to_string(),
+            tip_receiver: pubkey(config.tip_receiver),
+            block_builder: pubkey(config.block_builder),
+            block_builder_commission_pct: config.block_builder_commission_pct,
+            version: config.version,
+            config_bump: bumps.config,
+            tip_payment_account_bumps: [
+                bumps.tip_payment_account_0,
+                bumps.tip_payment_account_1,
+                bumps.tip_payment_account_2,
+                bumps.tip_payment_account_3,
+                bumps.tip_payment_account_4,
+                bumps.tip_payment_account_5,
+                bumps.tip_payment_account_6,
+                bumps.tip_payment_account_7,
+            ],
+        }
+    }
+}
+
+impl fmt::Display for TipPaymentConfigView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Config Account Address: {}", self.address)?;
+        writeln!(f, "Config Account Data:")?;
+        writeln!(f, "  Tip Receiver: {}", self.tip_receiver)?;
+        writeln!(f, "  Block Builder: {}", self.block_builder)?;
+        writeln!(
+            f,
+            "  Block Builder Commission Pct: {}",
+            self.block_builder_commission_pct
+        )?;
+        writeln!(f, "  Version: {}", self.version)?;
+        writeln!(f, "  Bumps:")?;
+        writeln!(f, "    Config: {}", self.config_bump)?;
+        for (index, bump) in self.tip_payment_account_bumps.iter().enumerate() {
+            writeln!(f, "    Tip Payment Account {index}: {bump}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TipAccountView {
+    pub index: usize,
+    pub address: String,
+    /// `None` if the account isn't initialized.
+    pub claimable_lamports: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TipAccountsView {
+    pub tip_accounts: Vec<TipAccountView>,
+    pub total_claimable_lamports: u64,
+}
+
+impl fmt::Display for TipAccountsView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for tip_account in &self.tip_accounts {
+            match tip_account.claimable_lamports {
+                Some(claimable_lamports) => {
+                    writeln!(
+                        f,
+                        "Tip Payment Account {}: {}",
+                        tip_account.index, tip_account.address
+                    )?;
+                    writeln!(f, "  Claimable Lamports: {claimable_lamports}")?;
+                }
+                None => writeln!(
+                    f,
+                    "Tip Payment Account {}: {} (not initialized)",
+                    tip_account.index, tip_account.address
+                )?,
+            }
+        }
+        writeln!(
+            f,
+            "Total Claimable Lamports: {}",
+            self.total_claimable_lamports
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AccountMetaView {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// A raw instruction, e.g. for a multisig proposal. The data is base58 encoded.
+#[derive(Debug, Serialize)]
+pub struct InstructionView {
+    pub program_id: String,
+    pub accounts: Vec<AccountMetaView>,
+    pub data: String,
+}
+
+impl From<&Instruction> for InstructionView {
+    fn from(ix: &Instruction) -> Self {
+        Self {
+            program_id: ix.program_id.to_string(),
+            accounts: ix
+                .accounts
+                .iter()
+                .map(|account| AccountMetaView {
+                    pubkey: account.pubkey.to_string(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: bs58::encode(&ix.data).into_string(),
+        }
+    }
+}
+
+impl fmt::Display for InstructionView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Program Id: {}", self.program_id)?;
+        writeln!(f, "Accounts:")?;
+        for (index, account) in self.accounts.iter().enumerate() {
+            writeln!(
+                f,
+                "  {index}: {} (signer: {}, writable: {})",
+                account.pubkey, account.is_signer, account.is_writable
+            )?;
+        }
+        writeln!(f, "Data (base58): {}", self.data)
+    }
+}
+
+/// A transaction signed with `--sign-only`, missing the signatures of `absent_signers`.
+#[derive(Debug, Serialize)]
+pub struct SignedTransactionView {
+    pub blockhash: String,
+    pub signers: Vec<String>,
+    pub absent_signers: Vec<String>,
+    pub transaction: String,
+}
+
+impl SignedTransactionView {
+    pub fn new(
+        tx: &Transaction,
+        absent_signers: &[Pubkey],
+        encoding: TransactionEncoding,
+    ) -> anyhow::Result<Self> {
+        let signers = tx
+            .message
+            .signer_keys()
+            .into_iter()
+            .zip(&tx.signatures)
+            .filter(|(pubkey, _)| !absent_signers.contains(pubkey))
+            .map(|(pubkey, signature)| format!("{pubkey}={signature}"))
+            .collect();
+
+        Ok(Self {
+            blockhash: tx.message.recent_blockhash.to_string(),
+            signers,
+            absent_signers: absent_signers.iter().map(Pubkey::to_string).collect(),
+            transaction: encoding.encode(tx)?,
+        })
+    }
+}
+
+impl fmt::Display for SignedTransactionView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Blockhash: {}", self.blockhash)?;
+        writeln!(f, "Signers (Pubkey=Signature):")?;
+        for signer in &self.signers {
+            writeln!(f, "  {signer}")?;
+        }
+        writeln!(f, "Absent Signers:")?;
+        for absent_signer in &self.absent_signers {
+            writeln!(f, "  {absent_signer}")?;
+        }
+        writeln!(f, "Transaction: {}", self.transaction)
+    }
+}
+
+/// The outcome of a `--dry-run` simulation. `error` is `None` if it succeeded.
+#[derive(Debug, Serialize)]
+pub struct SimulationView {
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl fmt::Display for SimulationView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Simulation Logs:")?;
+        for log in &self.logs {
+            writeln!(f, "  {log}")?;
+        }
+        match self.units_consumed {
+            Some(units_consumed) => writeln!(f, "Compute Units Consumed: {units_consumed}")?,
+            None => writeln!(f, "Compute Units Consumed: unknown")?,
+        }
+        match &self.error {
+            Some(error) => writeln!(f, "Simulation Failed: {error}"),
+            None => writeln!(f, "Simulation Succeeded"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProofStepView {
+    pub sibling: String,
+    pub hash: String,
+}
+
+/// The leaf of a claim and every hash computed from it on the way to the root.
+#[derive(Debug, Serialize)]
+pub struct ProofVerificationView {
+    pub claimant: String,
+    pub amount: u64,
+    pub leaf: String,
+    pub steps: Vec<ProofStepView>,
+    pub computed_root: String,
+    pub root: String,
+    pub valid: bool,
+}
+
+impl ProofVerificationView {
+    /// Traces `proof` from the leaf of `claimant` and `amount` up to the root it computes, and
+    /// checks that against `root`.
+    pub fn new(claimant: &Pubkey, amount: u64, proof: &[[u8; 32]], root: &[u8; 32]) -> Self {
+        let leaf = merkle_proof::leaf(&claimant.to_bytes(), amount);
+        let mut computed_root = leaf;
+        let steps = proof
+            .iter()
+            .map(|sibling| {
+                computed_root = merkle_proof::intermediate(&computed_root, sibling);
+                ProofStepView {
+                    sibling: to_hex(sibling),
+                    hash: to_hex(&computed_root),
+                }
+            })
+            .collect();
+
+        Self {
+            claimant: claimant.to_string(),
+            amount,
+            leaf: to_hex(&leaf),
+            steps,
+            computed_root: to_hex(&computed_root),
+            root: to_hex(root),
+            valid: merkle_proof::verify(proof, root, leaf),
+        }
+    }
+}
+
+impl fmt::Display for ProofVerificationView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Claimant: {}", self.claimant)?;
+        writeln!(f, "Amount: {}", self.amount)?;
+        writeln!(f, "Leaf: {}", self.leaf)?;
+        for (index, step) in self.steps.iter().enumerate() {
+            writeln!(f, "Proof {index}:")?;
+            writeln!(f, "  Sibling: {}", step.sibling)?;
+            writeln!(f, "  Hash: {}", step.hash)?;
+        }
+        writeln!(f, "Computed Root: {}", self.computed_root)?;
+        writeln!(f, "Root: {}", self.root)?;
+        if self.valid {
+            writeln!(f, "The proof is valid")
+        } else {
+            writeln!(f, "The proof does NOT match the root")
+        }
+    }
+}
+
+/// Why a TipDistributionAccount of an [EpochReportView] needs attention.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReportFlag {
+    /// No merkle root has been uploaded
+    NoMerkleRoot,
+
+    /// The claims left on the root exceed the lamports held above rent
+    Underfunded,
+
+    /// Past `expires_at` but not closed yet
+    ExpiredNotClosed,
+}
+
+impl fmt::Display for ReportFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NoMerkleRoot => "no-merkle-root",
+            Self::Underfunded => "underfunded",
+            Self::ExpiredNotClosed => "expired-not-closed",
+        })
+    }
+}
+
+/// A TipDistributionAccount of an [EpochReportView]. The lamports `tipped` to it split into
+/// `claimed`, `claimable`, `unallocated`, `rolling_over` and `expired`.
+#[derive(Debug, Serialize)]
+pub struct EpochReportRowView {
+    pub tip_distribution_account: String,
+    pub vote_account: String,
+    pub lamports: u64,
+    pub merkle_root: Option<String>,
+    pub max_total_claim: u64,
+    pub max_num_nodes: u64,
+    pub num_nodes_claimed: u64,
+    pub tipped: u64,
+    pub claimed: u64,
+    pub claimable: u64,
+    pub unallocated: u64,
+    pub rolling_over: u64,
+    pub expired: u64,
+    pub expires_at: u64,
+    pub flags: Vec<ReportFlag>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ClaimStatusTotalsView {
+    pub claim_statuses: u64,
+    pub claimed: u64,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct EpochReportTotalsView {
+    pub tip_distribution_accounts: usize,
+    pub merkle_roots: usize,
+    pub flagged: usize,
+    pub lamports: u64,
+    pub max_total_claim: u64,
+    pub max_num_nodes: u64,
+    pub num_nodes_claimed: u64,
+    pub tipped: u64,
+    pub claimed: u64,
+    pub claimable: u64,
+    pub unallocated: u64,
+    pub rolling_over: u64,
+    pub expired: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim_statuses: Option<ClaimStatusTotalsView>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EpochReportView {
+    pub epoch: u64,
+    pub current_epoch: u64,
+    pub tip_distribution_accounts: Vec<EpochReportRowView>,
+    pub totals: EpochReportTotalsView,
+}
+
+impl EpochReportView {
+    pub fn new(
+        epoch: u64,
+        current_epoch: u64,
+        tip_distribution_accounts: Vec<EpochReportRowView>,
+        claim_statuses: Option<ClaimStatusTotalsView>,
+    ) -> Self {
+        let mut totals = EpochReportTotalsView {
+            tip_distribution_accounts: tip_distribution_accounts.len(),
+            claim_statuses,
+            ..EpochReportTotalsView::default()
+        };
+        for row in &tip_distribution_accounts {
+            totals.merkle_roots = totals
+                .merkle_roots
+                .saturating_add(usize::from(row.merkle_root.is_some()));
+            totals.flagged = totals
+                .flagged
+                .saturating_add(usize::from(!row.flags.is_empty()));
+            totals.lamports = totals.lamports.saturating_add(row.lamports);
+            totals.max_total_claim = totals.max_total_claim.saturating_add(row.max_total_claim);
+            totals.max_num_nodes = totals.max_num_nodes.saturating_add(row.max_num_nodes);
+            totals.num_nodes_claimed = totals
+                .num_nodes_claimed
+                .saturating_add(row.num_nodes_claimed);
+            totals.tipped = totals.tipped.saturating_add(row.tipped);
+            totals.claimed = totals.claimed.saturating_add(row.claimed);
+            totals.claimable = totals.claimable.saturating_add(row.claimable);
+            totals.unallocated = totals.unallocated.saturating_add(row.unallocated);
+            totals.rolling_over = totals.rolling_over.saturating_add(row.rolling_over);
+            totals.expired = totals.expired.saturating_add(row.expired);
+        }
+
+        Self {
+            epoch,
+            current_epoch,
+            tip_distribution_accounts,
+            totals,
+        }
+    }
+}
+
+/// Renders the report as CSV: a row per account, then a `total` row. The ClaimStatus columns are
+/// only added when they were scanned, and only filled in on the `total` row.
+impl fmt::Display for EpochReportView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let claim_statuses = self.totals.claim_statuses.as_ref();
+
+        write!(
+            f,
+            "tip_distribution_account,vote_account,lamports,merkle_root,max_total_claim,\
+             max_num_nodes,num_nodes_claimed,tipped,claimed,claimable,unallocated,rolling_over,\
+             expired,expires_at,flags"
+        )?;
+        if claim_statuses.is_some() {
+            write!(f, ",claim_statuses,claim_status_claimed")?;
+        }
+        writeln!(f)?;
+
+        for row in &self.tip_distribution_accounts {
+            let flags: Vec<String> = row.flags.iter().map(ReportFlag::to_string).collect();
+            write!(
+                f,
+                "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+                row.tip_distribution_account,
+                row.vote_account,
+                row.lamports,
+                row.merkle_root.as_deref().unwrap_or_default(),
+                row.max_total_claim,
+                row.max_num_nodes,
+                row.num_nodes_claimed,
+                row.tipped,
+                row.claimed,
+                row.claimable,
+                row.unallocated,
+                row.rolling_over,
+                row.expired,
+                row.expires_at,
+                flags.join(";"),
+            )?;
+            if claim_statuses.is_some() {
+                write!(f, ",,")?;
+            }
+            writeln!(f)?;
+        }
+
+        let totals = &self.totals;
+        write!(
+            f,
+            "total,,{},,{},{},{},{},{},{},{},{},{},,",
+            totals.lamports,
+            totals.max_total_claim,
+            totals.max_num_nodes,
+            totals.num_nodes_claimed,
+            totals.tipped,
+            totals.claimed,
+            totals.claimable,
+            totals.unallocated,
+            totals.rolling_over,
+            totals.expired,
+        )?;
+        if let Some(claim_statuses) = claim_statuses {
+            write!(
+                f,
+                ",{},{}",
+                claim_statuses.claim_statuses, claim_statuses.claimed
+            )?;
+        }
+        writeln!(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_tip_distribution_core::merkle_proof::{intermediate, leaf};
+    use solana_pubkey::Pubkey;
+
+    use super::ProofVerificationView;
+    use crate::output::to_hex;
+
+    #[test]
+    fn proof_verification_traces_proof_to_root() {
+        let claimant = Pubkey::new_unique();
+        let claimant_leaf = leaf(&claimant.to_bytes(), 100);
+        let siblings = [leaf(&[1; 32], 200), leaf(&[2; 32], 300)];
+        let first = intermediate(&claimant_leaf, &siblings[0]);
+        let root = intermediate(&first, &siblings[1]);
+
+        let view = ProofVerificationView::new(&claimant, 100, &siblings, &root);
+        assert!(view.valid);
+        assert_eq!(view.leaf, to_hex(&claimant_leaf));
+        assert_eq!(view.steps.len(), 2);
+        assert_eq!(view.steps[0].sibling, to_hex(&siblings[0]));
+        assert_eq!(view.steps[0].hash, to_hex(&first));
+        assert_eq!(view.steps[1].hash, to_hex(&root));
+        assert_eq!(view.computed_root, view.root);
+
+        // Claiming a different amount computes another root.
+        let view = ProofVerificationView::new(&claimant, 101, &siblings, &root);
+        assert!(!view.valid);
+        assert_ne!(view.computed_root, view.root);
+
+        // So does a proof missing a step.
+        let view = ProofVerificationView::new(&claimant, 100, &siblings[..1], &root);
+        assert!(!view.valid);
+        assert_eq!(view.computed_root, to_hex(&first));
+    }
+}
@@ -0,0 +1,23 @@
+//! Anchor-compatible discriminators, derived at compile time.
+
+use crate::sha256::sha256;
+
+/// Returns the Anchor instruction discriminator for `name`: `sha256("global:<name>")[..8]`.
+///
+/// `name` is the snake_case name of the instruction handler, e.g. `"upload_merkle_root"`.
+pub const fn instruction_discriminator(name: &str) -> [u8; 8] {
+    truncate(sha256(&[b"global:", name.as_bytes()]))
+}
+
+/// Returns the Anchor account discriminator for `name`: `sha256("account:<Name>")[..8]`.
+///
+/// `name` is the PascalCase name of the account struct, e.g. `"TipDistributionAccount"`.
+pub const fn account_discriminator(name: &str) -> [u8; 8] {
+    truncate(sha256(&[b"account:", name.as_bytes()]))
+}
+
+const fn truncate(hash: [u8; 32]) -> [u8; 8] {
+    [
+        hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7],
+    ]
+}
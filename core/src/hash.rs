@@ -0,0 +1,35 @@
+//! SHA-256 hashing without `solana_program`.
+//!
+//! On-chain the digest is computed by the `sol_sha256` syscall; everywhere else it falls back to
+//! the pure-Rust implementation in [`crate::sha256`], so the same code can be used by off-chain
+//! tooling and tests.
+
+/// Length of a SHA-256 digest.
+pub const HASH_BYTES: usize = 32;
+
+/// Returns the SHA-256 digest of the concatenation of `vals`.
+// Only the off-chain fallback could be `const`; the `sol_sha256` syscall can't be called in a
+// const context.
+#[allow(clippy::missing_const_for_fn)]
+#[inline(always)]
+pub fn hashv(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash_result = [0; HASH_BYTES];
+        // SAFETY: `vals` is a slice of `(ptr, len)` pairs, which is the layout the syscall
+        // expects, and `hash_result` is `HASH_BYTES` long.
+        unsafe {
+            pinocchio::syscalls::sol_sha256(
+                vals as *const _ as *const u8,
+                vals.len() as u64,
+                hash_result.as_mut_ptr(),
+            );
+        }
+        hash_result
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        crate::sha256::sha256(vals)
+    }
+}
@@ -4,8 +4,13 @@ use pinocchio::{
 };
 use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 
+pub mod discriminator;
+pub mod hash;
 pub mod loader;
+pub mod program_account;
+pub mod sha256;
 pub mod transmutable;
+pub mod versioned_account;
 
 /// Creates a new account or initializes an existing account
 ///
@@ -103,6 +108,7 @@ pub unsafe fn close_program_account(
     Ok(())
 }
 
+/// Resizes `account` to `new_size`, topping up its lamports from `payer` to stay rent-exempt.
 pub fn realloc(
     account: &AccountInfo,
     new_size: usize,
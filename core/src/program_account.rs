@@ -0,0 +1,184 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+};
+
+use crate::transmutable::Transmutable;
+
+/// Length of the Anchor discriminator that prefixes every program account.
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// An account owned by one of the tip programs.
+///
+/// The account data is laid out like an Anchor account: an 8-byte discriminator followed by the
+/// zero-copy representation of `Self`.
+pub trait ProgramAccount: Transmutable + Sized {
+    /// The account discriminator.
+    const DISCRIMINATOR: [u8; 8];
+
+    /// Total size of the account data, including the discriminator.
+    const SPACE: usize = DISCRIMINATOR_LEN + Self::LEN;
+
+    /// Arguments needed to derive the PDA that are not stored in the account itself.
+    type SeedArgs<'a>;
+
+    /// The seeds of the PDA, usually a fixed-size array of slices borrowed from the account and
+    /// its [ProgramAccount::SeedArgs].
+    type Seeds<'a>: AsRef<[&'a [u8]]>
+    where
+        Self: 'a;
+
+    /// Returns the seeds (without the bump) of the PDA the account lives at.
+    fn pda_seeds<'a>(&'a self, args: Self::SeedArgs<'a>) -> Self::Seeds<'a>;
+
+    /// Attempts to load the account as `Self`, returning an error if it's not valid.
+    ///
+    /// The account data must be exactly [ProgramAccount::SPACE] bytes long. A
+    /// [VersionedAccount](crate::versioned_account::VersionedAccount) still in a shorter, older
+    /// layout is rejected; read it with
+    /// [VersionedAccount::read](crate::versioned_account::VersionedAccount::read) or migrate it
+    /// first with
+    /// [VersionedAccount::load_mut_migrated](crate::versioned_account::VersionedAccount::load_mut_migrated).
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `account` - The account to load
+    /// * `seed_args` - The arguments used to derive the expected PDA
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<&Self, ProgramError>` - The loaded account
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that no mutable reference to the account data is alive.
+    #[inline(always)]
+    unsafe fn load<'a>(
+        program_id: &Pubkey,
+        account: &'a AccountInfo,
+        seed_args: Self::SeedArgs<'a>,
+        expect_writable: bool,
+    ) -> Result<&'a Self, ProgramError> {
+        let loaded = Self::load_without_pda_check(program_id, account, expect_writable)?;
+
+        let (expected_pubkey, _) =
+            find_program_address(loaded.pda_seeds(seed_args).as_ref(), program_id);
+        if account.key().ne(&expected_pubkey) {
+            msg!("Account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(loaded)
+    }
+
+    /// Attempts to load the account as `Self` without checking the address it lives at.
+    ///
+    /// Only `program_id` can write the discriminator of an account it owns, so this is enough to
+    /// trust the data when the seed arguments aren't known, e.g. to close an account.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that no mutable reference to the account data is alive.
+    #[inline(always)]
+    unsafe fn load_without_pda_check<'a>(
+        program_id: &Pubkey,
+        account: &'a AccountInfo,
+        expect_writable: bool,
+    ) -> Result<&'a Self, ProgramError> {
+        if account.owner().ne(program_id) {
+            msg!("Account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if account.data_is_empty() {
+            msg!("Account data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !account.is_writable() {
+            msg!("Account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let data = account.borrow_data_unchecked();
+        if data.len() != Self::SPACE {
+            msg!("Account data has an invalid length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if data[..DISCRIMINATOR_LEN].ne(&Self::DISCRIMINATOR) {
+            msg!("Account discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(&*(data[DISCRIMINATOR_LEN..].as_ptr() as *const Self))
+    }
+
+    /// Attempts to load the account as a writable `Self`, returning an error if it's not valid.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that no other reference to the account data is alive.
+    #[inline(always)]
+    unsafe fn load_mut<'a>(
+        program_id: &Pubkey,
+        account: &'a AccountInfo,
+        seed_args: Self::SeedArgs<'a>,
+    ) -> Result<&'a mut Self, ProgramError> {
+        Self::load(program_id, account, seed_args, true)?;
+
+        let data = account.borrow_mut_data_unchecked();
+        Ok(&mut *(data[DISCRIMINATOR_LEN..].as_mut_ptr() as *mut Self))
+    }
+
+    /// Like [ProgramAccount::load_without_pda_check], but returns a writable `Self`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that no other reference to the account data is alive.
+    #[inline(always)]
+    unsafe fn load_mut_without_pda_check<'a>(
+        program_id: &Pubkey,
+        account: &'a AccountInfo,
+    ) -> Result<&'a mut Self, ProgramError> {
+        Self::load_without_pda_check(program_id, account, true)?;
+
+        let data = account.borrow_mut_data_unchecked();
+        Ok(&mut *(data[DISCRIMINATOR_LEN..].as_mut_ptr() as *mut Self))
+    }
+
+    /// Writes the discriminator to a freshly created account and returns it as a writable `Self`.
+    ///
+    /// The account must already be allocated with [`ProgramAccount::SPACE`] bytes and owned by
+    /// `program_id`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that no other reference to the account data is alive.
+    #[inline(always)]
+    unsafe fn init<'a>(
+        program_id: &Pubkey,
+        account: &'a AccountInfo,
+    ) -> Result<&'a mut Self, ProgramError> {
+        if account.owner().ne(program_id) {
+            msg!("Account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if !account.is_writable() {
+            msg!("Account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let data = account.borrow_mut_data_unchecked();
+        if data.len() != Self::SPACE {
+            msg!("Account data has an invalid length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if data[..DISCRIMINATOR_LEN].ne(&[0; DISCRIMINATOR_LEN]) {
+            msg!("Account is already initialized");
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        data[..DISCRIMINATOR_LEN].copy_from_slice(&Self::DISCRIMINATOR);
+        Ok(&mut *(data[DISCRIMINATOR_LEN..].as_mut_ptr() as *mut Self))
+    }
+}
@@ -0,0 +1,140 @@
+//! A dependency-free SHA-256 that can be evaluated at compile time.
+// Index and length arithmetic below is bounded by the 64-byte block size.
+#![allow(clippy::arithmetic_side_effects)]
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Computes the SHA-256 digest of the concatenation of `vals`.
+pub const fn sha256(vals: &[&[u8]]) -> [u8; 32] {
+    let mut state = INITIAL_STATE;
+    let mut block = [0u8; 64];
+    let mut block_len = 0;
+    let mut message_len: u64 = 0;
+
+    let mut i = 0;
+    while i < vals.len() {
+        let val = vals[i];
+        let mut j = 0;
+        while j < val.len() {
+            block[block_len] = val[j];
+            block_len += 1;
+            if block_len == block.len() {
+                state = compress(state, &block);
+                block_len = 0;
+            }
+            j += 1;
+        }
+        message_len = message_len.wrapping_add(val.len() as u64);
+        i += 1;
+    }
+
+    // Padding: a single `1` bit, zeros, then the message length in bits.
+    block[block_len] = 0x80;
+    block_len += 1;
+    if block_len > 56 {
+        while block_len < block.len() {
+            block[block_len] = 0;
+            block_len += 1;
+        }
+        state = compress(state, &block);
+        block_len = 0;
+    }
+    while block_len < 56 {
+        block[block_len] = 0;
+        block_len += 1;
+    }
+    let bit_len = message_len.wrapping_mul(8).to_be_bytes();
+    let mut k = 0;
+    while k < bit_len.len() {
+        block[56 + k] = bit_len[k];
+        k += 1;
+    }
+    state = compress(state, &block);
+
+    let mut digest = [0u8; 32];
+    let mut w = 0;
+    while w < state.len() {
+        let bytes = state[w].to_be_bytes();
+        digest[w * 4] = bytes[0];
+        digest[w * 4 + 1] = bytes[1];
+        digest[w * 4 + 2] = bytes[2];
+        digest[w * 4 + 3] = bytes[3];
+        w += 1;
+    }
+    digest
+}
+
+const fn compress(mut state: [u32; 8], block: &[u8; 64]) -> [u32; 8] {
+    let mut schedule = [0u32; 64];
+    let mut t = 0;
+    while t < 16 {
+        schedule[t] = u32::from_be_bytes([
+            block[t * 4],
+            block[t * 4 + 1],
+            block[t * 4 + 2],
+            block[t * 4 + 3],
+        ]);
+        t += 1;
+    }
+    while t < 64 {
+        let s0 = schedule[t - 15].rotate_right(7)
+            ^ schedule[t - 15].rotate_right(18)
+            ^ (schedule[t - 15] >> 3);
+        let s1 = schedule[t - 2].rotate_right(17)
+            ^ schedule[t - 2].rotate_right(19)
+            ^ (schedule[t - 2] >> 10);
+        schedule[t] = schedule[t - 16]
+            .wrapping_add(s0)
+            .wrapping_add(schedule[t - 7])
+            .wrapping_add(s1);
+        t += 1;
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+    t = 0;
+    while t < 64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let temp1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(K[t])
+            .wrapping_add(schedule[t]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+        t += 1;
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+    state[4] = state[4].wrapping_add(e);
+    state[5] = state[5].wrapping_add(f);
+    state[6] = state[6].wrapping_add(g);
+    state[7] = state[7].wrapping_add(h);
+    state
+}
@@ -0,0 +1,188 @@
+//! Versioned account layouts and in-place migration.
+//!
+//! A versioned account stores a `version` byte right after the fields of its original layout
+//! (version 0), and the fields added by later versions after that byte. Accounts written before
+//! the byte existed read as version 0 since it lies either past the end of their data or in
+//! zero-filled padding.
+
+use core::{mem::MaybeUninit, ptr::copy_nonoverlapping};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+};
+
+use crate::{
+    program_account::{ProgramAccount, DISCRIMINATOR_LEN},
+    realloc,
+};
+
+/// A [ProgramAccount] whose layout can grow between program versions.
+pub trait VersionedAccount: ProgramAccount {
+    /// The layout version written by this program.
+    const VERSION: u8;
+
+    /// Total size of the account data in the version 0 layout, including the discriminator.
+    const V0_SPACE: usize;
+
+    /// Offset of the `version` byte, relative to the end of the discriminator.
+    const VERSION_OFFSET: usize;
+
+    /// Index of the `version` byte in the account data.
+    const VERSION_INDEX: usize = DISCRIMINATOR_LEN + Self::VERSION_OFFSET;
+
+    /// Sets the fields added after `from_version` to their defaults. They are zeroed when this is
+    /// called.
+    fn backfill(&mut self, from_version: u8);
+
+    /// Returns the layout version stored in `data`, which includes the discriminator.
+    #[inline(always)]
+    fn stored_version(data: &[u8]) -> u8 {
+        data.get(Self::VERSION_INDEX).copied().unwrap_or(0)
+    }
+
+    /// Like [ProgramAccount::init], but also stamps the account with [Self::VERSION].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that no other reference to the account data is alive.
+    #[inline(always)]
+    unsafe fn init_versioned<'a>(
+        program_id: &Pubkey,
+        account: &'a AccountInfo,
+    ) -> Result<&'a mut Self, ProgramError> {
+        Self::init(program_id, account)?;
+
+        let data = account.borrow_mut_data_unchecked();
+        data[Self::VERSION_INDEX] = Self::VERSION;
+        Ok(&mut *(data[DISCRIMINATOR_LEN..].as_mut_ptr() as *mut Self))
+    }
+
+    /// Reads a copy of `account` without checking the address it lives at, like
+    /// [ProgramAccount::load_without_pda_check]. An account still in an older layout is accepted
+    /// and the fields added since are backfilled in the copy, so instructions that only read or
+    /// close the account don't need it migrated first.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that no mutable reference to the account data is alive.
+    unsafe fn read(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<Self, ProgramError> {
+        if expect_writable && !account.is_writable() {
+            msg!("Account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let from_version = check_versioned_data::<Self>(program_id, account)?;
+
+        let fields = &account.borrow_data_unchecked()[DISCRIMINATOR_LEN..];
+        let mut copy = MaybeUninit::<Self>::zeroed();
+        let bytes = copy.as_mut_ptr() as *mut u8;
+        copy_nonoverlapping(fields.as_ptr(), bytes, fields.len());
+        *bytes.add(Self::VERSION_OFFSET) = Self::VERSION;
+
+        let mut copy = copy.assume_init();
+        if from_version < Self::VERSION {
+            copy.backfill(from_version);
+        }
+
+        Ok(copy)
+    }
+
+    /// Like [ProgramAccount::load_mut], but first migrates an account still in an older layout
+    /// with [VersionedAccount::migrate], `payer` funding the extra rent.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that no other reference to the account data is alive.
+    unsafe fn load_mut_migrated<'a>(
+        program_id: &Pubkey,
+        account: &'a AccountInfo,
+        seed_args: Self::SeedArgs<'a>,
+        payer: &AccountInfo,
+    ) -> Result<&'a mut Self, ProgramError> {
+        if account.owner().eq(program_id)
+            && Self::stored_version(account.borrow_data_unchecked()) < Self::VERSION
+        {
+            Self::migrate(program_id, account, payer)?;
+        }
+
+        Self::load_mut(program_id, account, seed_args)
+    }
+
+    /// Migrates `account` to [Self::VERSION] in place: grows it to [ProgramAccount::SPACE] with
+    /// `payer` funding the extra rent, backfills the new fields and stamps the version. Accounts
+    /// that are already up to date are left untouched.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that no other reference to the account data is alive.
+    unsafe fn migrate(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        payer: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if !account.is_writable() {
+            msg!("Account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let from_version = check_versioned_data::<Self>(program_id, account)?;
+        if from_version == Self::VERSION {
+            msg!("Account is already up to date");
+            return Ok(());
+        }
+
+        let len = account.data_len();
+        if len < Self::SPACE {
+            realloc(account, Self::SPACE, payer, &Rent::get()?)?;
+            account.borrow_mut_data_unchecked()[len..].fill(0);
+        }
+
+        let data = account.borrow_mut_data_unchecked();
+        data[Self::VERSION_INDEX] = Self::VERSION;
+        let migrated = &mut *(data[DISCRIMINATOR_LEN..].as_mut_ptr() as *mut Self);
+        migrated.backfill(from_version);
+
+        Ok(())
+    }
+}
+
+/// Checks that `account` holds a `T` owned by `program_id` in any layout up to [T::VERSION] and
+/// returns the version it's stored in.
+///
+/// # Safety
+///
+/// The caller must ensure that no mutable reference to the account data is alive.
+unsafe fn check_versioned_data<T: VersionedAccount>(
+    program_id: &Pubkey,
+    account: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    if account.owner().ne(program_id) {
+        msg!("Account has an invalid owner");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let data = account.borrow_data_unchecked();
+    if data.len() < T::V0_SPACE || data.len() > T::SPACE {
+        msg!("Account data has an invalid length");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if data[..DISCRIMINATOR_LEN].ne(&T::DISCRIMINATOR) {
+        msg!("Account discriminator is invalid");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let version = T::stored_version(data);
+    if version > T::VERSION {
+        msg!("Account version is newer than the program");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(version)
+}
@@ -0,0 +1,64 @@
+use jito_tip_core::{
+    discriminator::account_discriminator, program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
+};
+use pinocchio::pubkey::{find_program_address, Pubkey};
+
+use crate::Transmutable;
+
+/// Lets a claimant authorize another key to sign claims on their behalf.
+#[derive(Debug)]
+#[repr(C)]
+pub struct ClaimDelegate {
+    /// The claimant that registered the delegate. Claimed funds are always paid to this account.
+    pub claimant: Pubkey,
+
+    /// The key allowed to sign claims for `claimant`.
+    pub delegate: Pubkey,
+
+    /// The bump used to generate this account
+    pub bump: u8,
+
+    /// The layout version, see [VersionedAccount].
+    pub version: u8,
+}
+
+unsafe impl Transmutable for ClaimDelegate {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl ProgramAccount for ClaimDelegate {
+    const DISCRIMINATOR: [u8; 8] = account_discriminator("ClaimDelegate");
+
+    type SeedArgs<'a> = ();
+
+    type Seeds<'a> = [&'a [u8]; 2];
+
+    fn pda_seeds(&self, _args: ()) -> Self::Seeds<'_> {
+        [Self::SEED, self.claimant.as_ref()]
+    }
+}
+
+impl VersionedAccount for ClaimDelegate {
+    const VERSION: u8 = 1;
+
+    // The account has carried the version byte since it was introduced, so no version 0 layout
+    // is smaller.
+    const V0_SPACE: usize = Self::SPACE;
+
+    const VERSION_OFFSET: usize = core::mem::offset_of!(Self, version);
+
+    fn backfill(&mut self, _from_version: u8) {}
+}
+
+impl ClaimDelegate {
+    pub const SEED: &'static [u8] = b"CLAIM_DELEGATE";
+
+    /// Find the program address for the PDA
+    #[inline(always)]
+    pub fn find_program_address(program_id: &Pubkey, claimant: &Pubkey) -> (Pubkey, u8) {
+        let seeds = [Self::SEED, claimant.as_ref()];
+
+        find_program_address(&seeds, program_id)
+    }
+}
@@ -1,9 +1,8 @@
-use pinocchio::{
-    account_info::AccountInfo,
-    msg,
-    program_error::ProgramError,
-    pubkey::{find_program_address, Pubkey},
+use jito_tip_core::{
+    discriminator::account_discriminator, program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
 };
+use pinocchio::pubkey::{find_program_address, Pubkey};
 
 use crate::Transmutable;
 
@@ -31,6 +30,9 @@ pub struct ClaimStatus {
 
     /// The bump used to generate this account
     pub bump: u8,
+
+    /// The layout version, see [VersionedAccount].
+    pub version: u8,
 }
 
 unsafe impl Transmutable for ClaimStatus {
@@ -38,8 +40,37 @@ unsafe impl Transmutable for ClaimStatus {
     const LEN: usize = core::mem::size_of::<Self>();
 }
 
+impl ProgramAccount for ClaimStatus {
+    const DISCRIMINATOR: [u8; 8] = account_discriminator("ClaimStatus");
+
+    /// The [TipDistributionAccount](crate::tip_distribution_account::TipDistributionAccount)
+    /// the claim was made against.
+    type SeedArgs<'a> = &'a Pubkey;
+
+    type Seeds<'a> = [&'a [u8]; 3];
+
+    fn pda_seeds<'a>(&'a self, tip_distribution_account: &'a Pubkey) -> Self::Seeds<'a> {
+        [
+            Self::SEED,
+            self.claimant.as_ref(),
+            tip_distribution_account.as_ref(),
+        ]
+    }
+}
+
+impl VersionedAccount for ClaimStatus {
+    const VERSION: u8 = 1;
+
+    // The version byte fits in the padding at the end of the version 0 layout.
+    const V0_SPACE: usize = Self::SPACE;
+
+    const VERSION_OFFSET: usize = core::mem::offset_of!(Self, version);
+
+    fn backfill(&mut self, _from_version: u8) {}
+}
+
 impl ClaimStatus {
-    pub const DISCRIMINATOR: &'static [u8] = &[22, 183, 249, 157, 247, 95, 150, 96];
+    pub const SEED: &'static [u8] = b"CLAIM_STATUS";
 
     /// Find the program address for the PDA
     #[inline(always)]
@@ -49,48 +80,11 @@ impl ClaimStatus {
         tip_distribution_account: &Pubkey,
     ) -> (Pubkey, u8) {
         let seeds = [
-            b"CLAIM_STATUS".as_slice(),
+            Self::SEED,
             claimant.as_ref(),
             tip_distribution_account.as_ref(),
         ];
 
         find_program_address(&seeds, program_id)
     }
-
-    /// Attempts to load the account as [`ClaimStatus`], returning an error if it's not valid.
-    ///
-    /// # Safety
-    #[inline(always)]
-    pub unsafe fn load(
-        program_id: &Pubkey,
-        claim_status: &AccountInfo,
-        claimant: &Pubkey,
-        tip_distribution_account: &Pubkey,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if claim_status.owner().ne(program_id) {
-            msg!("ClaimStatus has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if claim_status.data_is_empty() {
-            msg!("ClaimStatus data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !claim_status.is_writable() {
-            msg!("ClaimStatus is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        let data = claim_status.borrow_data_unchecked();
-        if data[0..8].ne(Self::DISCRIMINATOR) {
-            msg!("ClaimStatus discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        let expected_pubkey =
-            Self::find_program_address(program_id, claimant, tip_distribution_account).0;
-        if claim_status.key().ne(&expected_pubkey) {
-            msg!("ClaimStatus is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
-    }
 }
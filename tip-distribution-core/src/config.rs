@@ -1,8 +1,11 @@
+use jito_tip_core::{
+    discriminator::account_discriminator,
+    program_account::{ProgramAccount, DISCRIMINATOR_LEN},
+    versioned_account::VersionedAccount,
+};
 use jito_tip_distribution_sdk::error::TipDistributionError;
 use pinocchio::{
-    account_info::AccountInfo,
     msg,
-    program_error::ProgramError,
     pubkey::{find_program_address, Pubkey},
 };
 
@@ -27,6 +30,14 @@ pub struct Config {
 
     /// The bump used to generate this account
     pub bump: u8,
+
+    /// The layout version, see [VersionedAccount].
+    pub version: u8,
+
+    /// Number of epochs after a TipDistributionAccount's creation its `merkle_root_upload_authority`
+    /// has to upload the merkle root. Once it passes, the upload falls back to the
+    /// MerkleRootUploadConfig `override_authority`. `0` disables the deadline.
+    pub merkle_root_upload_deadline_epochs: u64,
 }
 
 unsafe impl Transmutable for Config {
@@ -34,9 +45,37 @@ unsafe impl Transmutable for Config {
     const LEN: usize = core::mem::size_of::<Self>();
 }
 
+impl ProgramAccount for Config {
+    const DISCRIMINATOR: [u8; 8] = account_discriminator("Config");
+
+    type SeedArgs<'a> = ();
+
+    type Seeds<'a> = [&'a [u8]; 1];
+
+    fn pda_seeds(&self, _args: ()) -> Self::Seeds<'_> {
+        [Self::SEED]
+    }
+}
+
+impl VersionedAccount for Config {
+    /// Version 2 added `merkle_root_upload_deadline_epochs`.
+    const VERSION: u8 = 2;
+
+    // The version byte fits in the padding at the end of the version 0 layout.
+    const V0_SPACE: usize =
+        DISCRIMINATOR_LEN + core::mem::offset_of!(Self, merkle_root_upload_deadline_epochs);
+
+    const VERSION_OFFSET: usize = core::mem::offset_of!(Self, version);
+
+    fn backfill(&mut self, from_version: u8) {
+        if from_version < 2 {
+            self.merkle_root_upload_deadline_epochs = 0;
+        }
+    }
+}
+
 impl Config {
     pub const SEED: &'static [u8] = b"CONFIG_ACCOUNT";
-    pub const DISCRIMINATOR: &'static [u8] = &[155, 12, 170, 224, 30, 250, 204, 130];
 
     /// Initialize a [`Config`]
     #[inline(always)]
@@ -53,6 +92,8 @@ impl Config {
             num_epochs_valid,
             max_validator_commission_bps,
             bump,
+            version: Self::VERSION,
+            merkle_root_upload_deadline_epochs: 0,
         }
     }
 
@@ -71,6 +112,13 @@ impl Config {
             return Err(TipDistributionError::AccountValidationFailure);
         }
 
+        if self.merkle_root_upload_deadline_epochs != 0
+            && self.merkle_root_upload_deadline_epochs >= self.num_epochs_valid
+        {
+            msg!("merkle_root_upload_deadline_epochs should be less than num_epochs_valid");
+            return Err(TipDistributionError::AccountValidationFailure);
+        }
+
         let default_pubkey = Pubkey::default();
         if self.expired_funds_account == default_pubkey || self.authority == default_pubkey {
             msg!("expired_funds_account should not default pubkey");
@@ -80,6 +128,24 @@ impl Config {
         Ok(())
     }
 
+    /// Returns `true` once the merkle root upload deadline of a TipDistributionAccount created at
+    /// `epoch_created_at` has passed.
+    #[inline(always)]
+    pub fn merkle_root_upload_deadline_passed(
+        &self,
+        epoch_created_at: u64,
+        current_epoch: u64,
+    ) -> Result<bool, TipDistributionError> {
+        if self.merkle_root_upload_deadline_epochs == 0 {
+            return Ok(false);
+        }
+
+        let deadline = epoch_created_at
+            .checked_add(self.merkle_root_upload_deadline_epochs)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+        Ok(current_epoch > deadline)
+    }
+
     /// Returns the seeds for the PDA
     pub fn seeds() -> Vec<Vec<u8>> {
         vec![b"CONFIG_ACCOUNT".to_vec()]
@@ -100,46 +166,4 @@ impl Config {
         let (pda, bump) = find_program_address(&seeds_iter, program_id);
         (pda, bump, seeds)
     }
-
-    /// Attempts to load the account as [`Config`], returning an error if it's not valid.
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `account` - The account to load the configuration from
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    ///
-    /// # Safety
-    #[inline(always)]
-    pub unsafe fn load(
-        program_id: &Pubkey,
-        account: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if account.owner().ne(program_id) {
-            msg!("Config account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if account.data_is_empty() {
-            msg!("Config account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !account.is_writable() {
-            msg!("Config account is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        let data = account.borrow_data_unchecked();
-        if data[0..8].ne(Self::DISCRIMINATOR) {
-            msg!("Config account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if account.key().ne(&Self::find_program_address(program_id).0) {
-            msg!("Config account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
-    }
 }
@@ -0,0 +1,80 @@
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+};
+
+use crate::merkle_root::MerkleRoot;
+
+/// Lamport transfers of the program-owned accounts that hold the lamports they distribute.
+///
+/// Those are the [TipDistributionAccount](crate::tip_distribution_account::TipDistributionAccount)
+/// and the
+/// [PriorityFeeDistributionAccount](crate::priority_fee_distribution_account::PriorityFeeDistributionAccount).
+pub trait DistributionAccount {
+    /// Moves everything above the rent-exempt minimum out of `from`.
+    #[inline(always)]
+    fn claim_expired(from: &AccountInfo, to: &AccountInfo) -> Result<u64, ProgramError> {
+        let rent = Rent::get()?;
+        let min_rent_lamports = rent.minimum_balance(from.data_len());
+
+        let amount = from
+            .lamports()
+            .checked_sub(min_rent_lamports)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+        Self::transfer_lamports(from, to, amount)?;
+
+        Ok(amount)
+    }
+
+    /// Returns `true` if the lamports of `account_info` cover its rent-exempt minimum plus
+    /// everything that can still be claimed from `merkle_root`.
+    #[inline(always)]
+    fn is_funded(
+        account_info: &AccountInfo,
+        merkle_root: &MerkleRoot,
+        rent: &Rent,
+    ) -> Result<bool, TipDistributionError> {
+        let remaining_claimable = merkle_root
+            .max_total_claim
+            .checked_sub(merkle_root.total_funds_claimed)
+            .ok_or(TipDistributionError::ExceedsMaxClaim)?;
+        let required_lamports = rent
+            .minimum_balance(account_info.data_len())
+            .checked_add(remaining_claimable)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+
+        Ok(account_info.lamports() >= required_lamports)
+    }
+
+    #[inline(always)]
+    fn claim(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<(), ProgramError> {
+        Self::transfer_lamports(from, to, amount)
+    }
+
+    /// Moves lamports out of the program-owned account directly; the system program can't debit
+    /// it.
+    #[inline(always)]
+    fn transfer_lamports(
+        from: &AccountInfo,
+        to: &AccountInfo,
+        amount: u64,
+    ) -> Result<(), ProgramError> {
+        // debit lamports
+        let from_lamports = from
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+        // credit lamports
+        let to_lamports = to
+            .lamports()
+            .checked_add(amount)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+
+        *from.try_borrow_mut_lamports()? = from_lamports;
+        *to.try_borrow_mut_lamports()? = to_lamports;
+
+        Ok(())
+    }
+}
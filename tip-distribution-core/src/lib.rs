@@ -1,12 +1,16 @@
 use jito_tip_core::transmutable::Transmutable;
-use jito_tip_distribution_sdk::error::TipDistributionError;
 use pinocchio::program_error::ProgramError;
 
+pub mod claim_delegate;
 pub mod claim_status;
 pub mod config;
+pub mod distribution_account;
+pub mod merkle_proof;
 pub mod merkle_root;
 pub mod merkle_root_upload_config;
+pub mod priority_fee_distribution_account;
 pub mod tip_distribution_account;
+pub mod token_merkle_root;
 
 // Trait to represent a type that can be initialized.
 // pub trait Initializable {
@@ -25,11 +29,7 @@ pub mod tip_distribution_account;
 pub unsafe fn load_mut_unchecked<T: Transmutable>(
     bytes: &mut [u8],
 ) -> Result<&mut T, ProgramError> {
-    if bytes.len()
-        != T::LEN
-            .checked_sub(8)
-            .ok_or(TipDistributionError::ArithmeticError)?
-    {
+    if bytes.len() != T::LEN {
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -0,0 +1,42 @@
+use jito_tip_core::hash::{hashv, HASH_BYTES};
+
+/// Prefix of a leaf node, to keep leaves and intermediate nodes in separate domains.
+const LEAF_PREFIX: &[u8] = &[0];
+
+/// Prefix of an intermediate node.
+const INTERMEDIATE_PREFIX: &[u8] = &[1];
+
+/// Returns the leaf node of the merkle tree that entitles `claimant` to `amount` lamports.
+#[inline(always)]
+pub fn leaf(claimant: &[u8; 32], amount: u64) -> [u8; HASH_BYTES] {
+    hashv(&[
+        LEAF_PREFIX,
+        &hashv(&[claimant.as_slice(), &amount.to_le_bytes()]),
+    ])
+}
+
+/// Returns the parent of two nodes, which are hashed in sorted order.
+#[inline(always)]
+pub fn intermediate(a: &[u8; HASH_BYTES], b: &[u8; HASH_BYTES]) -> [u8; HASH_BYTES] {
+    if a <= b {
+        hashv(&[INTERMEDIATE_PREFIX, a, b])
+    } else {
+        hashv(&[INTERMEDIATE_PREFIX, b, a])
+    }
+}
+
+/// This function deals with verification of Merkle trees (hash trees).
+///
+/// Direct port of https://github.com/OpenZeppelin/openzeppelin-contracts/blob/v3.4.0/contracts/cryptography/MerkleProof.sol
+/// Returns true if a `leaf` can be proved to be a part of a Merkle tree
+/// defined by `root`. For this, a `proof` must be provided, containing
+/// sibling hashes on the branch from the leaf to the root of the tree. Each
+/// pair of leaves and each pair of pre-images are assumed to be sorted.
+pub fn verify(proof: &[[u8; HASH_BYTES]], root: &[u8; HASH_BYTES], leaf: [u8; HASH_BYTES]) -> bool {
+    let mut computed_hash = leaf;
+    for proof_element in proof {
+        computed_hash = intermediate(&computed_hash, proof_element);
+    }
+    // Check if the computed hash (root) is equal to the provided root
+    computed_hash == *root
+}
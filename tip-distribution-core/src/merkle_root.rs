@@ -17,3 +17,42 @@ pub struct MerkleRoot {
     /// Number of nodes that have been claimed.
     pub num_nodes_claimed: u64,
 }
+
+impl MerkleRoot {
+    /// Length of the Borsh encoding of a [MerkleRoot].
+    pub const LEN: usize = 64;
+
+    /// Decodes a [MerkleRoot] from its Borsh (little-endian) encoding.
+    #[inline(always)]
+    pub fn from_bytes(bytes: &[u8; Self::LEN]) -> Self {
+        let mut root = [0; 32];
+        root.copy_from_slice(&bytes[0..32]);
+
+        Self {
+            root,
+            max_total_claim: read_u64(&bytes[32..40]),
+            max_num_nodes: read_u64(&bytes[40..48]),
+            total_funds_claimed: read_u64(&bytes[48..56]),
+            num_nodes_claimed: read_u64(&bytes[56..64]),
+        }
+    }
+
+    /// Encodes the [MerkleRoot] with the same layout as Borsh.
+    #[inline(always)]
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0; Self::LEN];
+        bytes[0..32].copy_from_slice(&self.root);
+        bytes[32..40].copy_from_slice(&self.max_total_claim.to_le_bytes());
+        bytes[40..48].copy_from_slice(&self.max_num_nodes.to_le_bytes());
+        bytes[48..56].copy_from_slice(&self.total_funds_claimed.to_le_bytes());
+        bytes[56..64].copy_from_slice(&self.num_nodes_claimed.to_le_bytes());
+        bytes
+    }
+}
+
+#[inline(always)]
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buf = [0; 8];
+    buf.copy_from_slice(bytes);
+    u64::from_le_bytes(buf)
+}
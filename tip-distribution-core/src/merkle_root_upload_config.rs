@@ -1,9 +1,10 @@
-use pinocchio::{
-    account_info::AccountInfo,
-    msg,
-    program_error::ProgramError,
-    pubkey::{find_program_address, Pubkey},
+use jito_tip_core::{
+    discriminator::account_discriminator,
+    program_account::{ProgramAccount, DISCRIMINATOR_LEN},
+    versioned_account::VersionedAccount,
 };
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::pubkey::{find_program_address, Pubkey};
 
 use crate::Transmutable;
 
@@ -19,6 +20,16 @@ pub struct MerkleRootUploadConfig {
 
     /// The bump used to generate this account
     pub bump: u8,
+
+    /// The layout version, see [VersionedAccount].
+    pub version: u8,
+
+    /// Number of keys in use at the start of `permitted_uploaders`.
+    num_permitted_uploaders: u8,
+
+    /// Keys that may upload merkle roots to the TipDistributionAccounts whose upload authority is
+    /// the `override_authority`, e.g. a primary and a failover tree generator.
+    permitted_uploaders: [Pubkey; MerkleRootUploadConfig::MAX_PERMITTED_UPLOADERS],
 }
 
 unsafe impl Transmutable for MerkleRootUploadConfig {
@@ -26,9 +37,94 @@ unsafe impl Transmutable for MerkleRootUploadConfig {
     const LEN: usize = core::mem::size_of::<Self>();
 }
 
+impl ProgramAccount for MerkleRootUploadConfig {
+    const DISCRIMINATOR: [u8; 8] = account_discriminator("MerkleRootUploadConfig");
+
+    type SeedArgs<'a> = ();
+
+    type Seeds<'a> = [&'a [u8]; 1];
+
+    fn pda_seeds(&self, _args: ()) -> Self::Seeds<'_> {
+        [Self::ROOT_UPLOAD_CONFIG_SEED]
+    }
+}
+
+impl VersionedAccount for MerkleRootUploadConfig {
+    /// Version 2 added the permitted uploaders.
+    const VERSION: u8 = 2;
+
+    const V0_SPACE: usize = DISCRIMINATOR_LEN + Self::VERSION_OFFSET;
+
+    const VERSION_OFFSET: usize = core::mem::offset_of!(Self, version);
+
+    fn backfill(&mut self, from_version: u8) {
+        if from_version < 2 {
+            self.num_permitted_uploaders = 0;
+        }
+    }
+}
+
 impl MerkleRootUploadConfig {
     pub const ROOT_UPLOAD_CONFIG_SEED: &'static [u8] = b"ROOT_UPLOAD_CONFIG";
-    pub const DISCRIMINATOR: &'static [u8] = &[213, 125, 30, 192, 25, 121, 87, 33];
+
+    /// Maximum number of keys in `permitted_uploaders`.
+    pub const MAX_PERMITTED_UPLOADERS: usize = 8;
+
+    /// Returns the keys permitted to upload merkle roots on behalf of the `override_authority`.
+    pub fn permitted_uploaders(&self) -> &[Pubkey] {
+        let len = usize::from(self.num_permitted_uploaders).min(Self::MAX_PERMITTED_UPLOADERS);
+        &self.permitted_uploaders[..len]
+    }
+
+    /// Returns `true` if `uploader` may upload merkle roots on behalf of the `override_authority`.
+    pub fn is_permitted_uploader(&self, uploader: &Pubkey) -> bool {
+        self.permitted_uploaders().contains(uploader)
+    }
+
+    /// Appends `uploader` to the permitted uploaders.
+    pub fn add_permitted_uploader(&mut self, uploader: Pubkey) -> Result<(), TipDistributionError> {
+        if self.is_permitted_uploader(&uploader) {
+            return Err(TipDistributionError::PermittedUploaderAlreadyExists);
+        }
+
+        let index = self.permitted_uploaders().len();
+        let slot = self
+            .permitted_uploaders
+            .get_mut(index)
+            .ok_or(TipDistributionError::PermittedUploadersFull)?;
+        *slot = uploader;
+        self.num_permitted_uploaders = self
+            .num_permitted_uploaders
+            .checked_add(1)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+
+        Ok(())
+    }
+
+    /// Removes `uploader` from the permitted uploaders, keeping the others in order.
+    pub fn remove_permitted_uploader(
+        &mut self,
+        uploader: &Pubkey,
+    ) -> Result<(), TipDistributionError> {
+        let len = self.permitted_uploaders().len();
+        let index = self
+            .permitted_uploaders()
+            .iter()
+            .position(|permitted_uploader| permitted_uploader.eq(uploader))
+            .ok_or(TipDistributionError::PermittedUploaderNotFound)?;
+
+        self.permitted_uploaders[index..len].rotate_left(1);
+        let last = len
+            .checked_sub(1)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+        self.permitted_uploaders[last] = Pubkey::default();
+        self.num_permitted_uploaders = self
+            .num_permitted_uploaders
+            .checked_sub(1)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+
+        Ok(())
+    }
 
     /// Find the program address for the global configuration account
     ///
@@ -40,51 +136,8 @@ impl MerkleRootUploadConfig {
     /// * `Vec<Vec<u8>>` - The seeds used to generate the PDA
     #[inline(always)]
     pub fn find_program_address(program_id: &Pubkey) -> (Pubkey, u8) {
-        // let seeds = Self::seeds();
-        // let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
         let seeds = [Self::ROOT_UPLOAD_CONFIG_SEED];
         let (pda, bump) = find_program_address(&seeds, program_id);
         (pda, bump)
     }
-
-    /// Attempts to load the account as [`Config`], returning an error if it's not valid.
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `account` - The account to load the configuration from
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    ///
-    /// # Safety
-    pub unsafe fn load(
-        program_id: &Pubkey,
-        account: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if account.owner().ne(program_id) {
-            msg!("MerkleRootUploadConfig account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if account.data_is_empty() {
-            msg!("MerkleRootUploadConfig account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !account.is_writable() {
-            msg!("MerkleRootUploadConfig account is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        let data = account.borrow_data_unchecked();
-        if data[0..8].ne(Self::DISCRIMINATOR) {
-            msg!("MerkleRootUploadConfig account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if account.key().ne(&Self::find_program_address(program_id).0) {
-            msg!("MerkleRootUploadConfig account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
-    }
 }
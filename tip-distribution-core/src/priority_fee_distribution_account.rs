@@ -0,0 +1,146 @@
+use jito_tip_core::{discriminator::account_discriminator, program_account::ProgramAccount};
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::pubkey::{find_program_address, Pubkey};
+
+use crate::{distribution_account::DistributionAccount, merkle_root::MerkleRoot, Transmutable};
+
+/// The per-epoch account validators deposit block priority fees into, to be claimed by their
+/// stakers with merkle proofs.
+///
+/// Works like a [TipDistributionAccount](crate::tip_distribution_account::TipDistributionAccount)
+/// under its own seeds, with a fixed layout since it has no Anchor counterpart to stay
+/// compatible with.
+#[derive(Debug)]
+#[repr(C)]
+pub struct PriorityFeeDistributionAccount {
+    /// The validator's vote account.
+    pub validator_vote_account: Pubkey,
+
+    /// The only account authorized to upload a merkle-root for this account.
+    pub merkle_root_upload_authority: Pubkey,
+
+    /// Borsh encoding of the [MerkleRoot], all zeroes until one is uploaded.
+    merkle_root: [u8; MerkleRoot::LEN],
+
+    /// Little-endian epoch for which this account was created, stored as bytes so it can be
+    /// borrowed as a PDA seed.
+    epoch_created_at: [u8; 8],
+
+    /// The epoch (upto and including) that priority fees can be claimed.
+    pub expires_at: u64,
+
+    /// Total lamports deposited with `TransferPriorityFees`.
+    pub total_lamports_transferred: u64,
+
+    /// The commission basis points this validator charges.
+    pub validator_commission_bps: u16,
+
+    /// `1` once a merkle root has been uploaded.
+    merkle_root_uploaded: u8,
+
+    /// The bump used to generate this account
+    pub bump: u8,
+}
+
+unsafe impl Transmutable for PriorityFeeDistributionAccount {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl ProgramAccount for PriorityFeeDistributionAccount {
+    const DISCRIMINATOR: [u8; 8] = account_discriminator("PriorityFeeDistributionAccount");
+
+    type SeedArgs<'a> = ();
+
+    type Seeds<'a> = [&'a [u8]; 3];
+
+    fn pda_seeds(&self, _args: ()) -> Self::Seeds<'_> {
+        [
+            Self::SEED,
+            self.validator_vote_account.as_ref(),
+            &self.epoch_created_at,
+        ]
+    }
+}
+
+impl DistributionAccount for PriorityFeeDistributionAccount {}
+
+impl PriorityFeeDistributionAccount {
+    pub const SEED: &'static [u8] = b"PF_DISTRIBUTION_ACCOUNT";
+
+    /// Initializes a freshly allocated [PriorityFeeDistributionAccount] in place.
+    #[inline(always)]
+    pub fn initialize(
+        &mut self,
+        validator_vote_account: Pubkey,
+        current_epoch: u64,
+        validator_commission_bps: u16,
+        merkle_root_upload_authority: Pubkey,
+        bump: u8,
+        num_epochs_valid: u64,
+    ) -> Result<(), TipDistributionError> {
+        self.expires_at = current_epoch
+            .checked_add(num_epochs_valid)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+        self.validator_vote_account = validator_vote_account;
+        self.merkle_root_upload_authority = merkle_root_upload_authority;
+        self.merkle_root = [0; MerkleRoot::LEN];
+        self.epoch_created_at = current_epoch.to_le_bytes();
+        self.total_lamports_transferred = 0;
+        self.validator_commission_bps = validator_commission_bps;
+        self.merkle_root_uploaded = 0;
+        self.bump = bump;
+
+        Ok(())
+    }
+
+    /// Epoch for which this account was created.
+    #[inline(always)]
+    pub const fn epoch_created_at(&self) -> u64 {
+        u64::from_le_bytes(self.epoch_created_at)
+    }
+
+    /// The merkle root used to verify user claims from this account.
+    #[inline(always)]
+    pub fn merkle_root(&self) -> Option<MerkleRoot> {
+        if self.merkle_root_uploaded == 0 {
+            return None;
+        }
+
+        Some(MerkleRoot::from_bytes(&self.merkle_root))
+    }
+
+    #[inline(always)]
+    pub fn set_merkle_root(&mut self, merkle_root: &MerkleRoot) {
+        self.merkle_root = merkle_root.to_bytes();
+        self.merkle_root_uploaded = 1;
+    }
+
+    #[inline(always)]
+    pub fn validate(&self) -> Result<(), TipDistributionError> {
+        let default_pubkey = Pubkey::default();
+        if self.validator_vote_account == default_pubkey
+            || self.merkle_root_upload_authority == default_pubkey
+        {
+            return Err(TipDistributionError::AccountValidationFailure);
+        }
+
+        Ok(())
+    }
+
+    /// Find the program address for the PDA
+    #[inline(always)]
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        validator_vote_account: &Pubkey,
+        epoch: u64,
+    ) -> (Pubkey, u8) {
+        let epoch_bytes = epoch.to_le_bytes();
+        let seeds = [
+            Self::SEED,
+            validator_vote_account.as_ref(),
+            epoch_bytes.as_ref(),
+        ];
+
+        find_program_address(&seeds, program_id)
+    }
+}
@@ -1,18 +1,21 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use jito_tip_distribution_sdk::error::TipDistributionError;
-use pinocchio::{
-    account_info::AccountInfo,
-    msg,
-    program_error::ProgramError,
-    pubkey::{find_program_address, Pubkey},
-    sysvars::{rent::Rent, Sysvar},
+use jito_tip_core::{
+    discriminator::account_discriminator,
+    program_account::{ProgramAccount, DISCRIMINATOR_LEN},
+    versioned_account::VersionedAccount,
 };
-use pinocchio_system::instructions::Transfer;
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::pubkey::{find_program_address, Pubkey};
 
-use crate::{merkle_root::MerkleRoot, Transmutable};
+use crate::{distribution_account::DistributionAccount, merkle_root::MerkleRoot, Transmutable};
 
 /// The account that validators register as **tip_receiver** with the tip-payment program.
-#[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+///
+/// The first 160 bytes are a zero-copy view over the Borsh encoding written by the Anchor program.
+/// Since `merkle_root` is an `Option`, every field stored after it moves by [MerkleRoot::LEN] bytes
+/// once a root has been uploaded, so those fields are only reachable through accessors. Fields
+/// added since are stored after that region and the `version` byte, at fixed offsets.
+#[derive(Debug)]
+#[repr(C)]
 pub struct TipDistributionAccount {
     /// The validator's vote account, also the recipient of remaining lamports after
     /// upon closing this account.
@@ -21,109 +24,276 @@ pub struct TipDistributionAccount {
     /// The only account authorized to upload a merkle-root for this account.
     pub merkle_root_upload_authority: Pubkey,
 
-    /// The merkle root used to verify user claims from this account.
-    pub merkle_root: Option<MerkleRoot>,
+    /// Borsh tag of the `Option<MerkleRoot>`: `0` for `None`, `1` for `Some`.
+    merkle_root_tag: u8,
 
-    /// Epoch for which this account was created.  
-    pub epoch_created_at: u64,
+    /// The encoded [MerkleRoot] (only present when `merkle_root_tag` is `1`), followed by
+    /// `epoch_created_at`, `validator_commission_bps`, `expires_at` and `bump`.
+    data: [u8; 95],
 
-    /// The commission basis points this validator charges.
-    pub validator_commission_bps: u16,
+    /// The layout version, see [VersionedAccount].
+    pub version: u8,
 
-    /// The epoch (upto and including) that tip funds can be claimed.
-    pub expires_at: u64,
+    /// The account the rent is refunded to when this account is closed, chosen by the validator
+    /// at initialization.
+    pub rent_refund_destination: Pubkey,
 
-    /// The bump used to generate this account
-    pub bump: u8,
+    /// `1` if unclaimed funds roll over into a later [TipDistributionAccount] of the same
+    /// validator on closure instead of going to the `expired_funds_account`.
+    roll_over_unclaimed_funds: u8,
+
+    /// Number of token vaults created for this account, little-endian. They must be swept before
+    /// it can be closed.
+    num_token_vaults: [u8; 2],
 }
 
 unsafe impl Transmutable for TipDistributionAccount {
-    // header, fields, and InitBumps
-    // const LEN: usize = 8 + core::mem::size_of::<Self>();
-    const LEN: usize = 168;
+    // The 160 bytes Anchor allocates after the discriminator, plus the fields added since.
+    const LEN: usize = core::mem::size_of::<Self>();
 }
 
+impl ProgramAccount for TipDistributionAccount {
+    const DISCRIMINATOR: [u8; 8] = account_discriminator("TipDistributionAccount");
+
+    type SeedArgs<'a> = ();
+
+    type Seeds<'a> = [&'a [u8]; 3];
+
+    fn pda_seeds(&self, _args: ()) -> Self::Seeds<'_> {
+        [
+            Self::SEED,
+            self.validator_vote_account.as_ref(),
+            // Stored little-endian, like the seed.
+            &self.tail()[Self::EPOCH_CREATED_AT_OFFSET..Self::VALIDATOR_COMMISSION_BPS_OFFSET],
+        ]
+    }
+}
+
+impl VersionedAccount for TipDistributionAccount {
+    /// Version 1 added `rent_refund_destination`, `roll_over_unclaimed_funds` and
+    /// `num_token_vaults`.
+    const VERSION: u8 = 1;
+
+    /// The Anchor layout.
+    const V0_SPACE: usize = DISCRIMINATOR_LEN + Self::VERSION_OFFSET;
+
+    const VERSION_OFFSET: usize = core::mem::offset_of!(Self, version);
+
+    fn backfill(&mut self, from_version: u8) {
+        if from_version < 1 {
+            // Refund the rent to the validator like the Anchor program did.
+            self.rent_refund_destination = self.validator_vote_account;
+            self.roll_over_unclaimed_funds = 0;
+            self.num_token_vaults = [0; 2];
+        }
+    }
+}
+
+impl DistributionAccount for TipDistributionAccount {}
+
 impl TipDistributionAccount {
     pub const SEED: &'static [u8] = b"TIP_DISTRIBUTION_ACCOUNT";
-    pub const DISCRIMINATOR: &'static [u8] = &[85, 64, 113, 198, 234, 94, 120, 123];
 
+    /// Seed of the token vaults, see [Self::find_token_vault_address].
+    pub const TOKEN_VAULT_SEED: &'static [u8] = b"TOKEN_VAULT";
+
+    /// Offset of `validator_vote_account`, relative to the end of the discriminator.
+    pub const VALIDATOR_VOTE_ACCOUNT_OFFSET: usize = 0;
+
+    /// Offset of `merkle_root_upload_authority`, relative to the end of the discriminator.
+    pub const MERKLE_ROOT_UPLOAD_AUTHORITY_OFFSET: usize = 32;
+
+    /// Offset of the `Option<MerkleRoot>` tag, relative to the end of the discriminator.
+    pub const MERKLE_ROOT_OFFSET: usize = 64;
+
+    /// Offsets of the trailing fields, relative to the first byte after the merkle root.
+    pub const EPOCH_CREATED_AT_OFFSET: usize = 0;
+    pub const VALIDATOR_COMMISSION_BPS_OFFSET: usize = 8;
+    pub const EXPIRES_AT_OFFSET: usize = 10;
+    pub const BUMP_OFFSET: usize = 18;
+
+    /// Offset of `rent_refund_destination`, relative to the end of the discriminator.
+    pub const RENT_REFUND_DESTINATION_OFFSET: usize = 161;
+
+    /// Offset of the `roll_over_unclaimed_funds` flag, relative to the end of the discriminator.
+    pub const ROLL_OVER_UNCLAIMED_FUNDS_OFFSET: usize = 193;
+
+    /// Offset of `num_token_vaults`, relative to the end of the discriminator.
+    pub const NUM_TOKEN_VAULTS_OFFSET: usize = 194;
+
+    /// Length of the fields stored after the merkle root.
+    const TAIL_LEN: usize = 19;
+
+    /// End of the trailing fields inside `data` once a root has been uploaded.
+    const TAIL_WITH_ROOT_END: usize = MerkleRoot::LEN + Self::TAIL_LEN;
+
+    /// Initializes a freshly allocated [TipDistributionAccount] in place.
+    #[allow(clippy::too_many_arguments)]
     #[inline(always)]
-    pub fn new(
+    pub fn initialize(
+        &mut self,
         validator_vote_account: Pubkey,
         current_epoch: u64,
         validator_commission_bps: u16,
         merkle_root_upload_authority: Pubkey,
         bump: u8,
         num_epochs_valid: u64,
-    ) -> Result<Self, TipDistributionError> {
+        rent_refund_destination: Pubkey,
+        roll_over_unclaimed_funds: bool,
+    ) -> Result<(), TipDistributionError> {
         let expires_at = current_epoch
             .checked_add(num_epochs_valid)
             .ok_or(TipDistributionError::ArithmeticError)?;
 
-        Ok(Self {
-            validator_vote_account,
-            epoch_created_at: current_epoch,
-            validator_commission_bps,
-            merkle_root_upload_authority,
-            merkle_root: None,
-            expires_at,
-            bump,
-        })
+        self.validator_vote_account = validator_vote_account;
+        self.merkle_root_upload_authority = merkle_root_upload_authority;
+        self.merkle_root_tag = 0;
+        self.data = [0; 95];
+        self.rent_refund_destination = rent_refund_destination;
+        self.roll_over_unclaimed_funds = roll_over_unclaimed_funds as u8;
+        self.num_token_vaults = [0; 2];
+
+        let tail = self.tail_mut();
+        tail[Self::EPOCH_CREATED_AT_OFFSET..Self::VALIDATOR_COMMISSION_BPS_OFFSET]
+            .copy_from_slice(&current_epoch.to_le_bytes());
+        tail[Self::VALIDATOR_COMMISSION_BPS_OFFSET..Self::EXPIRES_AT_OFFSET]
+            .copy_from_slice(&validator_commission_bps.to_le_bytes());
+        tail[Self::EXPIRES_AT_OFFSET..Self::BUMP_OFFSET].copy_from_slice(&expires_at.to_le_bytes());
+        tail[Self::BUMP_OFFSET] = bump;
+
+        Ok(())
     }
 
+    /// Returns `true` if a merkle root has been uploaded.
     #[inline(always)]
-    pub fn validate(&self) -> Result<(), TipDistributionError> {
-        let default_pubkey = Pubkey::default();
-        if self.validator_vote_account == default_pubkey
-            || self.merkle_root_upload_authority == default_pubkey
-        {
-            return Err(TipDistributionError::AccountValidationFailure);
+    pub const fn has_merkle_root(&self) -> bool {
+        self.merkle_root_tag != 0
+    }
+
+    /// The merkle root used to verify user claims from this account.
+    #[inline(always)]
+    pub fn merkle_root(&self) -> Option<MerkleRoot> {
+        if !self.has_merkle_root() {
+            return None;
         }
 
-        Ok(())
+        let mut bytes = [0; MerkleRoot::LEN];
+        bytes.copy_from_slice(&self.data[..MerkleRoot::LEN]);
+        Some(MerkleRoot::from_bytes(&bytes))
     }
 
+    /// Stores `merkle_root`, shifting the trailing fields if this is the first root uploaded.
     #[inline(always)]
-    pub fn claim_expired(from: &AccountInfo, to: &AccountInfo) -> Result<u64, ProgramError> {
-        let rent = Rent::get()?;
-        let min_rent_lamports = rent.minimum_balance(from.data_len());
+    pub fn set_merkle_root(&mut self, merkle_root: &MerkleRoot) {
+        if !self.has_merkle_root() {
+            self.data.copy_within(..Self::TAIL_LEN, MerkleRoot::LEN);
+            self.merkle_root_tag = 1;
+        }
+
+        self.data[..MerkleRoot::LEN].copy_from_slice(&merkle_root.to_bytes());
+    }
+
+    /// Returns `true` if unclaimed funds roll over into a later [TipDistributionAccount] of the
+    /// same validator on closure.
+    #[inline(always)]
+    pub const fn roll_over_unclaimed_funds(&self) -> bool {
+        self.roll_over_unclaimed_funds != 0
+    }
+
+    /// Number of token vaults that haven't been swept yet.
+    #[inline(always)]
+    pub const fn num_token_vaults(&self) -> u16 {
+        u16::from_le_bytes(self.num_token_vaults)
+    }
 
-        let amount = from
-            .lamports()
-            .checked_sub(min_rent_lamports)
+    /// Records that a token vault was created for this account.
+    #[inline(always)]
+    pub fn add_token_vault(&mut self) -> Result<(), TipDistributionError> {
+        let num_token_vaults = self
+            .num_token_vaults()
+            .checked_add(1)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+        self.num_token_vaults = num_token_vaults.to_le_bytes();
+
+        Ok(())
+    }
+
+    /// Records that a token vault of this account was swept and closed.
+    #[inline(always)]
+    pub fn remove_token_vault(&mut self) -> Result<(), TipDistributionError> {
+        let num_token_vaults = self
+            .num_token_vaults()
+            .checked_sub(1)
             .ok_or(TipDistributionError::ArithmeticError)?;
-        Self::transfer_lamports(from, to, amount)?;
+        self.num_token_vaults = num_token_vaults.to_le_bytes();
 
-        Ok(amount)
+        Ok(())
     }
 
+    /// The last epoch in which unclaimed funds must roll over. If the validator hasn't created a
+    /// later account to roll them into by then, they go to the `expired_funds_account` instead so
+    /// they can't get stuck.
     #[inline(always)]
-    pub fn claim(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<(), ProgramError> {
-        Self::transfer_lamports(from, to, amount)
+    pub fn roll_over_deadline(&self, num_epochs_valid: u64) -> Result<u64, TipDistributionError> {
+        self.expires_at()
+            .checked_add(num_epochs_valid)
+            .ok_or(TipDistributionError::ArithmeticError)
     }
 
+    /// Epoch for which this account was created.
     #[inline(always)]
-    fn transfer_lamports(
-        from: &AccountInfo,
-        to: &AccountInfo,
-        amount: u64,
-    ) -> Result<(), ProgramError> {
-        Transfer {
-            from,
-            to,
-            lamports: amount,
+    pub fn epoch_created_at(&self) -> u64 {
+        read_u64(&self.tail()[Self::EPOCH_CREATED_AT_OFFSET..Self::VALIDATOR_COMMISSION_BPS_OFFSET])
+    }
+
+    /// The commission basis points this validator charges.
+    #[inline(always)]
+    pub fn validator_commission_bps(&self) -> u16 {
+        let mut buf = [0; 2];
+        buf.copy_from_slice(
+            &self.tail()[Self::VALIDATOR_COMMISSION_BPS_OFFSET..Self::EXPIRES_AT_OFFSET],
+        );
+        u16::from_le_bytes(buf)
+    }
+
+    /// The epoch (upto and including) that tip funds can be claimed.
+    #[inline(always)]
+    pub fn expires_at(&self) -> u64 {
+        read_u64(&self.tail()[Self::EXPIRES_AT_OFFSET..Self::BUMP_OFFSET])
+    }
+
+    /// The bump used to generate this account
+    #[inline(always)]
+    pub fn bump(&self) -> u8 {
+        self.tail()[Self::BUMP_OFFSET]
+    }
+
+    #[inline(always)]
+    fn tail(&self) -> &[u8] {
+        if self.has_merkle_root() {
+            &self.data[MerkleRoot::LEN..Self::TAIL_WITH_ROOT_END]
+        } else {
+            &self.data[..Self::TAIL_LEN]
+        }
+    }
+
+    #[inline(always)]
+    fn tail_mut(&mut self) -> &mut [u8] {
+        if self.has_merkle_root() {
+            &mut self.data[MerkleRoot::LEN..Self::TAIL_WITH_ROOT_END]
+        } else {
+            &mut self.data[..Self::TAIL_LEN]
+        }
+    }
+
+    #[inline(always)]
+    pub fn validate(&self) -> Result<(), TipDistributionError> {
+        let default_pubkey = Pubkey::default();
+        if self.validator_vote_account == default_pubkey
+            || self.merkle_root_upload_authority == default_pubkey
+        {
+            return Err(TipDistributionError::AccountValidationFailure);
         }
-        .invoke()?;
-        // debit lamports
-        // *from.try_borrow_mut_lamports()? = from
-        //     .lamports()
-        //     .checked_sub(amount)
-        //     .ok_or(TipDistributionError::ArithmeticError)?;
-        // credit lamports
-        // to.try_borrow_mut_lamports()? = to
-        //     .lamports()
-        //     .checked_add(amount)
-        //     .ok_or(TipDistributionError::ArithmeticError)?;
 
         Ok(())
     }
@@ -137,7 +307,7 @@ impl TipDistributionAccount {
     ) -> (Pubkey, u8) {
         let epoch_bytes = epoch.to_le_bytes();
         let seeds = [
-            b"TIP_DISTRIBUTION_ACCOUNT".as_slice(),
+            Self::SEED,
             validator_vote_account.as_ref(),
             epoch_bytes.as_ref(),
         ];
@@ -145,40 +315,28 @@ impl TipDistributionAccount {
         find_program_address(&seeds, program_id)
     }
 
-    /// Attempts to load the account as [`TipDistributionAccount`], returning an error if it's not valid.
-    ///
-    /// # Safety
+    /// Find the address of the token vault holding the `mint` tokens of
+    /// `tip_distribution_account`: an SPL token account owned by the [TipDistributionAccount].
+    /// Only the program can create it, so every vault is counted in `num_token_vaults`.
     #[inline(always)]
-    pub unsafe fn load(
+    pub fn find_token_vault_address(
         program_id: &Pubkey,
-        tip_distribution_account: &AccountInfo,
-        validator_vote_account: &Pubkey,
-        epoch: u64,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if tip_distribution_account.owner().ne(program_id) {
-            msg!("TipDistributionAccount has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if tip_distribution_account.data_is_empty() {
-            msg!("TipDistributionAccount data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !tip_distribution_account.is_writable() {
-            msg!("TipDistributionAccount is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        let data = tip_distribution_account.borrow_data_unchecked();
-        if data[0..8].ne(Self::DISCRIMINATOR) {
-            msg!("TipDistributionAccount discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        let expected_pubkey =
-            Self::find_program_address(program_id, validator_vote_account, epoch).0;
-        if tip_distribution_account.key().ne(&expected_pubkey) {
-            msg!("TipDistributionAccount is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
+        tip_distribution_account: &Pubkey,
+        mint: &Pubkey,
+    ) -> (Pubkey, u8) {
+        let seeds = [
+            Self::TOKEN_VAULT_SEED,
+            tip_distribution_account.as_ref(),
+            mint.as_ref(),
+        ];
+
+        find_program_address(&seeds, program_id)
     }
 }
+
+#[inline(always)]
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buf = [0; 8];
+    buf.copy_from_slice(bytes);
+    u64::from_le_bytes(buf)
+}
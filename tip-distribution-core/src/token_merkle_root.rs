@@ -0,0 +1,90 @@
+use jito_tip_core::{
+    discriminator::account_discriminator, program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
+};
+use pinocchio::pubkey::{find_program_address, Pubkey};
+
+use crate::{merkle_root::MerkleRoot, Transmutable};
+
+/// The merkle root distributing the SPL tokens of one mint held by a
+/// [TipDistributionAccount](crate::tip_distribution_account::TipDistributionAccount).
+///
+/// The tokens themselves live in the TDA's token vault for `mint`, found with
+/// `TipDistributionAccount::find_token_vault_address`.
+#[derive(Debug)]
+#[repr(C)]
+pub struct TokenMerkleRoot {
+    /// The [TipDistributionAccount](crate::tip_distribution_account::TipDistributionAccount)
+    /// owning the token vault.
+    pub tip_distribution_account: Pubkey,
+
+    /// The mint of the distributed tokens.
+    pub mint: Pubkey,
+
+    /// Borsh encoding of the [MerkleRoot].
+    merkle_root: [u8; MerkleRoot::LEN],
+
+    /// The bump used to generate this account
+    pub bump: u8,
+
+    /// The layout version, see [VersionedAccount].
+    pub version: u8,
+}
+
+unsafe impl Transmutable for TokenMerkleRoot {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl ProgramAccount for TokenMerkleRoot {
+    const DISCRIMINATOR: [u8; 8] = account_discriminator("TokenMerkleRoot");
+
+    type SeedArgs<'a> = ();
+
+    type Seeds<'a> = [&'a [u8]; 3];
+
+    fn pda_seeds(&self, _args: ()) -> Self::Seeds<'_> {
+        [
+            Self::SEED,
+            self.tip_distribution_account.as_ref(),
+            self.mint.as_ref(),
+        ]
+    }
+}
+
+impl VersionedAccount for TokenMerkleRoot {
+    const VERSION: u8 = 1;
+
+    // The account has carried the version byte since it was introduced, so no version 0 layout
+    // is smaller.
+    const V0_SPACE: usize = Self::SPACE;
+
+    const VERSION_OFFSET: usize = core::mem::offset_of!(Self, version);
+
+    fn backfill(&mut self, _from_version: u8) {}
+}
+
+impl TokenMerkleRoot {
+    pub const SEED: &'static [u8] = b"TOKEN_MERKLE_ROOT";
+
+    #[inline(always)]
+    pub fn merkle_root(&self) -> MerkleRoot {
+        MerkleRoot::from_bytes(&self.merkle_root)
+    }
+
+    #[inline(always)]
+    pub fn set_merkle_root(&mut self, merkle_root: &MerkleRoot) {
+        self.merkle_root = merkle_root.to_bytes();
+    }
+
+    /// Find the program address for the PDA
+    #[inline(always)]
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        tip_distribution_account: &Pubkey,
+        mint: &Pubkey,
+    ) -> (Pubkey, u8) {
+        let seeds = [Self::SEED, tip_distribution_account.as_ref(), mint.as_ref()];
+
+        find_program_address(&seeds, program_id)
+    }
+}
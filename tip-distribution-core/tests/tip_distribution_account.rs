@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use jito_tip_core::versioned_account::VersionedAccount;
+    use jito_tip_distribution_core::{
+        merkle_root::MerkleRoot, tip_distribution_account::TipDistributionAccount,
+    };
+
+    /// The account data the Anchor program allocates after the discriminator.
+    const ANCHOR_LEN: usize = 160;
+
+    /// Mirror of the Anchor `TipDistributionAccount`, encoded the way Anchor writes it.
+    #[derive(BorshSerialize)]
+    struct AnchorTipDistributionAccount {
+        validator_vote_account: [u8; 32],
+        merkle_root_upload_authority: [u8; 32],
+        merkle_root: Option<MerkleRoot>,
+        epoch_created_at: u64,
+        validator_commission_bps: u16,
+        expires_at: u64,
+        bump: u8,
+    }
+
+    impl AnchorTipDistributionAccount {
+        fn new(merkle_root: Option<MerkleRoot>) -> Self {
+            Self {
+                validator_vote_account: [1; 32],
+                merkle_root_upload_authority: [2; 32],
+                merkle_root,
+                epoch_created_at: 0x0102_0304_0506_0708,
+                validator_commission_bps: 0x0a0b,
+                expires_at: 0x1112_1314_1516_1718,
+                bump: 254,
+            }
+        }
+
+        /// The Borsh encoding padded with zeroes to the size Anchor allocates.
+        fn encode(&self) -> [u8; ANCHOR_LEN] {
+            let encoded = borsh::to_vec(self).unwrap();
+            let mut data = [0; ANCHOR_LEN];
+            data[..encoded.len()].copy_from_slice(&encoded);
+            data
+        }
+    }
+
+    fn merkle_root() -> MerkleRoot {
+        MerkleRoot {
+            root: [3; 32],
+            max_total_claim: 1_000,
+            max_num_nodes: 10,
+            total_funds_claimed: 400,
+            num_nodes_claimed: 4,
+        }
+    }
+
+    /// Reads a [TipDistributionAccount] from Anchor account data, with the fields added since
+    /// zeroed like a version 0 account.
+    fn view(anchor_data: &[u8; ANCHOR_LEN]) -> TipDistributionAccount {
+        let mut data = vec![0; core::mem::size_of::<TipDistributionAccount>()];
+        data[..ANCHOR_LEN].copy_from_slice(anchor_data);
+        unsafe { core::ptr::read_unaligned(data.as_ptr() as *const TipDistributionAccount) }
+    }
+
+    /// The first [ANCHOR_LEN] bytes of `tip_distribution_account`.
+    fn anchor_bytes(tip_distribution_account: &TipDistributionAccount) -> [u8; ANCHOR_LEN] {
+        let bytes = unsafe {
+            core::slice::from_raw_parts(
+                tip_distribution_account as *const TipDistributionAccount as *const u8,
+                core::mem::size_of::<TipDistributionAccount>(),
+            )
+        };
+        bytes[..ANCHOR_LEN].try_into().unwrap()
+    }
+
+    fn assert_fields(
+        tip_distribution_account: &TipDistributionAccount,
+        anchor: &AnchorTipDistributionAccount,
+    ) {
+        assert_eq!(
+            tip_distribution_account.validator_vote_account,
+            anchor.validator_vote_account
+        );
+        assert_eq!(
+            tip_distribution_account.merkle_root_upload_authority,
+            anchor.merkle_root_upload_authority
+        );
+        assert_eq!(tip_distribution_account.merkle_root(), anchor.merkle_root);
+        assert_eq!(
+            tip_distribution_account.epoch_created_at(),
+            anchor.epoch_created_at
+        );
+        assert_eq!(
+            tip_distribution_account.validator_commission_bps(),
+            anchor.validator_commission_bps
+        );
+        assert_eq!(tip_distribution_account.expires_at(), anchor.expires_at);
+        assert_eq!(tip_distribution_account.bump(), anchor.bump);
+    }
+
+    #[test]
+    fn anchor_layout_fits_before_the_version_byte() {
+        assert_eq!(TipDistributionAccount::VERSION_OFFSET, ANCHOR_LEN);
+        assert_eq!(TipDistributionAccount::V0_SPACE, 8 + ANCHOR_LEN);
+    }
+
+    #[test]
+    fn reads_anchor_account_without_merkle_root() {
+        let anchor = AnchorTipDistributionAccount::new(None);
+        let tip_distribution_account = view(&anchor.encode());
+
+        assert!(!tip_distribution_account.has_merkle_root());
+        assert_fields(&tip_distribution_account, &anchor);
+        assert_eq!(anchor_bytes(&tip_distribution_account), anchor.encode());
+    }
+
+    #[test]
+    fn reads_anchor_account_with_merkle_root() {
+        let anchor = AnchorTipDistributionAccount::new(Some(merkle_root()));
+        let tip_distribution_account = view(&anchor.encode());
+
+        assert!(tip_distribution_account.has_merkle_root());
+        assert_fields(&tip_distribution_account, &anchor);
+        assert_eq!(anchor_bytes(&tip_distribution_account), anchor.encode());
+    }
+
+    #[test]
+    fn initialize_matches_anchor_encoding() {
+        let anchor = AnchorTipDistributionAccount::new(None);
+        let mut tip_distribution_account: TipDistributionAccount = unsafe { core::mem::zeroed() };
+        tip_distribution_account
+            .initialize(
+                anchor.validator_vote_account,
+                anchor.epoch_created_at,
+                anchor.validator_commission_bps,
+                anchor.merkle_root_upload_authority,
+                anchor.bump,
+                anchor.expires_at - anchor.epoch_created_at,
+                [4; 32],
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(anchor_bytes(&tip_distribution_account), anchor.encode());
+    }
+
+    #[test]
+    fn set_merkle_root_shifts_the_tail_when_the_tag_flips() {
+        let mut tip_distribution_account = view(&AnchorTipDistributionAccount::new(None).encode());
+        tip_distribution_account.set_merkle_root(&merkle_root());
+
+        let anchor = AnchorTipDistributionAccount::new(Some(merkle_root()));
+        assert_fields(&tip_distribution_account, &anchor);
+        assert_eq!(anchor_bytes(&tip_distribution_account), anchor.encode());
+    }
+
+    #[test]
+    fn set_merkle_root_overwrites_an_existing_root_in_place() {
+        let mut tip_distribution_account =
+            view(&AnchorTipDistributionAccount::new(Some(merkle_root())).encode());
+        let new_merkle_root = MerkleRoot {
+            root: [5; 32],
+            max_total_claim: 2_000,
+            max_num_nodes: 20,
+            total_funds_claimed: 0,
+            num_nodes_claimed: 0,
+        };
+        tip_distribution_account.set_merkle_root(&new_merkle_root);
+
+        let anchor = AnchorTipDistributionAccount::new(Some(new_merkle_root));
+        assert_fields(&tip_distribution_account, &anchor);
+        assert_eq!(anchor_bytes(&tip_distribution_account), anchor.encode());
+    }
+
+    #[test]
+    fn token_vaults_are_counted_after_the_anchor_layout() {
+        let mut tip_distribution_account = view(&AnchorTipDistributionAccount::new(None).encode());
+        assert_eq!(tip_distribution_account.num_token_vaults(), 0);
+
+        tip_distribution_account.add_token_vault().unwrap();
+        tip_distribution_account.add_token_vault().unwrap();
+        tip_distribution_account.remove_token_vault().unwrap();
+        assert_eq!(tip_distribution_account.num_token_vaults(), 1);
+
+        let bytes = unsafe {
+            core::slice::from_raw_parts(
+                &tip_distribution_account as *const TipDistributionAccount as *const u8,
+                core::mem::size_of::<TipDistributionAccount>(),
+            )
+        };
+        let offset = TipDistributionAccount::NUM_TOKEN_VAULTS_OFFSET;
+        assert_eq!(bytes[offset..offset + 2], 1u16.to_le_bytes());
+        // The Anchor fields are untouched.
+        assert_eq!(
+            anchor_bytes(&tip_distribution_account),
+            AnchorTipDistributionAccount::new(None).encode()
+        );
+
+        tip_distribution_account.remove_token_vault().unwrap();
+        assert!(tip_distribution_account.remove_token_vault().is_err());
+    }
+}
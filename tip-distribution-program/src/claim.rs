@@ -1,6 +1,10 @@
-use jito_tip_core::{create_account, loader::load_signer, transmutable::Transmutable};
+use jito_tip_core::{
+    create_account, loader::load_signer, program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
+};
 use jito_tip_distribution_core::{
-    claim_status::ClaimStatus, config::Config, load_mut_unchecked,
+    claim_delegate::ClaimDelegate, claim_status::ClaimStatus, config::Config,
+    distribution_account::DistributionAccount, merkle_proof,
     tip_distribution_account::TipDistributionAccount,
 };
 use jito_tip_distribution_sdk::error::TipDistributionError;
@@ -13,56 +17,58 @@ use pinocchio::{
 };
 use pinocchio_log::log;
 
-use crate::verify;
-
 /// Claims tokens from the [TipDistributionAccount].
-#[allow(dead_code)]
+///
+/// The claim must be signed by the TDA's `merkle_root_upload_authority`, by the claimant, or by
+/// a delegate the claimant registered with `SetClaimDelegate`. A delegate must also pass the
+/// claimant's [ClaimDelegate] account after the regular accounts. The payer can be any account
+/// and is refunded the [ClaimStatus] rent when it's closed. A TDA still in the version 0 layout
+/// is migrated first, the payer funding its extra rent.
 pub fn process_claim(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     bump: u8,
     amount: u64,
-    proof: Vec<[u8; 32]>,
+    proof: &[[u8; 32]],
 ) -> Result<(), ProgramError> {
-    let [config_info, tip_distribution_account_info, merkle_root_upload_authority_info, claim_status_info, claimant_info, payer_info, system_program_info, validator_vote_account_info] =
+    let [config_info, tip_distribution_account_info, authority_info, claim_status_info, claimant_info, payer_info, system_program_info, validator_vote_account_info, remaining_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let current_epoch = Clock::get()?.epoch;
-
     unsafe {
-        Config::load(program_id, config_info, false)?;
+        Config::load(program_id, config_info, (), false)?;
     }
 
+    load_signer(payer_info, true)?;
+
     let tip_distribution_account = unsafe {
-        TipDistributionAccount::load(
+        TipDistributionAccount::load_mut_migrated(
             program_id,
             tip_distribution_account_info,
-            validator_vote_account_info.key(),
-            current_epoch,
-            false,
-        )?;
-        load_mut_unchecked::<TipDistributionAccount>(
-            &mut tip_distribution_account_info.borrow_mut_data_unchecked()[8..],
+            (),
+            payer_info,
         )?
     };
 
     if tip_distribution_account
-        .merkle_root_upload_authority
-        .ne(merkle_root_upload_authority_info.key())
+        .validator_vote_account
+        .ne(validator_vote_account_info.key())
     {
-        return Err(TipDistributionError::Unauthorized.into());
+        return Err(ProgramError::InvalidAccountData);
     }
 
-    load_signer(merkle_root_upload_authority_info, false)?;
-    load_signer(payer_info, true)?;
+    authorize_claim(
+        program_id,
+        &tip_distribution_account.merkle_root_upload_authority,
+        authority_info,
+        claimant_info,
+        remaining_accounts,
+    )?;
 
     let rent = Rent::get()?;
-    let space = 8usize
-        .checked_add(ClaimStatus::LEN)
-        .ok_or(TipDistributionError::ArithmeticError)?;
+    let space = ClaimStatus::SPACE;
 
     let (claim_status_pubkey, claim_status_bump) = ClaimStatus::find_program_address(
         program_id,
@@ -79,7 +85,7 @@ pub fn process_claim(
 
     // Create the seeds for the PDA
     let claim_status_seeds = [
-        Seed::from(b"CLAIM_STATUS".as_slice()),
+        Seed::from(ClaimStatus::SEED),
         Seed::from(claimant_info.key().as_ref()),
         Seed::from(tip_distribution_account_info.key().as_ref()),
         Seed::from(claim_status_bump_slice.as_slice()),
@@ -98,16 +104,12 @@ pub fn process_claim(
         &signers,
     )?;
 
-    let claim_status = unsafe {
-        let claim_status_info_data = claim_status_info.borrow_mut_data_unchecked();
-        claim_status_info_data[0..8].copy_from_slice(ClaimStatus::DISCRIMINATOR);
-        load_mut_unchecked::<ClaimStatus>(&mut claim_status_info_data[8..])?
-    };
+    let claim_status = unsafe { ClaimStatus::init_versioned(program_id, claim_status_info)? };
 
     claim_status.bump = bump;
 
     let clock = Clock::get()?;
-    if clock.epoch > tip_distribution_account.expires_at {
+    if clock.epoch > tip_distribution_account.expires_at() {
         return Err(TipDistributionError::ExpiredTipDistributionAccount.into());
     }
 
@@ -117,20 +119,15 @@ pub fn process_claim(
     }
 
     // let tip_distribution_info = tip_distribution_account.to_account_info();
-    let tip_distribution_epoch_expires_at = tip_distribution_account.expires_at;
-    let merkle_root = tip_distribution_account
-        .merkle_root
-        .as_mut()
+    let tip_distribution_epoch_expires_at = tip_distribution_account.expires_at();
+    let mut merkle_root = tip_distribution_account
+        .merkle_root()
         .ok_or(TipDistributionError::RootNotUploaded)?;
 
     // Verify the merkle proof.
-    let node = &solana_program::hash::hashv(&[
-        &[0u8],
-        &solana_program::hash::hashv(&[claimant_info.key().as_slice(), &amount.to_le_bytes()])
-            .to_bytes(),
-    ]);
+    let node = merkle_proof::leaf(claimant_info.key(), amount);
 
-    if !verify(proof, merkle_root.root, node.to_bytes()) {
+    if !merkle_proof::verify(proof, &merkle_root.root, node) {
         return Err(TipDistributionError::InvalidProof.into());
     }
 
@@ -160,7 +157,45 @@ pub fn process_claim(
         return Err(TipDistributionError::ExceedsMaxNumNodes.into());
     }
 
+    // The claimants left must still be able to claim their share.
+    if !TipDistributionAccount::is_funded(tip_distribution_account_info, &merkle_root, &rent)? {
+        return Err(TipDistributionError::InsufficientFundsForRemainingClaims.into());
+    }
+
+    tip_distribution_account.set_merkle_root(&merkle_root);
     tip_distribution_account.validate()?;
 
     Ok(())
 }
+
+/// Checks that `authority_info` signed and may claim on behalf of `claimant_info`: it must be the
+/// distribution account's `merkle_root_upload_authority`, the claimant, or the delegate registered
+/// in the [ClaimDelegate] account passed first in `remaining_accounts`.
+pub fn authorize_claim(
+    program_id: &Pubkey,
+    merkle_root_upload_authority: &Pubkey,
+    authority_info: &AccountInfo,
+    claimant_info: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    load_signer(authority_info, false)?;
+
+    let is_authorized = if authority_info.key().eq(merkle_root_upload_authority)
+        || authority_info.key().eq(claimant_info.key())
+    {
+        true
+    } else if let [claim_delegate_info, ..] = remaining_accounts {
+        let claim_delegate =
+            unsafe { ClaimDelegate::load(program_id, claim_delegate_info, (), false)? };
+        claim_delegate.claimant.eq(claimant_info.key())
+            && claim_delegate.delegate.eq(authority_info.key())
+    } else {
+        false
+    };
+
+    if !is_authorized {
+        return Err(TipDistributionError::Unauthorized.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use jito_tip_core::{
+    create_account, loader::load_signer, program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
+};
+use jito_tip_distribution_core::{
+    claim_status::ClaimStatus, config::Config, distribution_account::DistributionAccount,
+    merkle_proof, priority_fee_distribution_account::PriorityFeeDistributionAccount,
+};
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+};
+use pinocchio_log::log;
+
+use crate::claim::authorize_claim;
+
+/// Claims priority fees from the [PriorityFeeDistributionAccount].
+///
+/// Authorization is the same as for `Claim`, and the [ClaimStatus] is derived from the
+/// [PriorityFeeDistributionAccount], so it's closed with `CloseClaimStatus` as well.
+pub fn process_claim_priority_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    bump: u8,
+    amount: u64,
+    proof: &[[u8; 32]],
+) -> Result<(), ProgramError> {
+    let [config_info, priority_fee_distribution_account_info, authority_info, claim_status_info, claimant_info, payer_info, system_program_info, validator_vote_account_info, remaining_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    unsafe {
+        Config::load(program_id, config_info, (), false)?;
+    }
+
+    let priority_fee_distribution_account = unsafe {
+        PriorityFeeDistributionAccount::load_mut(
+            program_id,
+            priority_fee_distribution_account_info,
+            (),
+        )?
+    };
+
+    if priority_fee_distribution_account
+        .validator_vote_account
+        .ne(validator_vote_account_info.key())
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    authorize_claim(
+        program_id,
+        &priority_fee_distribution_account.merkle_root_upload_authority,
+        authority_info,
+        claimant_info,
+        remaining_accounts,
+    )?;
+    load_signer(payer_info, true)?;
+
+    let rent = Rent::get()?;
+    let space = ClaimStatus::SPACE;
+
+    let (claim_status_pubkey, claim_status_bump) = ClaimStatus::find_program_address(
+        program_id,
+        claimant_info.key(),
+        priority_fee_distribution_account_info.key(),
+    );
+
+    if claim_status_pubkey.ne(claim_status_info.key()) {
+        log!("ClaimStatus account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let claim_status_bump_slice = [claim_status_bump];
+
+    // Create the seeds for the PDA
+    let claim_status_seeds = [
+        Seed::from(ClaimStatus::SEED),
+        Seed::from(claimant_info.key().as_ref()),
+        Seed::from(priority_fee_distribution_account_info.key().as_ref()),
+        Seed::from(claim_status_bump_slice.as_slice()),
+    ];
+
+    let signers = [Signer::from(claim_status_seeds.as_slice())];
+
+    log!(
+        "Initializing ClaimStatus at address {}",
+        claim_status_info.key()
+    );
+    create_account(
+        payer_info,
+        claim_status_info,
+        system_program_info,
+        program_id,
+        &rent,
+        space as u64,
+        &signers,
+    )?;
+
+    let claim_status = unsafe { ClaimStatus::init_versioned(program_id, claim_status_info)? };
+
+    claim_status.bump = bump;
+
+    let clock = Clock::get()?;
+    if clock.epoch > priority_fee_distribution_account.expires_at {
+        return Err(TipDistributionError::ExpiredTipDistributionAccount.into());
+    }
+
+    let mut merkle_root = priority_fee_distribution_account
+        .merkle_root()
+        .ok_or(TipDistributionError::RootNotUploaded)?;
+
+    // Verify the merkle proof.
+    let node = merkle_proof::leaf(claimant_info.key(), amount);
+
+    if !merkle_proof::verify(proof, &merkle_root.root, node) {
+        return Err(TipDistributionError::InvalidProof.into());
+    }
+
+    PriorityFeeDistributionAccount::claim(
+        priority_fee_distribution_account_info,
+        claimant_info,
+        amount,
+    )?;
+
+    // Mark it claimed.
+    claim_status.amount = amount;
+    claim_status.is_claimed = true;
+    claim_status.slot_claimed_at = clock.slot;
+    claim_status.claimant = *claimant_info.key();
+    claim_status.claim_status_payer = *payer_info.key();
+    claim_status.expires_at = priority_fee_distribution_account.expires_at;
+
+    merkle_root.total_funds_claimed = merkle_root
+        .total_funds_claimed
+        .checked_add(amount)
+        .ok_or(TipDistributionError::ArithmeticError)?;
+    if merkle_root.total_funds_claimed > merkle_root.max_total_claim {
+        return Err(TipDistributionError::ExceedsMaxClaim.into());
+    }
+
+    merkle_root.num_nodes_claimed = merkle_root
+        .num_nodes_claimed
+        .checked_add(1)
+        .ok_or(TipDistributionError::ArithmeticError)?;
+    if merkle_root.num_nodes_claimed > merkle_root.max_num_nodes {
+        return Err(TipDistributionError::ExceedsMaxNumNodes.into());
+    }
+
+    // The claimants left must still be able to claim their share.
+    if !PriorityFeeDistributionAccount::is_funded(
+        priority_fee_distribution_account_info,
+        &merkle_root,
+        &rent,
+    )? {
+        return Err(TipDistributionError::InsufficientFundsForRemainingClaims.into());
+    }
+
+    priority_fee_distribution_account.set_merkle_root(&merkle_root);
+    priority_fee_distribution_account.validate()?;
+
+    Ok(())
+}
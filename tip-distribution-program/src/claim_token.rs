@@ -0,0 +1,217 @@
+use jito_tip_core::{
+    create_account, loader::load_signer, program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
+};
+use jito_tip_distribution_core::{
+    claim_status::ClaimStatus, config::Config, merkle_proof,
+    tip_distribution_account::TipDistributionAccount, token_merkle_root::TokenMerkleRoot,
+};
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+};
+use pinocchio_log::log;
+use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+
+use crate::claim::authorize_claim;
+
+/// Claims SPL tokens from the [TipDistributionAccount]'s token vault against the
+/// [TokenMerkleRoot] of the vault's mint.
+///
+/// Authorization is the same as for `Claim`. The [ClaimStatus] is derived from the
+/// [TokenMerkleRoot] instead of the [TipDistributionAccount], so lamport and token claims of the
+/// same claimant don't collide.
+pub fn process_claim_token(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    bump: u8,
+    amount: u64,
+    proof: &[[u8; 32]],
+) -> Result<(), ProgramError> {
+    let [config_info, tip_distribution_account_info, token_merkle_root_info, authority_info, claim_status_info, claimant_info, claimant_token_account_info, token_vault_info, payer_info, system_program_info, token_program_info, validator_vote_account_info, remaining_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    unsafe {
+        Config::load(program_id, config_info, (), false)?;
+    }
+
+    let tip_distribution_account = unsafe {
+        TipDistributionAccount::load(program_id, tip_distribution_account_info, (), false)?
+    };
+
+    if tip_distribution_account
+        .validator_vote_account
+        .ne(validator_vote_account_info.key())
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let token_merkle_root =
+        unsafe { TokenMerkleRoot::load_mut(program_id, token_merkle_root_info, ())? };
+
+    if token_merkle_root
+        .tip_distribution_account
+        .ne(tip_distribution_account_info.key())
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    authorize_claim(
+        program_id,
+        &tip_distribution_account.merkle_root_upload_authority,
+        authority_info,
+        claimant_info,
+        remaining_accounts,
+    )?;
+    load_signer(payer_info, true)?;
+
+    if token_program_info.key().ne(&pinocchio_token::ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (token_vault_pubkey, _) = TipDistributionAccount::find_token_vault_address(
+        program_id,
+        tip_distribution_account_info.key(),
+        &token_merkle_root.mint,
+    );
+    if token_vault_pubkey.ne(token_vault_info.key()) {
+        log!("Token vault is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    {
+        let token_vault = TokenAccount::from_account_info(token_vault_info)?;
+        if token_vault.owner().ne(tip_distribution_account_info.key())
+            || token_vault.mint().ne(&token_merkle_root.mint)
+        {
+            log!("Token vault is not owned by the TipDistributionAccount");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let claimant_token_account = TokenAccount::from_account_info(claimant_token_account_info)?;
+        if claimant_token_account.owner().ne(claimant_info.key()) {
+            log!("Claimant token account is not owned by the claimant");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    let clock = Clock::get()?;
+    if clock.epoch > tip_distribution_account.expires_at() {
+        return Err(TipDistributionError::ExpiredTipDistributionAccount.into());
+    }
+
+    let rent = Rent::get()?;
+    let space = ClaimStatus::SPACE;
+
+    let (claim_status_pubkey, claim_status_bump) = ClaimStatus::find_program_address(
+        program_id,
+        claimant_info.key(),
+        token_merkle_root_info.key(),
+    );
+
+    if claim_status_pubkey.ne(claim_status_info.key()) {
+        log!("ClaimStatus account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let claim_status_bump_slice = [claim_status_bump];
+
+    // Create the seeds for the PDA
+    let claim_status_seeds = [
+        Seed::from(ClaimStatus::SEED),
+        Seed::from(claimant_info.key().as_ref()),
+        Seed::from(token_merkle_root_info.key().as_ref()),
+        Seed::from(claim_status_bump_slice.as_slice()),
+    ];
+
+    let signers = [Signer::from(claim_status_seeds.as_slice())];
+
+    log!(
+        "Initializing ClaimStatus at address {}",
+        claim_status_info.key()
+    );
+    create_account(
+        payer_info,
+        claim_status_info,
+        system_program_info,
+        program_id,
+        &rent,
+        space as u64,
+        &signers,
+    )?;
+
+    let claim_status = unsafe { ClaimStatus::init_versioned(program_id, claim_status_info)? };
+
+    claim_status.bump = bump;
+
+    let mut merkle_root = token_merkle_root.merkle_root();
+
+    // Verify the merkle proof.
+    let node = merkle_proof::leaf(claimant_info.key(), amount);
+
+    if !merkle_proof::verify(proof, &merkle_root.root, node) {
+        return Err(TipDistributionError::InvalidProof.into());
+    }
+
+    // Transfer the tokens out of the vault, signed by the TipDistributionAccount.
+    let epoch_created_at_bytes = tip_distribution_account.epoch_created_at().to_le_bytes();
+    let tip_distribution_account_bump_slice = [tip_distribution_account.bump()];
+    let tip_distribution_account_seeds = [
+        Seed::from(TipDistributionAccount::SEED),
+        Seed::from(tip_distribution_account.validator_vote_account.as_ref()),
+        Seed::from(epoch_created_at_bytes.as_slice()),
+        Seed::from(tip_distribution_account_bump_slice.as_slice()),
+    ];
+
+    Transfer {
+        from: token_vault_info,
+        to: claimant_token_account_info,
+        authority: tip_distribution_account_info,
+        amount,
+    }
+    .invoke_signed(&[Signer::from(tip_distribution_account_seeds.as_slice())])?;
+
+    // Mark it claimed.
+    claim_status.amount = amount;
+    claim_status.is_claimed = true;
+    claim_status.slot_claimed_at = clock.slot;
+    claim_status.claimant = *claimant_info.key();
+    claim_status.claim_status_payer = *payer_info.key();
+    claim_status.expires_at = tip_distribution_account.expires_at();
+
+    merkle_root.total_funds_claimed = merkle_root
+        .total_funds_claimed
+        .checked_add(amount)
+        .ok_or(TipDistributionError::ArithmeticError)?;
+    if merkle_root.total_funds_claimed > merkle_root.max_total_claim {
+        return Err(TipDistributionError::ExceedsMaxClaim.into());
+    }
+
+    merkle_root.num_nodes_claimed = merkle_root
+        .num_nodes_claimed
+        .checked_add(1)
+        .ok_or(TipDistributionError::ArithmeticError)?;
+    if merkle_root.num_nodes_claimed > merkle_root.max_num_nodes {
+        return Err(TipDistributionError::ExceedsMaxNumNodes.into());
+    }
+
+    // The claimants left must still be able to claim their share.
+    let remaining_claimable = merkle_root
+        .max_total_claim
+        .checked_sub(merkle_root.total_funds_claimed)
+        .ok_or(TipDistributionError::ArithmeticError)?;
+    if TokenAccount::from_account_info(token_vault_info)?.amount() < remaining_claimable {
+        return Err(TipDistributionError::InsufficientFundsForRemainingClaims.into());
+    }
+
+    token_merkle_root.set_merkle_root(&merkle_root);
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use jito_tip_core::{close_program_account, loader::load_signer, program_account::ProgramAccount};
+use jito_tip_distribution_core::claim_delegate::ClaimDelegate;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+/// Revokes the claimant's delegate and returns the [ClaimDelegate] rent to the claimant.
+pub fn process_close_claim_delegate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let [claim_delegate_info, claimant_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(claimant_info, true)?;
+
+    let claim_delegate = unsafe { ClaimDelegate::load(program_id, claim_delegate_info, (), true)? };
+
+    if claim_delegate.claimant.ne(claimant_info.key()) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    unsafe {
+        close_program_account(program_id, claim_delegate_info, claimant_info)?;
+    }
+
+    Ok(())
+}
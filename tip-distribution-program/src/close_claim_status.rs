@@ -1,5 +1,5 @@
-use jito_tip_core::close_program_account;
-use jito_tip_distribution_core::{claim_status::ClaimStatus, config::Config, load_unchecked};
+use jito_tip_core::{close_program_account, program_account::ProgramAccount};
+use jito_tip_distribution_core::{claim_status::ClaimStatus, config::Config};
 use jito_tip_distribution_sdk::error::TipDistributionError;
 use pinocchio::{
     account_info::AccountInfo,
@@ -21,21 +21,22 @@ pub fn process_close_claim_status(
     };
 
     unsafe {
-        Config::load(program_id, config_info, false)?;
+        Config::load(program_id, config_info, (), false)?;
     }
 
     let claim_status = unsafe {
         ClaimStatus::load(
             program_id,
             claim_status_info,
-            claimant_info.key(),
             tip_distribution_account_info.key(),
-            false,
-        )?;
-
-        load_unchecked::<ClaimStatus>(&claim_status_info.borrow_data_unchecked()[8..])?
+            true,
+        )?
     };
 
+    if claim_status.claimant.ne(claimant_info.key()) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
     if claim_status
         .claim_status_payer
         .ne(claim_status_payer_info.key())
@@ -0,0 +1,77 @@
+use jito_tip_core::{close_program_account, program_account::ProgramAccount};
+use jito_tip_distribution_core::{claim_status::ClaimStatus, config::Config};
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_log::log;
+
+/// Batch version of `CloseClaimStatus`: closes every expired [ClaimStatus] passed as a
+/// `(claim_status, claim_status_payer)` pair after the config, returning the rent to each payer.
+///
+/// Anyone can invoke this. Pairs that can't be closed, because the account isn't a live
+/// [ClaimStatus], the payer doesn't match or the claim hasn't expired yet, are skipped so that one
+/// stale entry doesn't fail the whole batch.
+pub fn process_close_claim_statuses(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let [config_info, claim_status_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    unsafe {
+        Config::load(program_id, config_info, (), false)?;
+    }
+
+    let claim_status_accounts = claim_status_accounts.chunks_exact(2);
+    if !claim_status_accounts.remainder().is_empty() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let epoch = Clock::get()?.epoch;
+    let mut num_closed: u64 = 0;
+
+    for claim_status_pair in claim_status_accounts {
+        let [claim_status_info, claim_status_payer_info] = claim_status_pair else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // The ClaimStatus seeds include the account the claim was made against, which may already
+        // be closed, so the address can't be checked here. Only this program can have written the
+        // discriminator, and the rent only ever goes back to the recorded payer.
+        let Ok(claim_status) =
+            (unsafe { ClaimStatus::load_without_pda_check(program_id, claim_status_info, true) })
+        else {
+            continue;
+        };
+
+        if claim_status
+            .claim_status_payer
+            .ne(claim_status_payer_info.key())
+            || !claim_status_payer_info.is_writable()
+        {
+            continue;
+        }
+
+        // can only close after claim_status has expired to prevent draining.
+        if epoch <= claim_status.expires_at {
+            continue;
+        }
+
+        unsafe {
+            close_program_account(program_id, claim_status_info, claim_status_payer_info)?;
+        }
+
+        num_closed = num_closed
+            .checked_add(1)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+    }
+
+    log!("Closed {} ClaimStatus accounts", num_closed);
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use jito_tip_core::{close_program_account, loader::load_signer, program_account::ProgramAccount};
+use jito_tip_distribution_core::{
+    config::Config, distribution_account::DistributionAccount,
+    priority_fee_distribution_account::PriorityFeeDistributionAccount,
+};
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+};
+
+/// Anyone can invoke this only after the [PriorityFeeDistributionAccount] has expired.
+/// This instruction will send any unclaimed funds to the designated `expired_funds_account`
+/// before closing and returning the rent exempt funds to the validator.
+pub fn process_close_priority_fee_distribution_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let [config_info, expired_funds_account_info, priority_fee_distribution_account_info, validator_vote_account_info, signer] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let config = unsafe { Config::load(program_id, config_info, (), false)? };
+
+    let priority_fee_distribution_account = unsafe {
+        PriorityFeeDistributionAccount::load(
+            program_id,
+            priority_fee_distribution_account_info,
+            (),
+            true,
+        )?
+    };
+
+    load_signer(signer, false)?;
+
+    if priority_fee_distribution_account
+        .validator_vote_account
+        .ne(validator_vote_account_info.key())
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if config
+        .expired_funds_account
+        .ne(expired_funds_account_info.key())
+    {
+        return Err(TipDistributionError::Unauthorized.into());
+    }
+
+    if Clock::get()?.epoch <= priority_fee_distribution_account.expires_at {
+        return Err(TipDistributionError::PrematureCloseTipDistributionAccount.into());
+    }
+
+    PriorityFeeDistributionAccount::claim_expired(
+        priority_fee_distribution_account_info,
+        expired_funds_account_info,
+    )?;
+
+    unsafe {
+        close_program_account(
+            program_id,
+            priority_fee_distribution_account_info,
+            validator_vote_account_info,
+        )?;
+    }
+
+    Ok(())
+}
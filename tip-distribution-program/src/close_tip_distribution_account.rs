@@ -1,50 +1,66 @@
-use jito_tip_core::{close_program_account, loader::load_signer};
+use jito_tip_core::{
+    close_program_account, loader::load_signer, program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
+};
 use jito_tip_distribution_core::{
-    config::Config, load_mut_unchecked, tip_distribution_account::TipDistributionAccount,
+    config::Config, distribution_account::DistributionAccount,
+    tip_distribution_account::TipDistributionAccount, token_merkle_root::TokenMerkleRoot,
 };
 use jito_tip_distribution_sdk::error::TipDistributionError;
 use pinocchio::{
     account_info::AccountInfo,
+    instruction::{Seed, Signer},
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvars::{clock::Clock, Sysvar},
 };
+use pinocchio_token::{
+    instructions::{CloseAccount, Transfer},
+    state::TokenAccount,
+};
 
 /// Anyone can invoke this only after the [TipDistributionAccount] has expired.
 /// This instruction will send any unclaimed funds to the designated `expired_funds_account`
-/// before closing and returning the rent exempt funds to the validator.
+/// before closing and returning the rent exempt funds to the `rent_refund_destination` the
+/// validator chose at initialization.
+///
+/// Token vaults are swept by passing the token program after the regular accounts, followed by a
+/// `(token_vault, expired_funds_token_account, token_merkle_root)` triple per vault, the
+/// [TokenMerkleRoot] being passed at its address even if no root was uploaded. The vault's
+/// balance is transferred to the `expired_funds_account`'s token account and the vault and its
+/// [TokenMerkleRoot] (if any) are closed to the `expired_funds_account`. The close fails while any
+/// token vault of the account hasn't been swept.
+///
+/// If the validator opted to roll unclaimed funds over, a later [TipDistributionAccount] of the
+/// same validator that has no merkle root yet must be passed right after the signer, and the
+/// unclaimed lamports go there instead. Once the roll-over deadline has passed without such an
+/// account they go to the `expired_funds_account` as usual. Tokens never roll over.
 pub fn process_close_tip_distribution_account(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> Result<(), ProgramError> {
-    let [config_info, expired_funds_account_info, tip_distribution_account_info, validator_vote_account_info, signer] =
+    let [config_info, expired_funds_account_info, tip_distribution_account_info, rent_refund_destination_info, signer, remaining_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let current_epoch = Clock::get()?.epoch;
+    let config = unsafe { Config::load(program_id, config_info, (), false)? };
 
-    let config = unsafe {
-        Config::load(program_id, config_info, false)?;
-        load_mut_unchecked::<Config>(&mut config_info.borrow_mut_data_unchecked()[8..])?
-    };
-
-    let tip_distribution_account = unsafe {
-        TipDistributionAccount::load(
-            program_id,
-            tip_distribution_account_info,
-            validator_vote_account_info.key(),
-            current_epoch,
-            false,
-        )?;
-        load_mut_unchecked::<TipDistributionAccount>(
-            &mut tip_distribution_account_info.borrow_mut_data_unchecked()[8..],
-        )?
-    };
+    // A copy, so an account still in the version 0 layout can be closed without migrating it
+    // first. Nothing written to it has to persist since the account is closed below.
+    let mut tip_distribution_account =
+        unsafe { TipDistributionAccount::read(program_id, tip_distribution_account_info, true)? };
 
     load_signer(signer, false)?;
 
+    if tip_distribution_account
+        .rent_refund_destination
+        .ne(rent_refund_destination_info.key())
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
     if config
         .expired_funds_account
         .ne(expired_funds_account_info.key())
@@ -52,19 +68,179 @@ pub fn process_close_tip_distribution_account(
         return Err(TipDistributionError::Unauthorized.into());
     }
 
-    if Clock::get()?.epoch <= tip_distribution_account.expires_at {
+    let epoch = Clock::get()?.epoch;
+    if epoch <= tip_distribution_account.expires_at() {
         return Err(TipDistributionError::PrematureCloseTipDistributionAccount.into());
     }
 
+    // The roll-over target, if any, is the first remaining account that isn't the token program.
+    let (roll_over_info, token_accounts) = match remaining_accounts {
+        [roll_over_info, token_accounts @ ..] if roll_over_info.key().ne(&pinocchio_token::ID) => {
+            (Some(roll_over_info), token_accounts)
+        }
+        token_accounts => (None, token_accounts),
+    };
+
+    let rolls_over = tip_distribution_account.roll_over_unclaimed_funds()
+        && epoch <= tip_distribution_account.roll_over_deadline(config.num_epochs_valid)?;
+    let unclaimed_funds_destination_info = match (rolls_over, roll_over_info) {
+        (true, Some(roll_over_info)) => {
+            validate_roll_over_target(program_id, &tip_distribution_account, roll_over_info)?;
+            roll_over_info
+        }
+        (false, None) => expired_funds_account_info,
+        _ => return Err(TipDistributionError::InvalidRolloverTarget.into()),
+    };
+
+    if let [token_program_info, token_vaults @ ..] = token_accounts {
+        if token_program_info.key().ne(&pinocchio_token::ID) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        // Copied so the seeds don't borrow the account while its token vaults are counted down.
+        let validator_vote_account = tip_distribution_account.validator_vote_account;
+        let epoch_created_at_bytes = tip_distribution_account.epoch_created_at().to_le_bytes();
+        let tip_distribution_account_bump_slice = [tip_distribution_account.bump()];
+        let tip_distribution_account_seeds = [
+            Seed::from(TipDistributionAccount::SEED),
+            Seed::from(validator_vote_account.as_ref()),
+            Seed::from(epoch_created_at_bytes.as_slice()),
+            Seed::from(tip_distribution_account_bump_slice.as_slice()),
+        ];
+        let signers = [Signer::from(tip_distribution_account_seeds.as_slice())];
+
+        let token_vaults = token_vaults.chunks_exact(3);
+        if !token_vaults.remainder().is_empty() {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        for token_vault_accounts in token_vaults {
+            let [token_vault_info, expired_funds_token_account_info, token_merkle_root_info] =
+                token_vault_accounts
+            else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            let (mint, amount) = {
+                let token_vault = TokenAccount::from_account_info(token_vault_info)?;
+                if token_vault.owner().ne(tip_distribution_account_info.key()) {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+
+                let expired_funds_token_account =
+                    TokenAccount::from_account_info(expired_funds_token_account_info)?;
+                if expired_funds_token_account
+                    .owner()
+                    .ne(expired_funds_account_info.key())
+                    || expired_funds_token_account.mint().ne(token_vault.mint())
+                {
+                    return Err(TipDistributionError::Unauthorized.into());
+                }
+
+                (*token_vault.mint(), token_vault.amount())
+            };
+
+            // Only the vault the program created is counted, and its TokenMerkleRoot must not be
+            // left behind.
+            let (token_vault_pubkey, _) = TipDistributionAccount::find_token_vault_address(
+                program_id,
+                tip_distribution_account_info.key(),
+                &mint,
+            );
+            let (token_merkle_root_pubkey, _) = TokenMerkleRoot::find_program_address(
+                program_id,
+                tip_distribution_account_info.key(),
+                &mint,
+            );
+            if token_vault_pubkey.ne(token_vault_info.key())
+                || token_merkle_root_pubkey.ne(token_merkle_root_info.key())
+            {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            if amount > 0 {
+                Transfer {
+                    from: token_vault_info,
+                    to: expired_funds_token_account_info,
+                    authority: tip_distribution_account_info,
+                    amount,
+                }
+                .invoke_signed(&signers)?;
+            }
+
+            CloseAccount {
+                account: token_vault_info,
+                destination: expired_funds_account_info,
+                authority: tip_distribution_account_info,
+            }
+            .invoke_signed(&signers)?;
+            tip_distribution_account.remove_token_vault()?;
+
+            if !token_merkle_root_info.data_is_empty() {
+                let token_merkle_root =
+                    unsafe { TokenMerkleRoot::load(program_id, token_merkle_root_info, (), true)? };
+                if token_merkle_root
+                    .tip_distribution_account
+                    .ne(tip_distribution_account_info.key())
+                    || token_merkle_root.mint.ne(&mint)
+                {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+
+                unsafe {
+                    close_program_account(
+                        program_id,
+                        token_merkle_root_info,
+                        expired_funds_account_info,
+                    )?;
+                }
+            }
+        }
+    }
+
+    if tip_distribution_account.num_token_vaults() > 0 {
+        return Err(TipDistributionError::TokenVaultsNotSwept.into());
+    }
+
     let _expired_amount = TipDistributionAccount::claim_expired(
         tip_distribution_account_info,
-        expired_funds_account_info,
+        unclaimed_funds_destination_info,
     )?;
 
     tip_distribution_account.validate()?;
 
     unsafe {
-        close_program_account(program_id, tip_distribution_account_info, signer)?;
+        close_program_account(
+            program_id,
+            tip_distribution_account_info,
+            rent_refund_destination_info,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Checks that `roll_over_info` is a [TipDistributionAccount] of the same validator as
+/// `tip_distribution_account`, created for a later epoch and still waiting for its merkle root so
+/// the rolled over funds are included in it.
+fn validate_roll_over_target(
+    program_id: &Pubkey,
+    tip_distribution_account: &TipDistributionAccount,
+    roll_over_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    // Loading derives the PDA from the stored vote account and epoch.
+    let roll_over_account = unsafe {
+        TipDistributionAccount::load(program_id, roll_over_info, (), true)
+            .map_err(|_| TipDistributionError::InvalidRolloverTarget)?
+    };
+
+    if roll_over_account
+        .validator_vote_account
+        .ne(&tip_distribution_account.validator_vote_account)
+        || roll_over_account.epoch_created_at() <= tip_distribution_account.epoch_created_at()
+        || roll_over_account.has_merkle_root()
+    {
+        return Err(TipDistributionError::InvalidRolloverTarget.into());
     }
 
     Ok(())
@@ -0,0 +1,117 @@
+use jito_tip_core::{
+    close_program_account, program_account::ProgramAccount, versioned_account::VersionedAccount,
+};
+use jito_tip_distribution_core::{
+    config::Config, distribution_account::DistributionAccount,
+    tip_distribution_account::TipDistributionAccount,
+};
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_log::log;
+
+/// Batch version of `CloseTipDistributionAccount`: closes every expired [TipDistributionAccount]
+/// passed as a `(tip_distribution_account, rent_refund_destination)` pair after the config and the
+/// `expired_funds_account`. Unclaimed funds go to the `expired_funds_account` and the rent to the
+/// TDA's `rent_refund_destination`.
+///
+/// Anyone can invoke this. Pairs that can't be closed, because the account isn't a valid
+/// [TipDistributionAccount], the destination doesn't match or it hasn't expired yet, are skipped
+/// so that one stale entry doesn't fail the whole batch.
+///
+/// Token vaults aren't swept here and unclaimed funds aren't rolled over; accounts with a token
+/// vault left to sweep or rolling over before their roll-over deadline should be closed with
+/// `CloseTipDistributionAccount` and are skipped too.
+pub fn process_close_tip_distribution_accounts(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let [config_info, expired_funds_account_info, tip_distribution_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let config = unsafe { Config::load(program_id, config_info, (), false)? };
+
+    if config
+        .expired_funds_account
+        .ne(expired_funds_account_info.key())
+    {
+        return Err(TipDistributionError::Unauthorized.into());
+    }
+
+    let tip_distribution_accounts = tip_distribution_accounts.chunks_exact(2);
+    if !tip_distribution_accounts.remainder().is_empty() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let epoch = Clock::get()?.epoch;
+    let mut num_closed: u64 = 0;
+
+    for tip_distribution_account_pair in tip_distribution_accounts {
+        let [tip_distribution_account_info, rent_refund_destination_info] =
+            tip_distribution_account_pair
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Only this program can have written the discriminator, so deriving the PDA again isn't
+        // worth its compute here. Read as a copy so accounts still in the version 0 layout can be
+        // closed too.
+        let Ok(tip_distribution_account) = (unsafe {
+            TipDistributionAccount::read(program_id, tip_distribution_account_info, true)
+        }) else {
+            continue;
+        };
+
+        if tip_distribution_account
+            .rent_refund_destination
+            .ne(rent_refund_destination_info.key())
+            || !rent_refund_destination_info.is_writable()
+        {
+            continue;
+        }
+
+        if epoch <= tip_distribution_account.expires_at() {
+            continue;
+        }
+
+        if tip_distribution_account.num_token_vaults() > 0 {
+            continue;
+        }
+
+        if tip_distribution_account.roll_over_unclaimed_funds()
+            && epoch <= tip_distribution_account.roll_over_deadline(config.num_epochs_valid)?
+        {
+            continue;
+        }
+
+        if tip_distribution_account.validate().is_err() {
+            continue;
+        }
+
+        TipDistributionAccount::claim_expired(
+            tip_distribution_account_info,
+            expired_funds_account_info,
+        )?;
+
+        unsafe {
+            close_program_account(
+                program_id,
+                tip_distribution_account_info,
+                rent_refund_destination_info,
+            )?;
+        }
+
+        num_closed = num_closed
+            .checked_add(1)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+    }
+
+    log!("Closed {} TipDistributionAccount accounts", num_closed);
+
+    Ok(())
+}
@@ -1,10 +1,10 @@
 use jito_tip_core::{
     create_account,
     loader::{load_signer, load_system_program},
-    transmutable::Transmutable,
+    program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
 };
-use jito_tip_distribution_core::{config::Config, load_mut_unchecked};
-use jito_tip_distribution_sdk::error::TipDistributionError;
+use jito_tip_distribution_core::config::Config;
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
@@ -32,9 +32,7 @@ pub fn process_initialize(
     load_system_program(system_program_info)?;
 
     let rent = Rent::get()?;
-    let space = 8usize
-        .checked_add(Config::LEN)
-        .ok_or(TipDistributionError::ArithmeticError)?;
+    let space = Config::SPACE;
 
     let (config_pubkey, config_bump, mut config_seeds) = Config::find_program_address(program_id);
     config_seeds.push(vec![config_bump]);
@@ -60,11 +58,7 @@ pub fn process_initialize(
         &signers,
     )?;
 
-    let cfg = unsafe {
-        let config_data = config_info.borrow_mut_data_unchecked();
-        config_data[0..8].copy_from_slice(Config::DISCRIMINATOR);
-        load_mut_unchecked::<Config>(&mut config_data[8..])?
-    };
+    let cfg = unsafe { Config::init_versioned(program_id, config_info)? };
 
     cfg.authority = authority;
     cfg.expired_funds_account = expired_funds_account;
@@ -1,11 +1,11 @@
 use jito_tip_core::{
     create_account,
     loader::{load_signer, load_system_program},
-    transmutable::Transmutable,
+    program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
 };
 use jito_tip_distribution_core::{
-    config::Config, load_mut_unchecked, load_unchecked,
-    merkle_root_upload_config::MerkleRootUploadConfig,
+    config::Config, merkle_root_upload_config::MerkleRootUploadConfig,
 };
 use jito_tip_distribution_sdk::error::TipDistributionError;
 use pinocchio::{
@@ -29,10 +29,7 @@ pub fn process_initialize_merkle_root_upload_config(
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let config = unsafe {
-        Config::load(program_id, config_info, false)?;
-        load_unchecked::<Config>(&config_info.borrow_data_unchecked()[8..])?
-    };
+    let config = unsafe { Config::load(program_id, config_info, (), false)? };
 
     // Call the authorize function
     if config.authority.ne(authority_info.key()) {
@@ -44,9 +41,7 @@ pub fn process_initialize_merkle_root_upload_config(
     load_system_program(system_program_info)?;
 
     let rent = Rent::get()?;
-    let space = 8usize
-        .checked_add(MerkleRootUploadConfig::LEN)
-        .ok_or(TipDistributionError::ArithmeticError)?;
+    let space = MerkleRootUploadConfig::SPACE;
 
     let (merkle_root_upload_config_pubkey, merkle_root_upload_config_bump) =
         MerkleRootUploadConfig::find_program_address(program_id);
@@ -58,9 +53,9 @@ pub fn process_initialize_merkle_root_upload_config(
 
     let merkle_root_upload_config_bump_slice = [merkle_root_upload_config_bump];
 
-    // Create the seeds for the PDA (assuming MerkleRootUploadConfig uses similar seed structure)
+    // Create the seeds for the PDA
     let merkle_root_upload_config_seeds = [
-        Seed::from(b"MERKLE_ROOT_UPLOAD_CONFIG".as_slice()), // Adjust this based on actual seed
+        Seed::from(MerkleRootUploadConfig::ROOT_UPLOAD_CONFIG_SEED),
         Seed::from(merkle_root_upload_config_bump_slice.as_slice()),
     ];
 
@@ -82,10 +77,7 @@ pub fn process_initialize_merkle_root_upload_config(
     )?;
 
     let merkle_root_upload_config = unsafe {
-        let merkle_root_upload_config_data =
-            merkle_root_upload_config_info.borrow_mut_data_unchecked();
-        merkle_root_upload_config_data[0..8].copy_from_slice(MerkleRootUploadConfig::DISCRIMINATOR);
-        load_mut_unchecked::<MerkleRootUploadConfig>(&mut merkle_root_upload_config_data[8..])?
+        MerkleRootUploadConfig::init_versioned(program_id, merkle_root_upload_config_info)?
     };
 
     // Set the bump and override authority
@@ -0,0 +1,105 @@
+use jito_tip_core::{
+    create_account,
+    loader::{load_signer, load_system_program},
+    program_account::ProgramAccount,
+};
+use jito_tip_distribution_core::{
+    config::Config, priority_fee_distribution_account::PriorityFeeDistributionAccount,
+};
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+};
+use pinocchio_log::log;
+
+/// Initialize a new [PriorityFeeDistributionAccount] associated with the given validator vote key
+/// and current epoch.
+pub fn process_initialize_priority_fee_distribution_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    merkle_root_upload_authority: Pubkey,
+    validator_commission_bps: u16,
+    bump: u8,
+) -> Result<(), ProgramError> {
+    let [config_info, priority_fee_distribution_account_info, validator_vote_account_info, signer_info, system_program_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer_info, true)?;
+    load_system_program(system_program_info)?;
+
+    let cfg = unsafe { Config::load(program_id, config_info, (), false)? };
+
+    if validator_commission_bps > cfg.max_validator_commission_bps {
+        log!(
+            "Validator commission BPS {} should be less than {}",
+            validator_commission_bps,
+            cfg.max_validator_commission_bps
+        );
+        return Err(TipDistributionError::MaxValidatorCommissionFeeBpsExceeded.into());
+    }
+
+    let current_epoch = Clock::get()?.epoch;
+    let rent = Rent::get()?;
+    let space = PriorityFeeDistributionAccount::SPACE;
+
+    let (priority_fee_distribution_account_pubkey, priority_fee_distribution_account_bump) =
+        PriorityFeeDistributionAccount::find_program_address(
+            program_id,
+            validator_vote_account_info.key(),
+            current_epoch,
+        );
+
+    if priority_fee_distribution_account_pubkey.ne(priority_fee_distribution_account_info.key()) {
+        log!("PriorityFeeDistributionAccount account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let priority_fee_distribution_account_bump_slice = [priority_fee_distribution_account_bump];
+
+    // Create the seeds for the PDA
+    let current_epoch_bytes = current_epoch.to_le_bytes();
+    let priority_fee_distribution_account_seeds = [
+        Seed::from(PriorityFeeDistributionAccount::SEED),
+        Seed::from(validator_vote_account_info.key().as_ref()),
+        Seed::from(current_epoch_bytes.as_slice()),
+        Seed::from(priority_fee_distribution_account_bump_slice.as_slice()),
+    ];
+
+    let signers = [Signer::from(
+        priority_fee_distribution_account_seeds.as_slice(),
+    )];
+
+    create_account(
+        signer_info,
+        priority_fee_distribution_account_info,
+        system_program_info,
+        program_id,
+        &rent,
+        space as u64,
+        &signers,
+    )?;
+
+    let priority_fee_distribution_account = unsafe {
+        PriorityFeeDistributionAccount::init(program_id, priority_fee_distribution_account_info)?
+    };
+
+    priority_fee_distribution_account.initialize(
+        *validator_vote_account_info.key(),
+        current_epoch,
+        validator_commission_bps,
+        merkle_root_upload_authority,
+        bump,
+        cfg.num_epochs_valid,
+    )?;
+
+    priority_fee_distribution_account.validate()?;
+
+    Ok(())
+}
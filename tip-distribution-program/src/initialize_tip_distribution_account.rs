@@ -1,11 +1,11 @@
-use borsh::BorshSerialize;
 use jito_tip_core::{
     create_account,
     loader::{load_signer, load_system_program},
-    transmutable::Transmutable,
+    program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
 };
 use jito_tip_distribution_core::{
-    config::Config, load_unchecked, tip_distribution_account::TipDistributionAccount,
+    config::Config, tip_distribution_account::TipDistributionAccount,
 };
 use jito_tip_distribution_sdk::error::TipDistributionError;
 use pinocchio::{
@@ -16,16 +16,23 @@ use pinocchio::{
     sysvars::{clock::Clock, rent::Rent, Sysvar},
 };
 use pinocchio_log::log;
-// use vote_state::VoteState;
+use vote_state::VoteAuthorities;
 
 /// Initialize a new [TipDistributionAccount] associated with the given validator vote key
 /// and current epoch.
+///
+/// The rent is refunded to `rent_refund_destination` when the account is closed, defaulting to
+/// the validator vote account. With `roll_over_unclaimed_funds`, funds left unclaimed on closure
+/// go to a later [TipDistributionAccount] of the validator. Either option must be signed for by
+/// the node identity or the authorized withdrawer of the vote account.
 pub fn process_initialize_tip_distribution_account(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     merkle_root_upload_authority: Pubkey,
     validator_commission_bps: u16,
     bump: u8,
+    rent_refund_destination: Option<Pubkey>,
+    roll_over_unclaimed_funds: bool,
 ) -> Result<(), ProgramError> {
     let [config_info, tip_distribution_account_info, validator_vote_account_info, signer_info, system_program_info] =
         accounts
@@ -36,10 +43,7 @@ pub fn process_initialize_tip_distribution_account(
     load_signer(signer_info, true)?;
     load_system_program(system_program_info)?;
 
-    let cfg = unsafe {
-        Config::load(program_id, config_info, false)?;
-        load_unchecked::<Config>(&config_info.borrow_data_unchecked()[8..])?
-    };
+    let cfg = unsafe { Config::load(program_id, config_info, (), false)? };
 
     if validator_commission_bps > cfg.max_validator_commission_bps {
         log!(
@@ -50,19 +54,19 @@ pub fn process_initialize_tip_distribution_account(
         return Err(TipDistributionError::MaxValidatorCommissionFeeBpsExceeded.into());
     }
 
-    // unsafe {
-    //     let validator_vote_state = VoteState::deserialize(validator_vote_account_info)?;
-    //     if validator_vote_state.node_pubkey.ne(signer_info.key()) {
-    //         return Err(TipDistributionError::Unauthorized.into());
-    //     }
-    // }
+    // Anyone can create the account, but only the validator picks where its rent and unclaimed
+    // funds go.
+    if rent_refund_destination.is_some() || roll_over_unclaimed_funds {
+        let vote_authorities = VoteAuthorities::load(validator_vote_account_info)?;
+        if !vote_authorities.is_authority(signer_info.key()) {
+            log!("Only the validator can set the rent refund destination or roll over");
+            return Err(TipDistributionError::Unauthorized.into());
+        }
+    }
 
     let current_epoch = Clock::get()?.epoch;
     let rent = Rent::get()?;
-    // let space = 8usize
-    //     .checked_add(TipDistributionAccount::LEN)
-    //     .ok_or(TipDistributionError::ArithmeticError)?;
-    let space = TipDistributionAccount::LEN;
+    let space = TipDistributionAccount::SPACE;
 
     let (tip_distribution_account_pubkey, tip_distribution_account_bump) =
         TipDistributionAccount::find_program_address(
@@ -76,12 +80,19 @@ pub fn process_initialize_tip_distribution_account(
         return Err(ProgramError::InvalidAccountData);
     }
 
+    let rent_refund_destination =
+        rent_refund_destination.unwrap_or_else(|| *validator_vote_account_info.key());
+    // The rent can't be refunded to the account being closed.
+    if rent_refund_destination.eq(tip_distribution_account_info.key()) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
     let tip_distribution_account_bump_slice = [tip_distribution_account_bump];
 
     // Create the seeds for the PDA
     let current_epoch_bytes = current_epoch.to_le_bytes();
     let tip_distribution_account_seeds = [
-        Seed::from(b"TIP_DISTRIBUTION_ACCOUNT".as_slice()),
+        Seed::from(TipDistributionAccount::SEED),
         Seed::from(validator_vote_account_info.key().as_ref()),
         Seed::from(current_epoch_bytes.as_slice()),
         Seed::from(tip_distribution_account_bump_slice.as_slice()),
@@ -100,26 +111,20 @@ pub fn process_initialize_tip_distribution_account(
     )?;
 
     let tip_distribution_account = unsafe {
-        let tip_distribution_account_data =
-            tip_distribution_account_info.borrow_mut_data_unchecked();
-        tip_distribution_account_data[0..8].copy_from_slice(TipDistributionAccount::DISCRIMINATOR);
-
-        let account = TipDistributionAccount::new(
-            *validator_vote_account_info.key(),
-            current_epoch,
-            validator_commission_bps,
-            merkle_root_upload_authority,
-            bump,
-            cfg.num_epochs_valid,
-        )?;
-        let mut writer = &mut tip_distribution_account_data[8..];
-        account
-            .serialize(&mut writer)
-            .map_err(|_e| ProgramError::BorshIoError)?;
-
-        account
+        TipDistributionAccount::init_versioned(program_id, tip_distribution_account_info)?
     };
 
+    tip_distribution_account.initialize(
+        *validator_vote_account_info.key(),
+        current_epoch,
+        validator_commission_bps,
+        merkle_root_upload_authority,
+        bump,
+        cfg.num_epochs_valid,
+        rent_refund_destination,
+        roll_over_unclaimed_funds,
+    )?;
+
     tip_distribution_account.validate()?;
 
     Ok(())
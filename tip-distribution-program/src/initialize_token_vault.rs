@@ -0,0 +1,107 @@
+use jito_tip_core::{
+    create_account,
+    loader::{load_signer, load_system_program},
+    versioned_account::VersionedAccount,
+};
+use jito_tip_distribution_core::tip_distribution_account::TipDistributionAccount;
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+};
+use pinocchio_log::log;
+use pinocchio_token::{instructions::InitializeAccount3, state::TokenAccount};
+
+/// Creates the token vault holding the `mint` tokens of a [TipDistributionAccount]: an SPL token
+/// account at a PDA of this program, owned by the [TipDistributionAccount].
+///
+/// Anyone can create a vault until the account expires, paying its rent. The
+/// [TipDistributionAccount] counts its vaults and can't be closed until every one of them has
+/// been swept, so tokens sent to a vault can't be stranded. Tokens sent to any other token account
+/// aren't tracked.
+pub fn process_initialize_token_vault(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let [tip_distribution_account_info, token_vault_info, mint_info, payer_info, system_program_info, token_program_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(payer_info, true)?;
+    load_system_program(system_program_info)?;
+
+    if token_program_info.key().ne(&pinocchio_token::ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if mint_info.owner().ne(&pinocchio_token::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let tip_distribution_account = unsafe {
+        TipDistributionAccount::load_mut_migrated(
+            program_id,
+            tip_distribution_account_info,
+            (),
+            payer_info,
+        )?
+    };
+
+    if Clock::get()?.epoch > tip_distribution_account.expires_at() {
+        return Err(TipDistributionError::ExpiredTipDistributionAccount.into());
+    }
+
+    let (token_vault_pubkey, token_vault_bump) = TipDistributionAccount::find_token_vault_address(
+        program_id,
+        tip_distribution_account_info.key(),
+        mint_info.key(),
+    );
+
+    if token_vault_pubkey.ne(token_vault_info.key()) {
+        log!("Token vault is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let token_vault_bump_slice = [token_vault_bump];
+
+    // Create the seeds for the PDA
+    let token_vault_seeds = [
+        Seed::from(TipDistributionAccount::TOKEN_VAULT_SEED),
+        Seed::from(tip_distribution_account_info.key().as_ref()),
+        Seed::from(mint_info.key().as_ref()),
+        Seed::from(token_vault_bump_slice.as_slice()),
+    ];
+
+    let signers = [Signer::from(token_vault_seeds.as_slice())];
+
+    log!(
+        "Initializing token vault at address {}",
+        token_vault_info.key()
+    );
+
+    create_account(
+        payer_info,
+        token_vault_info,
+        system_program_info,
+        &pinocchio_token::ID,
+        &Rent::get()?,
+        TokenAccount::LEN as u64,
+        &signers,
+    )?;
+
+    InitializeAccount3 {
+        account: token_vault_info,
+        mint: mint_info,
+        owner: tip_distribution_account_info.key(),
+    }
+    .invoke()?;
+
+    tip_distribution_account.add_token_vault()?;
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
 use claim::process_claim;
+use claim_priority_fee::process_claim_priority_fee;
+use claim_token::process_claim_token;
 use jito_tip_distribution_sdk::instruction::JitoTipDistributionInstruction;
 use pinocchio::{
     account_info::AccountInfo, entrypoint, msg, program_error::ProgramError, pubkey::Pubkey,
@@ -6,27 +8,56 @@ use pinocchio::{
 };
 
 use crate::{
+    add_permitted_uploader::process_add_permitted_uploader,
+    close_claim_delegate::process_close_claim_delegate,
     close_claim_status::process_close_claim_status,
+    close_claim_statuses::process_close_claim_statuses,
+    close_priority_fee_distribution_account::process_close_priority_fee_distribution_account,
     close_tip_distribution_account::process_close_tip_distribution_account,
+    close_tip_distribution_accounts::process_close_tip_distribution_accounts,
     initialize::process_initialize,
     initialize_merkle_root_upload_config::process_initialize_merkle_root_upload_config,
+    initialize_priority_fee_distribution_account::process_initialize_priority_fee_distribution_account,
     initialize_tip_distribution_account::process_initialize_tip_distribution_account,
+    initialize_token_vault::process_initialize_token_vault,
+    migrate_account::process_migrate_account,
+    migrate_tda_merkle_root_upload_authorities::process_migrate_tda_merkle_root_upload_authorities,
     migrate_tda_merkle_root_upload_authority::process_migrate_tda_merkle_root_upload_authority,
-    update_config::process_update_config,
+    remove_permitted_uploader::process_remove_permitted_uploader,
+    set_claim_delegate::process_set_claim_delegate,
+    transfer_priority_fees::process_transfer_priority_fees, update_config::process_update_config,
     update_merkle_root_upload_config::process_update_merkle_root_upload_config,
     upload_merkle_root::process_upload_merkle_root,
+    upload_priority_fee_merkle_root::process_upload_priority_fee_merkle_root,
+    upload_token_merkle_root::process_upload_token_merkle_root,
 };
 
+mod add_permitted_uploader;
 mod claim;
+mod claim_priority_fee;
+mod claim_token;
+mod close_claim_delegate;
 mod close_claim_status;
+mod close_claim_statuses;
+mod close_priority_fee_distribution_account;
 mod close_tip_distribution_account;
+mod close_tip_distribution_accounts;
 mod initialize;
 mod initialize_merkle_root_upload_config;
+mod initialize_priority_fee_distribution_account;
 mod initialize_tip_distribution_account;
+mod initialize_token_vault;
+mod migrate_account;
+mod migrate_tda_merkle_root_upload_authorities;
 mod migrate_tda_merkle_root_upload_authority;
+mod remove_permitted_uploader;
+mod set_claim_delegate;
+mod transfer_priority_fees;
 mod update_config;
 mod update_merkle_root_upload_config;
 mod upload_merkle_root;
+mod upload_priority_fee_merkle_root;
+mod upload_token_merkle_root;
 
 entrypoint!(process_instruction);
 
@@ -67,6 +98,8 @@ fn process_instruction(
             merkle_root_upload_authority,
             validator_commission_bps,
             bump,
+            rent_refund_destination,
+            roll_over_unclaimed_funds,
         } => {
             msg!("Instruction: InitializeTipDistributionAccount");
             process_initialize_tip_distribution_account(
@@ -75,6 +108,8 @@ fn process_instruction(
                 merkle_root_upload_authority,
                 validator_commission_bps,
                 bump,
+                rent_refund_destination,
+                roll_over_unclaimed_funds,
             )
         }
         JitoTipDistributionInstruction::UpdateConfig {
@@ -82,6 +117,7 @@ fn process_instruction(
             expired_funds_account,
             num_epochs_valid,
             max_validator_commission_bps,
+            merkle_root_upload_deadline_epochs,
         } => {
             msg!("Instruction: UpdateConfig");
             process_update_config(
@@ -91,6 +127,7 @@ fn process_instruction(
                 expired_funds_account,
                 num_epochs_valid,
                 max_validator_commission_bps,
+                merkle_root_upload_deadline_epochs,
             )
         }
         JitoTipDistributionInstruction::UploadMerkleRoot {
@@ -147,29 +184,107 @@ fn process_instruction(
             msg!("Instruction: MigrateTdaMerkleRootUploadAuthority");
             process_migrate_tda_merkle_root_upload_authority(program_id, accounts)
         }
-    }
-}
-
-/// This function deals with verification of Merkle trees (hash trees).
-///
-/// Direct port of https://github.com/OpenZeppelin/openzeppelin-contracts/blob/v3.4.0/contracts/cryptography/MerkleProof.sol
-/// Returns true if a `leaf` can be proved to be a part of a Merkle tree
-/// defined by `root`. For this, a `proof` must be provided, containing
-/// sibling hashes on the branch from the leaf to the root of the tree. Each
-/// pair of leaves and each pair of pre-images are assumed to be sorted.
-pub fn verify(proof: Vec<[u8; 32]>, root: [u8; 32], leaf: [u8; 32]) -> bool {
-    let mut computed_hash = leaf;
-    for proof_element in proof.into_iter() {
-        if computed_hash <= proof_element {
-            // Hash(current computed hash + current element of the proof)
-            computed_hash =
-                solana_program::hash::hashv(&[&[1u8], &computed_hash, &proof_element]).to_bytes();
-        } else {
-            // Hash(current element of the proof + current computed hash)
-            computed_hash =
-                solana_program::hash::hashv(&[&[1u8], &proof_element, &computed_hash]).to_bytes();
+        JitoTipDistributionInstruction::SetClaimDelegate { delegate } => {
+            msg!("Instruction: SetClaimDelegate");
+            process_set_claim_delegate(program_id, accounts, delegate)
+        }
+        JitoTipDistributionInstruction::CloseClaimDelegate => {
+            msg!("Instruction: CloseClaimDelegate");
+            process_close_claim_delegate(program_id, accounts)
+        }
+        JitoTipDistributionInstruction::InitializeTokenVault => {
+            msg!("Instruction: InitializeTokenVault");
+            process_initialize_token_vault(program_id, accounts)
+        }
+        JitoTipDistributionInstruction::UploadTokenMerkleRoot {
+            root,
+            max_total_claim,
+            max_num_nodes,
+        } => {
+            msg!("Instruction: UploadTokenMerkleRoot");
+            process_upload_token_merkle_root(
+                program_id,
+                accounts,
+                root,
+                max_total_claim,
+                max_num_nodes,
+            )
+        }
+        JitoTipDistributionInstruction::ClaimToken {
+            bump,
+            amount,
+            proof,
+        } => {
+            msg!("Instruction: ClaimToken");
+            process_claim_token(program_id, accounts, bump, amount, proof)
+        }
+        JitoTipDistributionInstruction::InitializePriorityFeeDistributionAccount {
+            merkle_root_upload_authority,
+            validator_commission_bps,
+            bump,
+        } => {
+            msg!("Instruction: InitializePriorityFeeDistributionAccount");
+            process_initialize_priority_fee_distribution_account(
+                program_id,
+                accounts,
+                merkle_root_upload_authority,
+                validator_commission_bps,
+                bump,
+            )
+        }
+        JitoTipDistributionInstruction::TransferPriorityFees { lamports } => {
+            msg!("Instruction: TransferPriorityFees");
+            process_transfer_priority_fees(program_id, accounts, lamports)
+        }
+        JitoTipDistributionInstruction::UploadPriorityFeeMerkleRoot {
+            root,
+            max_total_claim,
+            max_num_nodes,
+        } => {
+            msg!("Instruction: UploadPriorityFeeMerkleRoot");
+            process_upload_priority_fee_merkle_root(
+                program_id,
+                accounts,
+                root,
+                max_total_claim,
+                max_num_nodes,
+            )
+        }
+        JitoTipDistributionInstruction::ClaimPriorityFee {
+            bump,
+            amount,
+            proof,
+        } => {
+            msg!("Instruction: ClaimPriorityFee");
+            process_claim_priority_fee(program_id, accounts, bump, amount, proof)
+        }
+        JitoTipDistributionInstruction::ClosePriorityFeeDistributionAccount => {
+            msg!("Instruction: ClosePriorityFeeDistributionAccount");
+            process_close_priority_fee_distribution_account(program_id, accounts)
+        }
+        JitoTipDistributionInstruction::CloseClaimStatuses => {
+            msg!("Instruction: CloseClaimStatuses");
+            process_close_claim_statuses(program_id, accounts)
+        }
+        JitoTipDistributionInstruction::CloseTipDistributionAccounts => {
+            msg!("Instruction: CloseTipDistributionAccounts");
+            process_close_tip_distribution_accounts(program_id, accounts)
+        }
+        JitoTipDistributionInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
+        JitoTipDistributionInstruction::AddPermittedUploader { uploader } => {
+            msg!("Instruction: AddPermittedUploader");
+            process_add_permitted_uploader(program_id, accounts, uploader)
+        }
+        JitoTipDistributionInstruction::RemovePermittedUploader { uploader } => {
+            msg!("Instruction: RemovePermittedUploader");
+            process_remove_permitted_uploader(program_id, accounts, uploader)
+        }
+        JitoTipDistributionInstruction::MigrateTdaMerkleRootUploadAuthorities => {
+            msg!("Instruction: MigrateTdaMerkleRootUploadAuthorities");
+            process_migrate_tda_merkle_root_upload_authorities(program_id, accounts)
         }
     }
-    // Check if the computed hash (root) is equal to the provided root
-    computed_hash == root
 }
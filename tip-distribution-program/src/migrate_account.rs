@@ -0,0 +1,52 @@
+use jito_tip_core::{
+    loader::{load_signer, load_system_program},
+    program_account::{ProgramAccount, DISCRIMINATOR_LEN},
+    versioned_account::VersionedAccount,
+};
+use jito_tip_distribution_core::{
+    claim_status::ClaimStatus, config::Config, merkle_root_upload_config::MerkleRootUploadConfig,
+    tip_distribution_account::TipDistributionAccount,
+};
+use pinocchio::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// Migrates a [Config], [TipDistributionAccount], [ClaimStatus] or [MerkleRootUploadConfig] to
+/// the latest layout version, growing it if needed with `payer` funding the extra rent.
+///
+/// Anyone can invoke this since migrating only backfills defaults for the new fields.
+pub fn process_migrate_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let [account_info, payer_info, system_program_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(payer_info, true)?;
+    load_system_program(system_program_info)?;
+
+    let discriminator = unsafe {
+        account_info
+            .borrow_data_unchecked()
+            .first_chunk::<DISCRIMINATOR_LEN>()
+            .copied()
+    };
+
+    unsafe {
+        match discriminator {
+            Some(Config::DISCRIMINATOR) => Config::migrate(program_id, account_info, payer_info),
+            Some(TipDistributionAccount::DISCRIMINATOR) => {
+                TipDistributionAccount::migrate(program_id, account_info, payer_info)
+            }
+            Some(ClaimStatus::DISCRIMINATOR) => {
+                ClaimStatus::migrate(program_id, account_info, payer_info)
+            }
+            Some(MerkleRootUploadConfig::DISCRIMINATOR) => {
+                MerkleRootUploadConfig::migrate(program_id, account_info, payer_info)
+            }
+            _ => {
+                msg!("Account is not a versioned program account");
+                Err(ProgramError::InvalidAccountData)
+            }
+        }
+    }
+}
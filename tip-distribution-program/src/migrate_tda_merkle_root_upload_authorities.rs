@@ -0,0 +1,58 @@
+use jito_tip_core::program_account::ProgramAccount;
+use jito_tip_distribution_core::{
+    merkle_root_upload_config::MerkleRootUploadConfig,
+    tip_distribution_account::TipDistributionAccount,
+};
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_log::log;
+
+use crate::migrate_tda_merkle_root_upload_authority::migrate_merkle_root_upload_authority;
+
+/// Batch version of `MigrateTdaMerkleRootUploadAuthority`: migrates every [TipDistributionAccount]
+/// passed after the [MerkleRootUploadConfig] to its `override_authority`.
+///
+/// Anyone can invoke this. Accounts that aren't live [TipDistributionAccount]s, already have a
+/// merkle root or don't carry the `original_upload_authority` are skipped so that one stale entry
+/// doesn't fail the whole batch.
+pub fn process_migrate_tda_merkle_root_upload_authorities(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let [merkle_root_upload_config_info, tip_distribution_account_infos @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let merkle_root_upload_config = unsafe {
+        MerkleRootUploadConfig::load(program_id, merkle_root_upload_config_info, (), false)?
+    };
+
+    let mut num_migrated: u64 = 0;
+
+    for tip_distribution_account_info in tip_distribution_account_infos {
+        // Only this program can have written the discriminator, so deriving the PDA again isn't
+        // worth its compute here.
+        let Ok(tip_distribution_account) = (unsafe {
+            TipDistributionAccount::load_mut_without_pda_check(
+                program_id,
+                tip_distribution_account_info,
+            )
+        }) else {
+            continue;
+        };
+
+        if migrate_merkle_root_upload_authority(tip_distribution_account, merkle_root_upload_config)
+            .is_err()
+        {
+            continue;
+        }
+
+        num_migrated = num_migrated
+            .checked_add(1)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+    }
+
+    log!("Migrated {} TipDistributionAccount accounts", num_migrated);
+
+    Ok(())
+}
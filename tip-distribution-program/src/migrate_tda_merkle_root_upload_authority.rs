@@ -1,5 +1,6 @@
+use jito_tip_core::program_account::ProgramAccount;
 use jito_tip_distribution_core::{
-    load_mut_unchecked, load_unchecked, merkle_root_upload_config::MerkleRootUploadConfig,
+    merkle_root_upload_config::MerkleRootUploadConfig,
     tip_distribution_account::TipDistributionAccount,
 };
 use jito_tip_distribution_sdk::error::TipDistributionError;
@@ -13,29 +14,34 @@ pub fn process_migrate_tda_merkle_root_upload_authority(
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let tip_distribution_account = unsafe {
-        load_mut_unchecked::<TipDistributionAccount>(
-            &mut tip_distribution_account_info.borrow_mut_data_unchecked()[8..],
-        )?
-    };
+    let tip_distribution_account =
+        unsafe { TipDistributionAccount::load_mut(program_id, tip_distribution_account_info, ())? };
 
     let merkle_root_upload_config = unsafe {
-        MerkleRootUploadConfig::load(program_id, merkle_root_upload_config_info, false)?;
-        load_unchecked::<MerkleRootUploadConfig>(
-            &merkle_root_upload_config_info.borrow_data_unchecked()[8..],
-        )?
+        MerkleRootUploadConfig::load(program_id, merkle_root_upload_config_info, (), false)?
     };
 
+    migrate_merkle_root_upload_authority(tip_distribution_account, merkle_root_upload_config)?;
+
+    Ok(())
+}
+
+/// Hands the merkle root upload of `tip_distribution_account` over to the `override_authority` if
+/// it still carries the `original_upload_authority` and has no merkle root yet.
+pub fn migrate_merkle_root_upload_authority(
+    tip_distribution_account: &mut TipDistributionAccount,
+    merkle_root_upload_config: &MerkleRootUploadConfig,
+) -> Result<(), TipDistributionError> {
     // Validate TDA has no MerkleRoot uploaded to it
-    if tip_distribution_account.merkle_root.is_some() {
-        return Err(TipDistributionError::InvalidTdaForMigration.into());
+    if tip_distribution_account.has_merkle_root() {
+        return Err(TipDistributionError::InvalidTdaForMigration);
     }
 
     // Validate the TDA key is the acceptable original authority (i.e. the original Jito Lab's authority)
     if tip_distribution_account.merkle_root_upload_authority
         != merkle_root_upload_config.original_upload_authority
     {
-        return Err(TipDistributionError::InvalidTdaForMigration.into());
+        return Err(TipDistributionError::InvalidTdaForMigration);
     }
 
     // Change the TDA's root upload authority
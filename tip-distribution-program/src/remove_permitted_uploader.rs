@@ -0,0 +1,33 @@
+use jito_tip_core::{loader::load_signer, program_account::ProgramAccount};
+use jito_tip_distribution_core::{
+    config::Config, merkle_root_upload_config::MerkleRootUploadConfig,
+};
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_log::log;
+
+/// Revokes a key added by `AddPermittedUploader`. Only the [Config] authority can invoke this.
+pub fn process_remove_permitted_uploader(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    uploader: Pubkey,
+) -> Result<(), ProgramError> {
+    let [config_info, merkle_root_upload_config_info, authority_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let config = unsafe { Config::load(program_id, config_info, (), false)? };
+    if config.authority.ne(authority_info.key()) {
+        return Err(TipDistributionError::Unauthorized.into());
+    }
+    load_signer(authority_info, false)?;
+
+    let merkle_root_upload_config = unsafe {
+        MerkleRootUploadConfig::load_mut(program_id, merkle_root_upload_config_info, ())?
+    };
+    merkle_root_upload_config.remove_permitted_uploader(&uploader)?;
+
+    log!("Removed permitted uploader {}", &uploader);
+
+    Ok(())
+}
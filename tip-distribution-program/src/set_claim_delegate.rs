@@ -0,0 +1,88 @@
+use jito_tip_core::{
+    create_account,
+    loader::{load_signer, load_system_program},
+    program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
+};
+use jito_tip_distribution_core::claim_delegate::ClaimDelegate;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+};
+use pinocchio_log::log;
+
+/// Registers (or replaces) the key allowed to sign claims on behalf of `claimant`.
+/// The claimant signs and pays for the [ClaimDelegate] account.
+pub fn process_set_claim_delegate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    delegate: Pubkey,
+) -> Result<(), ProgramError> {
+    let [claim_delegate_info, claimant_info, system_program_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(claimant_info, true)?;
+    load_system_program(system_program_info)?;
+
+    if !claim_delegate_info.data_is_empty() {
+        let claim_delegate =
+            unsafe { ClaimDelegate::load_mut(program_id, claim_delegate_info, ())? };
+
+        if claim_delegate.claimant.ne(claimant_info.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        claim_delegate.delegate = delegate;
+
+        return Ok(());
+    }
+
+    let rent = Rent::get()?;
+    let space = ClaimDelegate::SPACE;
+
+    let (claim_delegate_pubkey, claim_delegate_bump) =
+        ClaimDelegate::find_program_address(program_id, claimant_info.key());
+
+    if claim_delegate_pubkey.ne(claim_delegate_info.key()) {
+        log!("ClaimDelegate account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let claim_delegate_bump_slice = [claim_delegate_bump];
+
+    // Create the seeds for the PDA
+    let claim_delegate_seeds = [
+        Seed::from(ClaimDelegate::SEED),
+        Seed::from(claimant_info.key().as_ref()),
+        Seed::from(claim_delegate_bump_slice.as_slice()),
+    ];
+
+    let signers = [Signer::from(claim_delegate_seeds.as_slice())];
+
+    log!(
+        "Initializing ClaimDelegate at address {}",
+        claim_delegate_info.key()
+    );
+
+    create_account(
+        claimant_info,
+        claim_delegate_info,
+        system_program_info,
+        program_id,
+        &rent,
+        space as u64,
+        &signers,
+    )?;
+
+    let claim_delegate = unsafe { ClaimDelegate::init_versioned(program_id, claim_delegate_info)? };
+
+    claim_delegate.claimant = *claimant_info.key();
+    claim_delegate.delegate = delegate;
+    claim_delegate.bump = claim_delegate_bump;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use jito_tip_core::{
+    loader::{load_signer, load_system_program},
+    program_account::ProgramAccount,
+};
+use jito_tip_distribution_core::{
+    config::Config, priority_fee_distribution_account::PriorityFeeDistributionAccount,
+};
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_system::instructions::Transfer;
+
+/// Deposits `lamports` of priority fees into the [PriorityFeeDistributionAccount] and adds them
+/// to its `total_lamports_transferred`. Anyone can deposit until the account expires or its
+/// merkle root is uploaded, since the root only distributes the fees deposited before it.
+pub fn process_transfer_priority_fees(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lamports: u64,
+) -> Result<(), ProgramError> {
+    let [config_info, priority_fee_distribution_account_info, from_info, system_program_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    unsafe {
+        Config::load(program_id, config_info, (), false)?;
+    }
+
+    load_signer(from_info, true)?;
+    load_system_program(system_program_info)?;
+
+    let priority_fee_distribution_account = unsafe {
+        PriorityFeeDistributionAccount::load_mut(
+            program_id,
+            priority_fee_distribution_account_info,
+            (),
+        )?
+    };
+
+    if Clock::get()?.epoch > priority_fee_distribution_account.expires_at {
+        return Err(TipDistributionError::ExpiredTipDistributionAccount.into());
+    }
+
+    if priority_fee_distribution_account.merkle_root().is_some() {
+        return Err(TipDistributionError::MerkleRootAlreadyUploaded.into());
+    }
+
+    priority_fee_distribution_account.total_lamports_transferred =
+        priority_fee_distribution_account
+            .total_lamports_transferred
+            .checked_add(lamports)
+            .ok_or(TipDistributionError::ArithmeticError)?;
+
+    Transfer {
+        from: from_info,
+        to: priority_fee_distribution_account_info,
+        lamports,
+    }
+    .invoke()?;
+
+    Ok(())
+}
@@ -1,9 +1,10 @@
-use jito_tip_core::loader::load_signer;
-use jito_tip_distribution_core::{config::Config, load_mut_unchecked};
+use jito_tip_core::{loader::load_signer, program_account::ProgramAccount};
+use jito_tip_distribution_core::config::Config;
 use jito_tip_distribution_sdk::error::TipDistributionError;
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
-/// Update config fields. Only the [Config] authority can invoke this.
+/// Update config fields. Only the [Config] authority can invoke this. The merkle root upload
+/// deadline is left unchanged when `merkle_root_upload_deadline_epochs` is `None`.
 pub fn process_update_config(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -11,15 +12,13 @@ pub fn process_update_config(
     expired_funds_account: Pubkey,
     num_epochs_valid: u64,
     max_validator_commission_bps: u16,
+    merkle_root_upload_deadline_epochs: Option<u64>,
 ) -> Result<(), ProgramError> {
     let [config_info, authority_info] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let config = unsafe {
-        Config::load(program_id, config_info, true)?;
-        load_mut_unchecked::<Config>(&mut config_info.borrow_mut_data_unchecked()[8..])?
-    };
+    let config = unsafe { Config::load_mut(program_id, config_info, ())? };
 
     load_signer(authority_info, false)?;
 
@@ -31,6 +30,9 @@ pub fn process_update_config(
     config.expired_funds_account = expired_funds_account;
     config.num_epochs_valid = num_epochs_valid;
     config.max_validator_commission_bps = max_validator_commission_bps;
+    if let Some(merkle_root_upload_deadline_epochs) = merkle_root_upload_deadline_epochs {
+        config.merkle_root_upload_deadline_epochs = merkle_root_upload_deadline_epochs;
+    }
 
     config.validate()?;
 
@@ -1,7 +1,9 @@
-use jito_tip_core::loader::{load_signer, load_system_program};
+use jito_tip_core::{
+    loader::{load_signer, load_system_program},
+    program_account::ProgramAccount,
+};
 use jito_tip_distribution_core::{
-    config::Config, load_mut_unchecked, load_unchecked,
-    merkle_root_upload_config::MerkleRootUploadConfig,
+    config::Config, merkle_root_upload_config::MerkleRootUploadConfig,
 };
 use jito_tip_distribution_sdk::error::TipDistributionError;
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
@@ -19,10 +21,7 @@ pub fn process_update_merkle_root_upload_config(
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let config = unsafe {
-        Config::load(program_id, config_info, false)?;
-        load_unchecked::<Config>(&config_info.borrow_data_unchecked()[8..])?
-    };
+    let config = unsafe { Config::load(program_id, config_info, (), false)? };
 
     // Call the authorize function
     if config.authority.ne(authority_info.key()) {
@@ -33,10 +32,7 @@ pub fn process_update_merkle_root_upload_config(
     load_system_program(system_program_info)?;
 
     let merkle_root_upload_config = unsafe {
-        MerkleRootUploadConfig::load(program_id, merkle_root_upload_config_info, true)?;
-        load_mut_unchecked::<MerkleRootUploadConfig>(
-            &mut merkle_root_upload_config_info.borrow_mut_data_unchecked()[8..],
-        )?
+        MerkleRootUploadConfig::load_mut(program_id, merkle_root_upload_config_info, ())?
     };
 
     // Update override authority
@@ -1,21 +1,30 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use jito_tip_core::loader::load_signer;
+use jito_tip_core::{loader::load_signer, program_account::ProgramAccount};
 use jito_tip_distribution_core::{
-    config::Config, merkle_root::MerkleRoot, tip_distribution_account::TipDistributionAccount,
+    config::Config, distribution_account::DistributionAccount, merkle_root::MerkleRoot,
+    merkle_root_upload_config::MerkleRootUploadConfig,
+    tip_distribution_account::TipDistributionAccount,
 };
 use jito_tip_distribution_sdk::error::TipDistributionError;
 use pinocchio::{
     account_info::AccountInfo,
     program_error::ProgramError,
     pubkey::Pubkey,
-    sysvars::{clock::Clock, Sysvar},
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
 };
+use pinocchio_log::log;
 
 /// Uploads a merkle root to the provided [TipDistributionAccount].
 ///
 /// This instruction may be invoked many times as long as the account is at least one epoch old and not expired; and
 /// no funds have already been claimed. Only the `merkle_root_upload_authority` has the
-/// authority to invoke.
+/// authority to invoke, or one of the permitted uploaders of the [MerkleRootUploadConfig] passed
+/// after the signer when the `merkle_root_upload_authority` is its `override_authority`.
+///
+/// Once the [Config] upload deadline has passed, the upload falls back to the
+/// `override_authority` and its permitted uploaders instead.
+///
+/// The account's balance above its rent-exempt minimum must cover `max_total_claim`, so that
+/// early claimers can't drain funds later claimers are owed.
 pub fn process_upload_merkle_root(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -23,67 +32,122 @@ pub fn process_upload_merkle_root(
     max_total_claim: u64,
     max_num_nodes: u64,
 ) -> Result<(), ProgramError> {
-    let [config_info, tip_distribution_account_info, merkle_root_upload_authority_info] = accounts
+    let [config_info, tip_distribution_account_info, merkle_root_upload_authority_info, remaining_accounts @ ..] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     let current_epoch = Clock::get()?.epoch;
 
-    unsafe {
-        Config::load(program_id, config_info, false)?;
-    }
+    let config = unsafe { Config::load(program_id, config_info, (), false)? };
 
     load_signer(merkle_root_upload_authority_info, false)?;
 
-    let mut tip_distribution_account = unsafe {
-        TipDistributionAccount::deserialize(
-            &mut tip_distribution_account_info.borrow_mut_data_unchecked()[8..].as_ref(),
-        )
-        .map_err(|_e| ProgramError::BorshIoError)?
-    };
+    let tip_distribution_account =
+        unsafe { TipDistributionAccount::load_mut(program_id, tip_distribution_account_info, ())? };
+
+    let upload_deadline_passed = config.merkle_root_upload_deadline_passed(
+        tip_distribution_account.epoch_created_at(),
+        current_epoch,
+    )?;
 
-    if tip_distribution_account
-        .merkle_root_upload_authority
-        .ne(merkle_root_upload_authority_info.key())
-    {
-        return Err(TipDistributionError::Unauthorized.into());
+    unsafe {
+        authorize_upload(
+            program_id,
+            &tip_distribution_account.merkle_root_upload_authority,
+            merkle_root_upload_authority_info.key(),
+            remaining_accounts.first(),
+            upload_deadline_passed,
+        )?;
     }
 
-    if let Some(merkle_root) = tip_distribution_account.merkle_root {
+    if let Some(merkle_root) = tip_distribution_account.merkle_root() {
         if merkle_root.num_nodes_claimed > 0 {
             return Err(TipDistributionError::Unauthorized.into());
         }
     }
 
-    if current_epoch <= tip_distribution_account.epoch_created_at {
+    if current_epoch <= tip_distribution_account.epoch_created_at() {
         return Err(TipDistributionError::PrematureMerkleRootUpload.into());
     }
 
-    if current_epoch > tip_distribution_account.expires_at {
+    if current_epoch > tip_distribution_account.expires_at() {
         return Err(TipDistributionError::ExpiredTipDistributionAccount.into());
     }
 
-    tip_distribution_account.merkle_root = Some(MerkleRoot {
+    let merkle_root = MerkleRoot {
         root,
         max_total_claim,
         max_num_nodes,
         total_funds_claimed: 0,
         num_nodes_claimed: 0,
-    });
-
-    let tip_distribution_account = unsafe {
-        let tip_distribution_account_data =
-            tip_distribution_account_info.borrow_mut_data_unchecked();
-        let mut writer = &mut tip_distribution_account_data[8..];
-        tip_distribution_account
-            .serialize(&mut writer)
-            .map_err(|_e| ProgramError::BorshIoError)?;
-
-        tip_distribution_account
     };
+    if !TipDistributionAccount::is_funded(
+        tip_distribution_account_info,
+        &merkle_root,
+        &Rent::get()?,
+    )? {
+        return Err(TipDistributionError::InsufficientFundsForMaxClaim.into());
+    }
+
+    tip_distribution_account.set_merkle_root(&merkle_root);
 
     tip_distribution_account.validate()?;
 
+    log!(
+        "Merkle root uploaded to {} by {}",
+        tip_distribution_account_info.key(),
+        merkle_root_upload_authority_info.key()
+    );
+
     Ok(())
 }
+
+/// Checks that `uploader` may upload a merkle root to an account whose upload authority is
+/// `merkle_root_upload_authority`.
+///
+/// Before the upload deadline that is the authority itself or, if it is the `override_authority`,
+/// one of the permitted uploaders. Afterwards only the `override_authority` and its permitted
+/// uploaders may upload, so the late authority gets a dedicated error.
+///
+/// # Safety
+///
+/// The caller must ensure that no mutable reference to the [MerkleRootUploadConfig] data is alive.
+pub unsafe fn authorize_upload(
+    program_id: &Pubkey,
+    merkle_root_upload_authority: &Pubkey,
+    uploader: &Pubkey,
+    merkle_root_upload_config_info: Option<&AccountInfo>,
+    upload_deadline_passed: bool,
+) -> Result<(), ProgramError> {
+    if !upload_deadline_passed && merkle_root_upload_authority.eq(uploader) {
+        return Ok(());
+    }
+
+    let merkle_root_upload_config = merkle_root_upload_config_info
+        .map(|info| MerkleRootUploadConfig::load(program_id, info, (), false))
+        .transpose()?;
+
+    if let Some(merkle_root_upload_config) = merkle_root_upload_config {
+        let is_fallback_uploader = merkle_root_upload_config.override_authority.eq(uploader)
+            || merkle_root_upload_config.is_permitted_uploader(uploader);
+
+        if upload_deadline_passed && is_fallback_uploader {
+            return Ok(());
+        }
+        if merkle_root_upload_config
+            .override_authority
+            .eq(merkle_root_upload_authority)
+            && merkle_root_upload_config.is_permitted_uploader(uploader)
+        {
+            return Ok(());
+        }
+    }
+
+    if upload_deadline_passed && merkle_root_upload_authority.eq(uploader) {
+        return Err(TipDistributionError::MerkleRootUploadDeadlinePassed.into());
+    }
+
+    Err(TipDistributionError::Unauthorized.into())
+}
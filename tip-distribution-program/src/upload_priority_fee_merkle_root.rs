@@ -0,0 +1,94 @@
+use jito_tip_core::{loader::load_signer, program_account::ProgramAccount};
+use jito_tip_distribution_core::{
+    config::Config, distribution_account::DistributionAccount, merkle_root::MerkleRoot,
+    priority_fee_distribution_account::PriorityFeeDistributionAccount,
+};
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+};
+
+/// Uploads a merkle root to the provided [PriorityFeeDistributionAccount].
+///
+/// Same rules as `UploadMerkleRoot`: the account must be at least one epoch old and not expired,
+/// no funds may have been claimed yet and only the `merkle_root_upload_authority` can invoke it.
+/// The root can't distribute more than the priority fees transferred to the account, and the
+/// account balance above its rent-exempt minimum must cover its `max_total_claim`.
+pub fn process_upload_priority_fee_merkle_root(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+) -> Result<(), ProgramError> {
+    let [config_info, priority_fee_distribution_account_info, merkle_root_upload_authority_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let current_epoch = Clock::get()?.epoch;
+
+    unsafe {
+        Config::load(program_id, config_info, (), false)?;
+    }
+
+    load_signer(merkle_root_upload_authority_info, false)?;
+
+    let priority_fee_distribution_account = unsafe {
+        PriorityFeeDistributionAccount::load_mut(
+            program_id,
+            priority_fee_distribution_account_info,
+            (),
+        )?
+    };
+
+    if priority_fee_distribution_account
+        .merkle_root_upload_authority
+        .ne(merkle_root_upload_authority_info.key())
+    {
+        return Err(TipDistributionError::Unauthorized.into());
+    }
+
+    if let Some(merkle_root) = priority_fee_distribution_account.merkle_root() {
+        if merkle_root.num_nodes_claimed > 0 {
+            return Err(TipDistributionError::Unauthorized.into());
+        }
+    }
+
+    if current_epoch <= priority_fee_distribution_account.epoch_created_at() {
+        return Err(TipDistributionError::PrematureMerkleRootUpload.into());
+    }
+
+    if current_epoch > priority_fee_distribution_account.expires_at {
+        return Err(TipDistributionError::ExpiredTipDistributionAccount.into());
+    }
+
+    if max_total_claim > priority_fee_distribution_account.total_lamports_transferred {
+        return Err(TipDistributionError::MaxTotalClaimExceedsTransferredFees.into());
+    }
+
+    let merkle_root = MerkleRoot {
+        root,
+        max_total_claim,
+        max_num_nodes,
+        total_funds_claimed: 0,
+        num_nodes_claimed: 0,
+    };
+    if !PriorityFeeDistributionAccount::is_funded(
+        priority_fee_distribution_account_info,
+        &merkle_root,
+        &Rent::get()?,
+    )? {
+        return Err(TipDistributionError::InsufficientFundsForMaxClaim.into());
+    }
+
+    priority_fee_distribution_account.set_merkle_root(&merkle_root);
+
+    priority_fee_distribution_account.validate()?;
+
+    Ok(())
+}
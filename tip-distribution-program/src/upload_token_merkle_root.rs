@@ -0,0 +1,178 @@
+use jito_tip_core::{
+    create_account,
+    loader::{load_signer, load_system_program},
+    program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
+};
+use jito_tip_distribution_core::{
+    config::Config, merkle_root::MerkleRoot, tip_distribution_account::TipDistributionAccount,
+    token_merkle_root::TokenMerkleRoot,
+};
+use jito_tip_distribution_sdk::error::TipDistributionError;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+};
+use pinocchio_log::log;
+use pinocchio_token::state::TokenAccount;
+
+use crate::upload_merkle_root::authorize_upload;
+
+/// Uploads the merkle root distributing the `mint` tokens held in the [TipDistributionAccount]'s
+/// token vault, creating the [TokenMerkleRoot] account on the first upload.
+///
+/// The same rules as for `UploadMerkleRoot` apply: the account must be at least one epoch old and
+/// not expired, no tokens may have been claimed yet, and the uploader must be authorized the same
+/// way, passing the `MerkleRootUploadConfig` after the system program when needed. The token
+/// vault must have been created with `InitializeTokenVault`, so the [TokenMerkleRoot] is closed
+/// along with it when the vault is swept, and must already hold the root's `max_total_claim`. The
+/// uploader pays for the [TokenMerkleRoot] account.
+pub fn process_upload_token_merkle_root(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+) -> Result<(), ProgramError> {
+    let [config_info, tip_distribution_account_info, token_merkle_root_info, mint_info, token_vault_info, merkle_root_upload_authority_info, system_program_info, remaining_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let current_epoch = Clock::get()?.epoch;
+
+    let config = unsafe { Config::load(program_id, config_info, (), false)? };
+
+    load_signer(merkle_root_upload_authority_info, true)?;
+    load_system_program(system_program_info)?;
+
+    if mint_info.owner().ne(&pinocchio_token::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let (token_vault_pubkey, _) = TipDistributionAccount::find_token_vault_address(
+        program_id,
+        tip_distribution_account_info.key(),
+        mint_info.key(),
+    );
+    if token_vault_pubkey.ne(token_vault_info.key())
+        || token_vault_info.owner().ne(&pinocchio_token::ID)
+    {
+        log!("Token vault has not been initialized");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let tip_distribution_account = unsafe {
+        TipDistributionAccount::load(program_id, tip_distribution_account_info, (), false)?
+    };
+
+    let upload_deadline_passed = config.merkle_root_upload_deadline_passed(
+        tip_distribution_account.epoch_created_at(),
+        current_epoch,
+    )?;
+
+    unsafe {
+        authorize_upload(
+            program_id,
+            &tip_distribution_account.merkle_root_upload_authority,
+            merkle_root_upload_authority_info.key(),
+            remaining_accounts.first(),
+            upload_deadline_passed,
+        )?;
+    }
+
+    if current_epoch <= tip_distribution_account.epoch_created_at() {
+        return Err(TipDistributionError::PrematureMerkleRootUpload.into());
+    }
+
+    if current_epoch > tip_distribution_account.expires_at() {
+        return Err(TipDistributionError::ExpiredTipDistributionAccount.into());
+    }
+
+    let token_merkle_root = if token_merkle_root_info.data_is_empty() {
+        let rent = Rent::get()?;
+        let space = TokenMerkleRoot::SPACE;
+
+        let (token_merkle_root_pubkey, token_merkle_root_bump) =
+            TokenMerkleRoot::find_program_address(
+                program_id,
+                tip_distribution_account_info.key(),
+                mint_info.key(),
+            );
+
+        if token_merkle_root_pubkey.ne(token_merkle_root_info.key()) {
+            log!("TokenMerkleRoot account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let token_merkle_root_bump_slice = [token_merkle_root_bump];
+
+        // Create the seeds for the PDA
+        let token_merkle_root_seeds = [
+            Seed::from(TokenMerkleRoot::SEED),
+            Seed::from(tip_distribution_account_info.key().as_ref()),
+            Seed::from(mint_info.key().as_ref()),
+            Seed::from(token_merkle_root_bump_slice.as_slice()),
+        ];
+
+        let signers = [Signer::from(token_merkle_root_seeds.as_slice())];
+
+        log!(
+            "Initializing TokenMerkleRoot at address {}",
+            token_merkle_root_info.key()
+        );
+
+        create_account(
+            merkle_root_upload_authority_info,
+            token_merkle_root_info,
+            system_program_info,
+            program_id,
+            &rent,
+            space as u64,
+            &signers,
+        )?;
+
+        let token_merkle_root =
+            unsafe { TokenMerkleRoot::init_versioned(program_id, token_merkle_root_info)? };
+        token_merkle_root.tip_distribution_account = *tip_distribution_account_info.key();
+        token_merkle_root.mint = *mint_info.key();
+        token_merkle_root.bump = token_merkle_root_bump;
+
+        token_merkle_root
+    } else {
+        let token_merkle_root =
+            unsafe { TokenMerkleRoot::load_mut(program_id, token_merkle_root_info, ())? };
+
+        if token_merkle_root
+            .tip_distribution_account
+            .ne(tip_distribution_account_info.key())
+            || token_merkle_root.mint.ne(mint_info.key())
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if token_merkle_root.merkle_root().num_nodes_claimed > 0 {
+            return Err(TipDistributionError::ClaimsAlreadyStarted.into());
+        }
+
+        token_merkle_root
+    };
+
+    if TokenAccount::from_account_info(token_vault_info)?.amount() < max_total_claim {
+        return Err(TipDistributionError::InsufficientFundsForMaxClaim.into());
+    }
+
+    token_merkle_root.set_merkle_root(&MerkleRoot {
+        root,
+        max_total_claim,
+        max_num_nodes,
+        total_funds_claimed: 0,
+        num_nodes_claimed: 0,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_distribution_core::{
+        claim_status::ClaimStatus, merkle_proof, merkle_root::MerkleRoot,
+    };
+    use jito_tip_distribution_sdk::sdk::claim;
+    use solana_keypair::Keypair;
+    use solana_native_token::sol_to_lamports;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::fixtures::{config, program_account, program_id, setup, tip_distribution_account};
+
+    /// Proof lengths the `Claim` compute units are reported for; a tree of `2^n` claimants has
+    /// proofs of length `n`.
+    const PROOF_LENGTHS: [usize; 6] = [0, 1, 4, 8, 12, 16];
+
+    const CLAIM_AMOUNT: u64 = 1_000_000;
+
+    /// Returns the compute units `Claim` consumes with a proof of `proof_len` nodes.
+    async fn claim_compute_units(proof_len: usize) -> u64 {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let (config_pubkey, config) = config(&program_id, &Pubkey::new_unique());
+        accounts.push((
+            config_pubkey,
+            program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        let claimant = Keypair::new();
+        let proof: Vec<[u8; 32]> = (0..proof_len).map(|i| [i as u8 + 1; 32]).collect();
+        let root = proof.iter().fold(
+            merkle_proof::leaf(&claimant.pubkey().to_bytes(), CLAIM_AMOUNT),
+            |node, sibling| merkle_proof::intermediate(&node, sibling),
+        );
+
+        let validator_vote_account = Pubkey::new_unique();
+        let (tip_distribution_account_pubkey, mut tip_distribution_account) =
+            tip_distribution_account(
+                &program_id,
+                &validator_vote_account,
+                0,
+                3,
+                &validator_vote_account,
+                false,
+            );
+        tip_distribution_account.set_merkle_root(&MerkleRoot {
+            root,
+            max_total_claim: CLAIM_AMOUNT,
+            max_num_nodes: 1 << proof_len,
+            total_funds_claimed: 0,
+            num_nodes_claimed: 0,
+        });
+        accounts.push((
+            tip_distribution_account_pubkey,
+            program_account(program_id, tip_distribution_account, sol_to_lamports(1.0)),
+        ));
+
+        let mut context = setup(accounts).await;
+
+        let (claim_status, bump) = Pubkey::find_program_address(
+            &[
+                ClaimStatus::SEED,
+                claimant.pubkey().as_ref(),
+                tip_distribution_account_pubkey.as_ref(),
+            ],
+            &program_id,
+        );
+        let ix = claim(
+            &program_id,
+            &config_pubkey,
+            &tip_distribution_account_pubkey,
+            &claimant.pubkey(),
+            &claim_status,
+            &claimant.pubkey(),
+            &context.payer.pubkey(),
+            &validator_vote_account,
+            None,
+            bump,
+            CLAIM_AMOUNT,
+            &proof,
+        );
+
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer, &claimant],
+                blockhash,
+            ))
+            .await
+            .unwrap();
+        result.result.unwrap();
+
+        result.metadata.unwrap().compute_units_consumed
+    }
+
+    /// Prints the rows of the README `Claim` compute unit table. Run with
+    /// `cargo test-sbf --test tests claim_compute_units -- --nocapture`.
+    #[tokio::test]
+    async fn claim_compute_units_by_proof_length() {
+        let mut previous = 0;
+        for proof_len in PROOF_LENGTHS {
+            let compute_units = claim_compute_units(proof_len).await;
+            println!("| {proof_len:<12} | {compute_units:<20} |");
+
+            // Every proof node costs one more hash.
+            assert!(compute_units > previous);
+            previous = compute_units;
+        }
+    }
+}
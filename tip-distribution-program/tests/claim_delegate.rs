@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::{program_account::ProgramAccount, versioned_account::VersionedAccount};
+    use jito_tip_distribution_core::{claim_delegate::ClaimDelegate, load_unchecked};
+    use jito_tip_distribution_sdk::sdk::{close_claim_delegate, set_claim_delegate};
+    use solana_commitment_config::CommitmentLevel;
+    use solana_keypair::Keypair;
+    use solana_native_token::sol_to_lamports;
+    use solana_program_test::ProgramTest;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_system_interface::instruction::transfer;
+    use solana_transaction::Transaction;
+
+    #[tokio::test]
+    async fn set_and_close_claim_delegate_success() {
+        let program_id = Pubkey::new_from_array(jito_tip_distribution_program::id());
+
+        let mut context = ProgramTest::new("jito_tip_distribution_program", program_id, None)
+            .start_with_context()
+            .await;
+
+        let claimant_kp = Keypair::new();
+
+        let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction_with_preflight_and_commitment(
+                Transaction::new_signed_with_payer(
+                    &[transfer(
+                        &context.payer.pubkey(),
+                        &claimant_kp.pubkey(),
+                        sol_to_lamports(1f64),
+                    )],
+                    Some(&context.payer.pubkey()),
+                    &[&context.payer],
+                    blockhash,
+                ),
+                CommitmentLevel::Processed,
+            )
+            .await
+            .unwrap();
+
+        let (claim_delegate_pubkey, claim_delegate_bump) = Pubkey::find_program_address(
+            &[ClaimDelegate::SEED, claimant_kp.pubkey().as_ref()],
+            &program_id,
+        );
+
+        // Registering and then replacing the delegate both go through `SetClaimDelegate`.
+        for delegate in [Pubkey::new_unique(), Pubkey::new_unique()] {
+            let ix = set_claim_delegate(
+                &program_id,
+                &claim_delegate_pubkey,
+                &claimant_kp.pubkey(),
+                &delegate,
+            );
+
+            let blockhash = context.get_new_latest_blockhash().await.unwrap();
+            context
+                .banks_client
+                .process_transaction(Transaction::new_signed_with_payer(
+                    &[ix],
+                    Some(&claimant_kp.pubkey()),
+                    &[&claimant_kp],
+                    blockhash,
+                ))
+                .await
+                .unwrap();
+
+            let account = context
+                .banks_client
+                .get_account(claim_delegate_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(account.data.len(), ClaimDelegate::SPACE);
+
+            let claim_delegate =
+                unsafe { load_unchecked::<ClaimDelegate>(&account.data[8..]).unwrap() };
+            assert_eq!(claim_delegate.claimant, claimant_kp.pubkey().to_bytes());
+            assert_eq!(claim_delegate.delegate, delegate.to_bytes());
+            assert_eq!(claim_delegate.bump, claim_delegate_bump);
+            assert_eq!(claim_delegate.version, ClaimDelegate::VERSION);
+        }
+
+        let ix = close_claim_delegate(&program_id, &claim_delegate_pubkey, &claimant_kp.pubkey());
+
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&claimant_kp.pubkey()),
+                &[&claimant_kp],
+                blockhash,
+            ))
+            .await
+            .unwrap();
+
+        assert!(context
+            .banks_client
+            .get_account(claim_delegate_pubkey)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,709 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::versioned_account::VersionedAccount;
+    use jito_tip_distribution_core::{
+        claim_status::ClaimStatus, merkle_proof, merkle_root::MerkleRoot,
+        tip_distribution_account::TipDistributionAccount, token_merkle_root::TokenMerkleRoot,
+    };
+    use jito_tip_distribution_sdk::{
+        error::TipDistributionError,
+        instruction::JitoTipDistributionInstruction,
+        sdk::{
+            claim_token, close_tip_distribution_account, initialize_token_vault,
+            upload_token_merkle_root, TOKEN_PROGRAM_ID,
+        },
+    };
+    use solana_account::Account;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_native_token::sol_to_lamports;
+    use solana_program_test::{BanksClientError, ProgramTestContext};
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::fixtures::{
+        config, custom_error, decode_program_account, mint_account, program_account,
+        program_account_data, program_id, setup, tip_distribution_account, token_account,
+        token_amount,
+    };
+
+    /// Tokens held by the vault of the TDA.
+    const VAULT_AMOUNT: u64 = 1_000;
+
+    /// Tokens the claimant is entitled to.
+    const CLAIM_AMOUNT: u64 = 600;
+
+    /// Tokens the other leaf of the merkle tree is entitled to.
+    const OTHER_AMOUNT: u64 = 400;
+
+    /// Epoch the TDA expires at.
+    const EXPIRES_AT: u64 = 3;
+
+    struct TestAccounts {
+        program_id: Pubkey,
+        config: Pubkey,
+        expired_funds_account: Pubkey,
+        expired_funds_token_account: Pubkey,
+        tip_distribution_account: Pubkey,
+        validator_vote_account: Pubkey,
+        merkle_root_upload_authority: Keypair,
+        mint: Pubkey,
+        token_vault: Pubkey,
+        token_merkle_root: Pubkey,
+        claimant: Keypair,
+        claimant_token_account: Pubkey,
+        /// Root of the tree paying [CLAIM_AMOUNT] to the claimant and [OTHER_AMOUNT] to another
+        /// leaf.
+        root: [u8; 32],
+        /// Proof of the claimant's leaf.
+        proof: Vec<[u8; 32]>,
+    }
+
+    /// Starts a test validator with a TDA created at epoch 0 whose token vault holds
+    /// [VAULT_AMOUNT] tokens. With `with_token_merkle_root` its [TokenMerkleRoot] has already been
+    /// uploaded, otherwise the vault is funded but has no root.
+    async fn start(with_token_merkle_root: bool) -> (ProgramTestContext, TestAccounts) {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let expired_funds_account = Pubkey::new_unique();
+        let (config_pubkey, config) = config(&program_id, &expired_funds_account);
+        accounts.push((
+            config_pubkey,
+            program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        let merkle_root_upload_authority = Keypair::new();
+        let validator_vote_account = Pubkey::new_unique();
+        let (tip_distribution_account_pubkey, mut tip_distribution_account) =
+            tip_distribution_account(
+                &program_id,
+                &validator_vote_account,
+                0,
+                EXPIRES_AT,
+                &validator_vote_account,
+                false,
+            );
+        tip_distribution_account.merkle_root_upload_authority =
+            merkle_root_upload_authority.pubkey().to_bytes();
+        // The uploader pays for the TokenMerkleRoot.
+        accounts.push((
+            merkle_root_upload_authority.pubkey(),
+            Account {
+                lamports: sol_to_lamports(1.0),
+                ..Account::default()
+            },
+        ));
+
+        let mint = Pubkey::new_unique();
+        accounts.push((mint, mint_account(VAULT_AMOUNT)));
+
+        let (token_vault, _) = Pubkey::find_program_address(
+            &[
+                TipDistributionAccount::TOKEN_VAULT_SEED,
+                tip_distribution_account_pubkey.as_ref(),
+                mint.as_ref(),
+            ],
+            &program_id,
+        );
+        accounts.push((
+            token_vault,
+            token_account(&mint, &tip_distribution_account_pubkey, VAULT_AMOUNT),
+        ));
+        tip_distribution_account.add_token_vault().unwrap();
+
+        let expired_funds_token_account = Pubkey::new_unique();
+        accounts.push((
+            expired_funds_token_account,
+            token_account(&mint, &expired_funds_account, 0),
+        ));
+
+        let claimant = Keypair::new();
+        let claimant_token_account = Pubkey::new_unique();
+        accounts.push((
+            claimant_token_account,
+            token_account(&mint, &claimant.pubkey(), 0),
+        ));
+
+        let claimant_leaf = merkle_proof::leaf(&claimant.pubkey().to_bytes(), CLAIM_AMOUNT);
+        let other_leaf = merkle_proof::leaf(&[9; 32], OTHER_AMOUNT);
+        let root = merkle_proof::intermediate(&claimant_leaf, &other_leaf);
+
+        let (token_merkle_root_pubkey, bump) = Pubkey::find_program_address(
+            &[
+                TokenMerkleRoot::SEED,
+                tip_distribution_account_pubkey.as_ref(),
+                mint.as_ref(),
+            ],
+            &program_id,
+        );
+        if with_token_merkle_root {
+            let mut token_merkle_root: TokenMerkleRoot = unsafe { core::mem::zeroed() };
+            token_merkle_root.tip_distribution_account = tip_distribution_account_pubkey.to_bytes();
+            token_merkle_root.mint = mint.to_bytes();
+            token_merkle_root.bump = bump;
+            token_merkle_root.version = TokenMerkleRoot::VERSION;
+            token_merkle_root.set_merkle_root(&MerkleRoot {
+                root,
+                max_total_claim: VAULT_AMOUNT,
+                max_num_nodes: 2,
+                total_funds_claimed: 0,
+                num_nodes_claimed: 0,
+            });
+            accounts.push((
+                token_merkle_root_pubkey,
+                program_account(program_id, token_merkle_root, sol_to_lamports(0.01)),
+            ));
+        }
+
+        accounts.push((
+            tip_distribution_account_pubkey,
+            program_account(program_id, tip_distribution_account, sol_to_lamports(0.01)),
+        ));
+
+        let context = setup(accounts).await;
+
+        (
+            context,
+            TestAccounts {
+                program_id,
+                config: config_pubkey,
+                expired_funds_account,
+                expired_funds_token_account,
+                tip_distribution_account: tip_distribution_account_pubkey,
+                validator_vote_account,
+                merkle_root_upload_authority,
+                mint,
+                token_vault,
+                token_merkle_root: token_merkle_root_pubkey,
+                claimant,
+                claimant_token_account,
+                root,
+                proof: vec![other_leaf],
+            },
+        )
+    }
+
+    async fn process(
+        context: &mut ProgramTestContext,
+        ix: Instruction,
+        signer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer, signer],
+                blockhash,
+            ))
+            .await
+    }
+
+    fn claim_ix(
+        context: &ProgramTestContext,
+        accounts: &TestAccounts,
+        proof: &[[u8; 32]],
+    ) -> Instruction {
+        let (claim_status, bump) = Pubkey::find_program_address(
+            &[
+                ClaimStatus::SEED,
+                accounts.claimant.pubkey().as_ref(),
+                accounts.token_merkle_root.as_ref(),
+            ],
+            &accounts.program_id,
+        );
+
+        claim_token(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.tip_distribution_account,
+            &accounts.token_merkle_root,
+            &accounts.claimant.pubkey(),
+            &claim_status,
+            &accounts.claimant.pubkey(),
+            &accounts.claimant_token_account,
+            &accounts.token_vault,
+            &context.payer.pubkey(),
+            &accounts.validator_vote_account,
+            None,
+            bump,
+            CLAIM_AMOUNT,
+            proof,
+        )
+    }
+
+    fn upload_ix(accounts: &TestAccounts) -> Instruction {
+        upload_token_merkle_root(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.tip_distribution_account,
+            &accounts.token_merkle_root,
+            &accounts.mint,
+            &accounts.token_vault,
+            &accounts.merkle_root_upload_authority.pubkey(),
+            None,
+            accounts.root,
+            VAULT_AMOUNT,
+            2,
+        )
+    }
+
+    /// Closes the TDA signed by `signer`, sweeping its token vault unless `sweep` is `false`.
+    fn close_ix(accounts: &TestAccounts, signer: &Keypair, sweep: bool) -> Instruction {
+        let mut ix = close_tip_distribution_account(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.expired_funds_account,
+            &accounts.tip_distribution_account,
+            &accounts.validator_vote_account,
+            &signer.pubkey(),
+            None,
+        );
+        if sweep {
+            ix.accounts.extend([
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new(accounts.token_vault, false),
+                AccountMeta::new(accounts.expired_funds_token_account, false),
+                AccountMeta::new(accounts.token_merkle_root, false),
+            ]);
+        }
+        ix
+    }
+
+    fn funded_keypair(context: &mut ProgramTestContext) -> Keypair {
+        let keypair = Keypair::new();
+        context.set_account(
+            &keypair.pubkey(),
+            &Account {
+                lamports: sol_to_lamports(1.0),
+                ..Account::default()
+            }
+            .into(),
+        );
+        keypair
+    }
+
+    async fn token_balance(context: &mut ProgramTestContext, pubkey: Pubkey) -> u64 {
+        let account = context
+            .banks_client
+            .get_account(pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+        token_amount(&account.data)
+    }
+
+    async fn load_tip_distribution_account(
+        context: &mut ProgramTestContext,
+        accounts: &TestAccounts,
+    ) -> TipDistributionAccount {
+        let account = context
+            .banks_client
+            .get_account(accounts.tip_distribution_account)
+            .await
+            .unwrap()
+            .unwrap();
+        decode_program_account(&account.data)
+    }
+
+    #[test]
+    fn decode_claim_token() {
+        let proof = [[7; 32]];
+        let key = Pubkey::new_unique();
+        let ix = claim_token(
+            &key, &key, &key, &key, &key, &key, &key, &key, &key, &key, &key, None, 253, 42, &proof,
+        );
+
+        assert_eq!(
+            JitoTipDistributionInstruction::try_from_slice(&ix.data),
+            Ok(JitoTipDistributionInstruction::ClaimToken {
+                bump: 253,
+                amount: 42,
+                proof: &proof,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn claim_token_transfers_tokens_from_vault() {
+        let (mut context, accounts) = start(true).await;
+
+        let ix = claim_ix(&context, &accounts, &accounts.proof);
+        process(&mut context, ix, &accounts.claimant).await.unwrap();
+
+        assert_eq!(
+            token_balance(&mut context, accounts.claimant_token_account).await,
+            CLAIM_AMOUNT
+        );
+        assert_eq!(
+            token_balance(&mut context, accounts.token_vault).await,
+            VAULT_AMOUNT - CLAIM_AMOUNT
+        );
+
+        let account = context
+            .banks_client
+            .get_account(accounts.token_merkle_root)
+            .await
+            .unwrap()
+            .unwrap();
+        let merkle_root = decode_program_account::<TokenMerkleRoot>(&account.data).merkle_root();
+        assert_eq!(merkle_root.total_funds_claimed, CLAIM_AMOUNT);
+        assert_eq!(merkle_root.num_nodes_claimed, 1);
+    }
+
+    #[tokio::test]
+    async fn claim_token_twice_fails() {
+        let (mut context, accounts) = start(true).await;
+
+        let ix = claim_ix(&context, &accounts, &accounts.proof);
+        process(&mut context, ix, &accounts.claimant).await.unwrap();
+
+        // The ClaimStatus already exists, so it can't be created again.
+        let ix = claim_ix(&context, &accounts, &accounts.proof);
+        process(&mut context, ix, &accounts.claimant)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            token_balance(&mut context, accounts.claimant_token_account).await,
+            CLAIM_AMOUNT
+        );
+    }
+
+    #[tokio::test]
+    async fn claim_token_with_invalid_proof_fails() {
+        let (mut context, accounts) = start(true).await;
+
+        let ix = claim_ix(&context, &accounts, &[[8; 32]]);
+        let error = process(&mut context, ix, &accounts.claimant)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::InvalidProof as u32)
+        );
+
+        assert_eq!(
+            token_balance(&mut context, accounts.token_vault).await,
+            VAULT_AMOUNT
+        );
+    }
+
+    #[tokio::test]
+    async fn claim_token_fails_if_remaining_claims_are_underfunded() {
+        let (mut context, accounts) = start(true).await;
+        context.set_account(
+            &accounts.token_vault,
+            &token_account(
+                &accounts.mint,
+                &accounts.tip_distribution_account,
+                VAULT_AMOUNT - 1,
+            )
+            .into(),
+        );
+
+        let ix = claim_ix(&context, &accounts, &accounts.proof);
+        let error = process(&mut context, ix, &accounts.claimant)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::InsufficientFundsForRemainingClaims as u32)
+        );
+    }
+
+    #[tokio::test]
+    async fn initialize_token_vault_counts_token_vaults() {
+        let (mut context, accounts) = start(false).await;
+
+        let mint = Pubkey::new_unique();
+        context.set_account(&mint, &mint_account(VAULT_AMOUNT).into());
+        let (token_vault, _) = Pubkey::find_program_address(
+            &[
+                TipDistributionAccount::TOKEN_VAULT_SEED,
+                accounts.tip_distribution_account.as_ref(),
+                mint.as_ref(),
+            ],
+            &accounts.program_id,
+        );
+
+        let payer = funded_keypair(&mut context);
+        let ix = initialize_token_vault(
+            &accounts.program_id,
+            &accounts.tip_distribution_account,
+            &token_vault,
+            &mint,
+            &payer.pubkey(),
+        );
+        process(&mut context, ix, &payer).await.unwrap();
+
+        let account = context
+            .banks_client
+            .get_account(token_vault)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(account.owner, TOKEN_PROGRAM_ID);
+        assert_eq!(account.data[..32], mint.to_bytes());
+        assert_eq!(
+            account.data[32..64],
+            accounts.tip_distribution_account.to_bytes()
+        );
+        assert_eq!(
+            load_tip_distribution_account(&mut context, &accounts)
+                .await
+                .num_token_vaults(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn initialize_token_vault_rejects_other_addresses() {
+        let (mut context, accounts) = start(false).await;
+
+        let mint = Pubkey::new_unique();
+        context.set_account(&mint, &mint_account(VAULT_AMOUNT).into());
+
+        let payer = funded_keypair(&mut context);
+        let ix = initialize_token_vault(
+            &accounts.program_id,
+            &accounts.tip_distribution_account,
+            &Pubkey::new_unique(),
+            &mint,
+            &payer.pubkey(),
+        );
+        process(&mut context, ix, &payer).await.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn upload_token_merkle_root_requires_token_vault() {
+        let (mut context, mut accounts) = start(false).await;
+        context.warp_to_epoch(1).unwrap();
+
+        // A mint the TDA has no vault for.
+        let mint = Pubkey::new_unique();
+        context.set_account(&mint, &mint_account(VAULT_AMOUNT).into());
+        accounts.mint = mint;
+        accounts.token_merkle_root = Pubkey::find_program_address(
+            &[
+                TokenMerkleRoot::SEED,
+                accounts.tip_distribution_account.as_ref(),
+                mint.as_ref(),
+            ],
+            &accounts.program_id,
+        )
+        .0;
+        accounts.token_vault = Pubkey::find_program_address(
+            &[
+                TipDistributionAccount::TOKEN_VAULT_SEED,
+                accounts.tip_distribution_account.as_ref(),
+                mint.as_ref(),
+            ],
+            &accounts.program_id,
+        )
+        .0;
+
+        let ix = upload_ix(&accounts);
+        process(&mut context, ix, &accounts.merkle_root_upload_authority)
+            .await
+            .unwrap_err();
+        assert!(context
+            .banks_client
+            .get_account(accounts.token_merkle_root)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn upload_token_merkle_root_creates_token_merkle_root() {
+        let (mut context, accounts) = start(false).await;
+        context.warp_to_epoch(1).unwrap();
+
+        let ix = upload_ix(&accounts);
+        process(&mut context, ix, &accounts.merkle_root_upload_authority)
+            .await
+            .unwrap();
+
+        let account = context
+            .banks_client
+            .get_account(accounts.token_merkle_root)
+            .await
+            .unwrap()
+            .unwrap();
+        let token_merkle_root = decode_program_account::<TokenMerkleRoot>(&account.data);
+        assert_eq!(token_merkle_root.merkle_root().root, accounts.root);
+
+        // The root can be replaced before claims started, and the vault is still counted once.
+        let ix = upload_ix(&accounts);
+        process(&mut context, ix, &accounts.merkle_root_upload_authority)
+            .await
+            .unwrap();
+        assert_eq!(
+            load_tip_distribution_account(&mut context, &accounts)
+                .await
+                .num_token_vaults(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_token_merkle_root_fails_after_claims_started() {
+        let (mut context, accounts) = start(true).await;
+
+        let ix = claim_ix(&context, &accounts, &accounts.proof);
+        process(&mut context, ix, &accounts.claimant).await.unwrap();
+
+        context.warp_to_epoch(1).unwrap();
+        let ix = upload_ix(&accounts);
+        let error = process(&mut context, ix, &accounts.merkle_root_upload_authority)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::ClaimsAlreadyStarted as u32)
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_token_merkle_root_requires_funds_for_max_total_claim() {
+        let (mut context, accounts) = start(false).await;
+        context.set_account(
+            &accounts.token_vault,
+            &token_account(
+                &accounts.mint,
+                &accounts.tip_distribution_account,
+                VAULT_AMOUNT - 1,
+            )
+            .into(),
+        );
+        context.warp_to_epoch(1).unwrap();
+
+        let ix = upload_ix(&accounts);
+        let error = process(&mut context, ix, &accounts.merkle_root_upload_authority)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::InsufficientFundsForMaxClaim as u32)
+        );
+    }
+
+    #[tokio::test]
+    async fn close_tip_distribution_account_fails_until_token_vault_is_swept() {
+        let (mut context, accounts) = start(true).await;
+        context.warp_to_epoch(EXPIRES_AT + 1).unwrap();
+
+        let signer = Keypair::new();
+        let ix = close_ix(&accounts, &signer, false);
+        let error = process(&mut context, ix, &signer).await.unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::TokenVaultsNotSwept as u32)
+        );
+
+        assert!(context
+            .banks_client
+            .get_account(accounts.tip_distribution_account)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn close_tip_distribution_account_fails_until_rootless_token_vault_is_swept() {
+        let (mut context, accounts) = start(false).await;
+        context.warp_to_epoch(EXPIRES_AT + 1).unwrap();
+
+        let signer = Keypair::new();
+        let ix = close_ix(&accounts, &signer, false);
+        let error = process(&mut context, ix, &signer).await.unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::TokenVaultsNotSwept as u32)
+        );
+
+        assert_eq!(
+            token_balance(&mut context, accounts.token_vault).await,
+            VAULT_AMOUNT
+        );
+    }
+
+    #[tokio::test]
+    async fn close_tip_distribution_account_sweeps_token_vault() {
+        let (mut context, accounts) = start(true).await;
+        context.warp_to_epoch(EXPIRES_AT + 1).unwrap();
+
+        let signer = Keypair::new();
+        let ix = close_ix(&accounts, &signer, true);
+        process(&mut context, ix, &signer).await.unwrap();
+
+        assert_eq!(
+            token_balance(&mut context, accounts.expired_funds_token_account).await,
+            VAULT_AMOUNT
+        );
+        for pubkey in [
+            accounts.token_vault,
+            accounts.token_merkle_root,
+            accounts.tip_distribution_account,
+        ] {
+            assert!(context
+                .banks_client
+                .get_account(pubkey)
+                .await
+                .unwrap()
+                .is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn close_tip_distribution_account_sweeps_token_vault_without_token_merkle_root() {
+        let (mut context, accounts) = start(false).await;
+        context.warp_to_epoch(EXPIRES_AT + 1).unwrap();
+
+        let signer = Keypair::new();
+        let ix = close_ix(&accounts, &signer, true);
+        process(&mut context, ix, &signer).await.unwrap();
+
+        assert_eq!(
+            token_balance(&mut context, accounts.expired_funds_token_account).await,
+            VAULT_AMOUNT
+        );
+        assert!(context
+            .banks_client
+            .get_account(accounts.tip_distribution_account)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn close_tip_distribution_account_counts_down_token_vaults() {
+        let (mut context, accounts) = start(true).await;
+
+        // A second token vault the close below doesn't sweep.
+        let mut account = context
+            .banks_client
+            .get_account(accounts.tip_distribution_account)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut tip_distribution_account: TipDistributionAccount =
+            decode_program_account(&account.data);
+        tip_distribution_account.add_token_vault().unwrap();
+        account.data = program_account_data(tip_distribution_account);
+        context.set_account(&accounts.tip_distribution_account, &account.into());
+
+        context.warp_to_epoch(EXPIRES_AT + 1).unwrap();
+        let signer = Keypair::new();
+        let ix = close_ix(&accounts, &signer, true);
+        let error = process(&mut context, ix, &signer).await.unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::TokenVaultsNotSwept as u32)
+        );
+    }
+}
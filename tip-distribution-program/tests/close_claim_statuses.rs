@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::{program_account::ProgramAccount, versioned_account::VersionedAccount};
+    use jito_tip_distribution_core::claim_status::ClaimStatus;
+    use jito_tip_distribution_sdk::sdk::close_claim_statuses;
+    use solana_native_token::sol_to_lamports;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::fixtures::{config, program_account, program_id, setup};
+
+    fn claim_status(claim_status_payer: Pubkey, expires_at: u64) -> ClaimStatus {
+        ClaimStatus {
+            is_claimed: true,
+            claimant: Pubkey::new_unique().to_bytes(),
+            claim_status_payer: claim_status_payer.to_bytes(),
+            slot_claimed_at: 0,
+            amount: 1,
+            expires_at,
+            bump: 255,
+            version: ClaimStatus::VERSION,
+        }
+    }
+
+    #[tokio::test]
+    async fn close_claim_statuses_skips_unclosable_accounts() {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let (config_pubkey, config) = config(&program_id, &Pubkey::new_unique());
+        accounts.push((
+            config_pubkey,
+            program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        let payer = Pubkey::new_unique();
+        let expired = Pubkey::new_unique();
+        let not_expired = Pubkey::new_unique();
+        let wrong_payer = Pubkey::new_unique();
+        accounts.push((
+            expired,
+            program_account(program_id, claim_status(payer, 0), sol_to_lamports(0.01)),
+        ));
+        accounts.push((
+            not_expired,
+            program_account(program_id, claim_status(payer, 100), sol_to_lamports(0.01)),
+        ));
+        accounts.push((
+            wrong_payer,
+            program_account(
+                program_id,
+                claim_status(Pubkey::new_unique(), 0),
+                sol_to_lamports(0.01),
+            ),
+        ));
+
+        let mut context = setup(accounts).await;
+        context.warp_to_epoch(1).unwrap();
+
+        // An account that isn't a ClaimStatus at all is skipped too, as is closing one twice.
+        let ix = close_claim_statuses(
+            &program_id,
+            &config_pubkey,
+            &[
+                (expired, payer),
+                (not_expired, payer),
+                (wrong_payer, payer),
+                (Pubkey::new_unique(), payer),
+                (expired, payer),
+            ],
+        );
+
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer],
+                blockhash,
+            ))
+            .await
+            .unwrap();
+
+        assert!(context
+            .banks_client
+            .get_account(expired)
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            context.banks_client.get_balance(payer).await.unwrap(),
+            sol_to_lamports(0.01)
+        );
+
+        for pubkey in [not_expired, wrong_payer] {
+            let account = context
+                .banks_client
+                .get_account(pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(account.owner, program_id);
+            assert_eq!(account.data.len(), ClaimStatus::SPACE);
+        }
+    }
+}
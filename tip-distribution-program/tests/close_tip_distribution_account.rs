@@ -0,0 +1,358 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::program_account::ProgramAccount;
+    use jito_tip_distribution_core::tip_distribution_account::TipDistributionAccount;
+    use jito_tip_distribution_sdk::sdk::{
+        close_tip_distribution_account, close_tip_distribution_accounts,
+    };
+    use solana_native_token::sol_to_lamports;
+    use solana_program_test::ProgramTestContext;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::fixtures::{
+        config, decode_program_account, program_account, program_account_data, program_id, setup,
+        tip_distribution_account,
+    };
+
+    /// Balance of every TDA the tests create: its rent plus the tips left unclaimed.
+    const TIP_DISTRIBUTION_ACCOUNT_LAMPORTS: u64 = 10_000_000;
+
+    struct TestAccounts {
+        program_id: Pubkey,
+        config: Pubkey,
+        expired_funds_account: Pubkey,
+        /// `(tip_distribution_account, validator_vote_account, rent_refund_destination)`
+        tip_distribution_accounts: Vec<(Pubkey, Pubkey, Pubkey)>,
+        rent_lamports: u64,
+        unclaimed_lamports: u64,
+    }
+
+    /// Starts a test validator with `count` TDAs created at epoch 0 that expire at epoch
+    /// `expires_at`, each refunding the rent to its own destination.
+    async fn start(count: usize, expires_at: u64) -> (ProgramTestContext, TestAccounts) {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let expired_funds_account = Pubkey::new_unique();
+        let (config_pubkey, config) = config(&program_id, &expired_funds_account);
+        accounts.push((
+            config_pubkey,
+            program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        let mut tip_distribution_accounts = Vec::with_capacity(count);
+        for _ in 0..count {
+            let validator_vote_account = Pubkey::new_unique();
+            let rent_refund_destination = Pubkey::new_unique();
+            let (pubkey, tda) = tip_distribution_account(
+                &program_id,
+                &validator_vote_account,
+                0,
+                expires_at,
+                &rent_refund_destination,
+                false,
+            );
+            accounts.push((
+                pubkey,
+                program_account(program_id, tda, TIP_DISTRIBUTION_ACCOUNT_LAMPORTS),
+            ));
+            tip_distribution_accounts.push((
+                pubkey,
+                validator_vote_account,
+                rent_refund_destination,
+            ));
+        }
+
+        let context = setup(accounts).await;
+        let rent_lamports = context
+            .banks_client
+            .get_rent()
+            .await
+            .unwrap()
+            .minimum_balance(TipDistributionAccount::SPACE);
+
+        (
+            context,
+            TestAccounts {
+                program_id,
+                config: config_pubkey,
+                expired_funds_account,
+                tip_distribution_accounts,
+                rent_lamports,
+                unclaimed_lamports: TIP_DISTRIBUTION_ACCOUNT_LAMPORTS - rent_lamports,
+            },
+        )
+    }
+
+    async fn balance(context: &mut ProgramTestContext, pubkey: Pubkey) -> u64 {
+        context.banks_client.get_balance(pubkey).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn close_tip_distribution_account_refunds_rent_to_destination() {
+        let (mut context, accounts) = start(1, 0).await;
+        context.warp_to_epoch(1).unwrap();
+
+        let (tda, validator_vote_account, rent_refund_destination) =
+            accounts.tip_distribution_accounts[0];
+
+        // The rent is only ever refunded to the destination the validator chose.
+        for (destination, should_succeed) in [
+            (validator_vote_account, false),
+            (rent_refund_destination, true),
+        ] {
+            let ix = close_tip_distribution_account(
+                &accounts.program_id,
+                &accounts.config,
+                &accounts.expired_funds_account,
+                &tda,
+                &destination,
+                &context.payer.pubkey(),
+                None,
+            );
+
+            let blockhash = context.get_new_latest_blockhash().await.unwrap();
+            let result = context
+                .banks_client
+                .process_transaction(Transaction::new_signed_with_payer(
+                    &[ix],
+                    Some(&context.payer.pubkey()),
+                    &[&context.payer],
+                    blockhash,
+                ))
+                .await;
+            assert_eq!(result.is_ok(), should_succeed);
+        }
+
+        assert!(context
+            .banks_client
+            .get_account(tda)
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            balance(&mut context, accounts.expired_funds_account).await,
+            accounts.unclaimed_lamports
+        );
+        assert_eq!(
+            balance(&mut context, rent_refund_destination).await,
+            accounts.rent_lamports
+        );
+        assert_eq!(balance(&mut context, validator_vote_account).await, 0);
+    }
+
+    #[tokio::test]
+    async fn close_tip_distribution_account_before_expiry_fails() {
+        let (mut context, accounts) = start(1, 1).await;
+        context.warp_to_epoch(1).unwrap();
+
+        let (tda, _, rent_refund_destination) = accounts.tip_distribution_accounts[0];
+        let ix = close_tip_distribution_account(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.expired_funds_account,
+            &tda,
+            &rent_refund_destination,
+            &context.payer.pubkey(),
+            None,
+        );
+
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        assert!(context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer],
+                blockhash,
+            ))
+            .await
+            .is_err());
+
+        assert_eq!(
+            balance(&mut context, tda).await,
+            TIP_DISTRIBUTION_ACCOUNT_LAMPORTS
+        );
+    }
+
+    #[tokio::test]
+    async fn close_tip_distribution_accounts_refunds_each_destination() {
+        let (mut context, accounts) = start(3, 0).await;
+        context.warp_to_epoch(1).unwrap();
+
+        // The last TDA is passed with its vote account instead of its destination and is skipped.
+        let mut pairs: Vec<(Pubkey, Pubkey)> = accounts
+            .tip_distribution_accounts
+            .iter()
+            .map(|(tda, _, rent_refund_destination)| (*tda, *rent_refund_destination))
+            .collect();
+        let (skipped_tda, skipped_vote_account, skipped_destination) =
+            accounts.tip_distribution_accounts[2];
+        pairs[2] = (skipped_tda, skipped_vote_account);
+
+        let ix = close_tip_distribution_accounts(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.expired_funds_account,
+            &pairs,
+        );
+
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer],
+                blockhash,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            balance(&mut context, accounts.expired_funds_account).await,
+            2 * accounts.unclaimed_lamports
+        );
+        for (tda, _, rent_refund_destination) in &accounts.tip_distribution_accounts[..2] {
+            assert!(context
+                .banks_client
+                .get_account(*tda)
+                .await
+                .unwrap()
+                .is_none());
+            assert_eq!(
+                balance(&mut context, *rent_refund_destination).await,
+                accounts.rent_lamports
+            );
+        }
+
+        assert_eq!(
+            balance(&mut context, skipped_tda).await,
+            TIP_DISTRIBUTION_ACCOUNT_LAMPORTS
+        );
+        assert_eq!(balance(&mut context, skipped_destination).await, 0);
+    }
+
+    #[tokio::test]
+    async fn close_tip_distribution_accounts_skips_unswept_token_vaults() {
+        let (mut context, accounts) = start(2, 0).await;
+
+        // The second TDA still has a token vault that must be swept first.
+        let (token_tda, _, token_destination) = accounts.tip_distribution_accounts[1];
+        let mut account = context
+            .banks_client
+            .get_account(token_tda)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut tip_distribution_account: TipDistributionAccount =
+            decode_program_account(&account.data);
+        tip_distribution_account.add_token_vault().unwrap();
+        account.data = program_account_data(tip_distribution_account);
+        context.set_account(&token_tda, &account.into());
+
+        context.warp_to_epoch(1).unwrap();
+        let pairs: Vec<(Pubkey, Pubkey)> = accounts
+            .tip_distribution_accounts
+            .iter()
+            .map(|(tda, _, rent_refund_destination)| (*tda, *rent_refund_destination))
+            .collect();
+        let ix = close_tip_distribution_accounts(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.expired_funds_account,
+            &pairs,
+        );
+
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer],
+                blockhash,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            balance(&mut context, accounts.expired_funds_account).await,
+            accounts.unclaimed_lamports
+        );
+        assert_eq!(
+            balance(&mut context, token_tda).await,
+            TIP_DISTRIBUTION_ACCOUNT_LAMPORTS
+        );
+        assert_eq!(balance(&mut context, token_destination).await, 0);
+    }
+
+    #[tokio::test]
+    async fn close_tip_distribution_accounts_skips_invalid_accounts() {
+        let (mut context, accounts) = start(3, 0).await;
+
+        // The TDA in the middle of the batch fails validation.
+        let (invalid_tda, _, invalid_destination) = accounts.tip_distribution_accounts[1];
+        let mut account = context
+            .banks_client
+            .get_account(invalid_tda)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut tip_distribution_account: TipDistributionAccount =
+            decode_program_account(&account.data);
+        tip_distribution_account.merkle_root_upload_authority = Pubkey::default().to_bytes();
+        account.data = program_account_data(tip_distribution_account);
+        context.set_account(&invalid_tda, &account.into());
+
+        context.warp_to_epoch(1).unwrap();
+        let pairs: Vec<(Pubkey, Pubkey)> = accounts
+            .tip_distribution_accounts
+            .iter()
+            .map(|(tda, _, rent_refund_destination)| (*tda, *rent_refund_destination))
+            .collect();
+        let ix = close_tip_distribution_accounts(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.expired_funds_account,
+            &pairs,
+        );
+
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer],
+                blockhash,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            balance(&mut context, accounts.expired_funds_account).await,
+            2 * accounts.unclaimed_lamports
+        );
+        for index in [0, 2] {
+            let (tda, _, rent_refund_destination) = accounts.tip_distribution_accounts[index];
+            assert!(context
+                .banks_client
+                .get_account(tda)
+                .await
+                .unwrap()
+                .is_none());
+            assert_eq!(
+                balance(&mut context, rent_refund_destination).await,
+                accounts.rent_lamports
+            );
+        }
+        assert_eq!(
+            balance(&mut context, invalid_tda).await,
+            TIP_DISTRIBUTION_ACCOUNT_LAMPORTS
+        );
+        assert_eq!(balance(&mut context, invalid_destination).await, 0);
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::program_account::ProgramAccount;
+    use jito_tip_distribution_core::{
+        claim_status::ClaimStatus, config::Config,
+        merkle_root_upload_config::MerkleRootUploadConfig,
+        tip_distribution_account::TipDistributionAccount,
+    };
+    use jito_tip_distribution_sdk::instruction::JitoTipDistributionInstruction;
+
+    #[test]
+    fn instruction_discriminators_match_anchor() {
+        let expected = [
+            (
+                JitoTipDistributionInstruction::INITIALIZE,
+                [175, 175, 109, 31, 13, 152, 155, 237],
+            ),
+            (
+                JitoTipDistributionInstruction::INITIALIZE_TIP_DISTRIBUTION_ACCOUNT,
+                [120, 191, 25, 182, 111, 49, 179, 55],
+            ),
+            (
+                JitoTipDistributionInstruction::UPDATE_CONFIG,
+                [29, 158, 252, 191, 10, 83, 219, 99],
+            ),
+            (
+                JitoTipDistributionInstruction::UPLOAD_MERKLE_ROOT,
+                [70, 3, 110, 29, 199, 190, 205, 176],
+            ),
+            (
+                JitoTipDistributionInstruction::CLOSE_CLAIM_STATUS,
+                [163, 214, 191, 165, 245, 188, 17, 185],
+            ),
+            (
+                JitoTipDistributionInstruction::CLOSE_TIP_DISTRIBUTION_ACCOUNT,
+                [47, 136, 208, 190, 125, 243, 74, 227],
+            ),
+            (
+                JitoTipDistributionInstruction::CLAIM,
+                [62, 198, 214, 193, 213, 159, 108, 210],
+            ),
+            (
+                JitoTipDistributionInstruction::INITIALIZE_MERKLE_ROOT_UPLOAD_CONFIG,
+                [232, 87, 72, 14, 89, 40, 40, 27],
+            ),
+            (
+                JitoTipDistributionInstruction::UPDATE_MERKLE_ROOT_UPLOAD_CONFIG,
+                [128, 227, 159, 139, 176, 128, 118, 2],
+            ),
+            (
+                JitoTipDistributionInstruction::MIGRATE_TDA_MERKLE_ROOT_UPLOAD_AUTHORITY,
+                [13, 226, 163, 144, 56, 202, 214, 23],
+            ),
+        ];
+
+        for (derived, anchor) in expected {
+            assert_eq!(derived, anchor);
+        }
+    }
+
+    #[test]
+    fn account_discriminators_match_anchor() {
+        assert_eq!(
+            Config::DISCRIMINATOR,
+            [155, 12, 170, 224, 30, 250, 204, 130]
+        );
+        assert_eq!(
+            TipDistributionAccount::DISCRIMINATOR,
+            [85, 64, 113, 198, 234, 94, 120, 123]
+        );
+        assert_eq!(
+            ClaimStatus::DISCRIMINATOR,
+            [22, 183, 249, 157, 247, 95, 150, 96]
+        );
+        assert_eq!(
+            MerkleRootUploadConfig::DISCRIMINATOR,
+            [213, 125, 30, 192, 25, 121, 87, 33]
+        );
+    }
+
+    #[test]
+    fn decodes_derived_discriminators() {
+        assert_eq!(
+            JitoTipDistributionInstruction::try_from_slice(
+                &JitoTipDistributionInstruction::CLOSE_CLAIM_STATUS
+            ),
+            Ok(JitoTipDistributionInstruction::CloseClaimStatus)
+        );
+        assert!(JitoTipDistributionInstruction::try_from_slice(&[0; 7]).is_err());
+    }
+}
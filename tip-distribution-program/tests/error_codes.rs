@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_distribution_sdk::error::TipDistributionError;
+    use pinocchio::program_error::ProgramError;
+
+    #[test]
+    fn custom_codes_round_trip() {
+        for error in TipDistributionError::ALL {
+            let ProgramError::Custom(code) = ProgramError::from(error.clone()) else {
+                panic!("{error:?} isn't a custom error");
+            };
+            assert_eq!(TipDistributionError::try_from(code), Ok(error));
+        }
+    }
+
+    #[test]
+    fn unknown_code_is_handed_back() {
+        let code = TipDistributionError::ALL.len() as u32;
+        assert_eq!(TipDistributionError::try_from(code), Err(code));
+    }
+}
@@ -1,3 +1,244 @@
-pub struct TestBuilder {
-    context: ProgramTestContext,
+use jito_tip_core::{
+    program_account::{ProgramAccount, DISCRIMINATOR_LEN},
+    versioned_account::VersionedAccount,
+};
+use jito_tip_distribution_core::{
+    config::Config, merkle_root_upload_config::MerkleRootUploadConfig,
+    priority_fee_distribution_account::PriorityFeeDistributionAccount,
+    tip_distribution_account::TipDistributionAccount,
+};
+use jito_tip_distribution_sdk::sdk::TOKEN_PROGRAM_ID;
+use solana_account::Account;
+use solana_instruction::error::InstructionError;
+use solana_native_token::sol_to_lamports;
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_transaction_error::TransactionError;
+
+/// The tip distribution program under test.
+pub fn program_id() -> Pubkey {
+    Pubkey::new_from_array(jito_tip_distribution_program::id())
+}
+
+/// Starts the program with `accounts` in place.
+pub async fn setup(accounts: Vec<(Pubkey, Account)>) -> ProgramTestContext {
+    let mut program_test = ProgramTest::new("jito_tip_distribution_program", program_id(), None);
+    for (pubkey, account) in accounts {
+        program_test.add_account(pubkey, account);
+    }
+    program_test.start_with_context().await
+}
+
+/// Serializes `value` the way the program lays out its accounts.
+pub fn program_account_data<T: ProgramAccount>(value: T) -> Vec<u8> {
+    let mut data = vec![0; T::SPACE];
+    data[..DISCRIMINATOR_LEN].copy_from_slice(&T::DISCRIMINATOR);
+    unsafe {
+        core::ptr::write_unaligned(data[DISCRIMINATOR_LEN..].as_mut_ptr() as *mut T, value);
+    }
+    data
+}
+
+/// An account owned by `program_id` holding `value` and `lamports`.
+pub fn program_account<T: ProgramAccount>(program_id: Pubkey, value: T, lamports: u64) -> Account {
+    Account {
+        lamports,
+        data: program_account_data(value),
+        owner: program_id,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// A config at its PDA sending expired funds to `expired_funds_account`.
+pub fn config(program_id: &Pubkey, expired_funds_account: &Pubkey) -> (Pubkey, Config) {
+    let (config_pubkey, bump) = Pubkey::find_program_address(&[Config::SEED], program_id);
+    let config = Config {
+        authority: Pubkey::new_unique().to_bytes(),
+        expired_funds_account: expired_funds_account.to_bytes(),
+        num_epochs_valid: 3,
+        max_validator_commission_bps: 10_000,
+        bump,
+        version: Config::VERSION,
+        merkle_root_upload_deadline_epochs: 0,
+    };
+
+    (config_pubkey, config)
+}
+
+/// A [MerkleRootUploadConfig] at its PDA without permitted uploaders.
+pub fn merkle_root_upload_config(
+    program_id: &Pubkey,
+    override_authority: &Pubkey,
+) -> (Pubkey, MerkleRootUploadConfig) {
+    let (pubkey, bump) = Pubkey::find_program_address(
+        &[MerkleRootUploadConfig::ROOT_UPLOAD_CONFIG_SEED],
+        program_id,
+    );
+    let mut merkle_root_upload_config = MerkleRootUploadConfig::default();
+    merkle_root_upload_config.override_authority = override_authority.to_bytes();
+    merkle_root_upload_config.original_upload_authority = Pubkey::new_unique().to_bytes();
+    merkle_root_upload_config.bump = bump;
+    merkle_root_upload_config.version = MerkleRootUploadConfig::VERSION;
+
+    (pubkey, merkle_root_upload_config)
+}
+
+/// A [TipDistributionAccount] created at `epoch` that can be claimed from until `expires_at`.
+pub fn tip_distribution_account(
+    program_id: &Pubkey,
+    validator_vote_account: &Pubkey,
+    epoch: u64,
+    expires_at: u64,
+    rent_refund_destination: &Pubkey,
+    roll_over_unclaimed_funds: bool,
+) -> (Pubkey, TipDistributionAccount) {
+    let epoch_bytes = epoch.to_le_bytes();
+    let (pubkey, bump) = Pubkey::find_program_address(
+        &[
+            TipDistributionAccount::SEED,
+            validator_vote_account.as_ref(),
+            &epoch_bytes,
+        ],
+        program_id,
+    );
+
+    let mut tip_distribution_account: TipDistributionAccount = unsafe { core::mem::zeroed() };
+    tip_distribution_account
+        .initialize(
+            validator_vote_account.to_bytes(),
+            epoch,
+            500,
+            Pubkey::new_unique().to_bytes(),
+            bump,
+            expires_at
+                .checked_sub(epoch)
+                .expect("expires_at is before epoch"),
+            rent_refund_destination.to_bytes(),
+            roll_over_unclaimed_funds,
+        )
+        .unwrap();
+    tip_distribution_account.version = TipDistributionAccount::VERSION;
+
+    (pubkey, tip_distribution_account)
+}
+
+/// A [PriorityFeeDistributionAccount] created at `epoch` without a merkle root that expires three
+/// epochs later.
+pub fn priority_fee_distribution_account(
+    program_id: &Pubkey,
+    validator_vote_account: &Pubkey,
+    merkle_root_upload_authority: &Pubkey,
+    epoch: u64,
+) -> (Pubkey, PriorityFeeDistributionAccount) {
+    let (pubkey, bump) = Pubkey::find_program_address(
+        &[
+            PriorityFeeDistributionAccount::SEED,
+            validator_vote_account.as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        program_id,
+    );
+
+    let mut priority_fee_distribution_account: PriorityFeeDistributionAccount =
+        unsafe { core::mem::zeroed() };
+    priority_fee_distribution_account
+        .initialize(
+            validator_vote_account.to_bytes(),
+            epoch,
+            500,
+            merkle_root_upload_authority.to_bytes(),
+            bump,
+            3,
+        )
+        .unwrap();
+
+    (pubkey, priority_fee_distribution_account)
+}
+
+/// Like [program_account], but laid out like version 0 of `T`: truncated to [VersionedAccount::V0_SPACE]
+/// without a version byte.
+pub fn v0_program_account<T: VersionedAccount>(
+    program_id: Pubkey,
+    value: T,
+    lamports: u64,
+) -> Account {
+    let mut account = program_account(program_id, value, lamports);
+    account.data.truncate(T::V0_SPACE);
+    if let Some(version) = account.data.get_mut(T::VERSION_INDEX) {
+        *version = 0;
+    }
+    account
+}
+
+/// A vote account in the current `VoteStateVersions` layout, of which only the node identity and
+/// the withdrawer are filled in.
+pub fn vote_account(node_pubkey: &Pubkey, authorized_withdrawer: &Pubkey) -> Account {
+    // `u32` version tag, node pubkey, authorized withdrawer and the rest of the vote state.
+    let mut data = vec![0; 3762];
+    data[..4].copy_from_slice(&2u32.to_le_bytes());
+    data[4..36].copy_from_slice(node_pubkey.as_ref());
+    data[36..68].copy_from_slice(authorized_withdrawer.as_ref());
+
+    Account {
+        lamports: sol_to_lamports(0.03),
+        data,
+        owner: solana_sdk_ids::vote::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// An initialized SPL token mint without authorities.
+pub fn mint_account(supply: u64) -> Account {
+    // `COption<Pubkey>` mint authority, supply, decimals, is_initialized and freeze authority.
+    let mut data = vec![0; 82];
+    data[36..44].copy_from_slice(&supply.to_le_bytes());
+    data[45] = 1;
+
+    Account {
+        lamports: sol_to_lamports(0.01),
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// An initialized SPL token account of `mint` owned by `owner` and holding `amount` tokens.
+pub fn token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+    // Mint, owner, amount, `COption<Pubkey>` delegate, state and the optional fields after it.
+    let mut data = vec![0; 165];
+    data[..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    data[108] = 1;
+
+    Account {
+        lamports: sol_to_lamports(0.01),
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Reads the token balance of an SPL token account.
+pub fn token_amount(data: &[u8]) -> u64 {
+    u64::from_le_bytes(data[64..72].try_into().unwrap())
+}
+
+/// Reads the `T` stored in the data of a program account.
+pub fn decode_program_account<T: ProgramAccount>(data: &[u8]) -> T {
+    assert_eq!(data.len(), T::SPACE);
+    assert_eq!(data[..DISCRIMINATOR_LEN], T::DISCRIMINATOR);
+    unsafe { core::ptr::read_unaligned(data[DISCRIMINATOR_LEN..].as_ptr() as *const T) }
+}
+
+/// Returns the custom program error code a transaction failed with, if any.
+pub fn custom_error(error: BanksClientError) -> Option<u32> {
+    match error.unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(code),
+        _ => None,
+    }
 }
@@ -0,0 +1,242 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::program_account::ProgramAccount;
+    use jito_tip_distribution_core::{
+        claim_status::ClaimStatus, merkle_proof, merkle_root::MerkleRoot,
+        tip_distribution_account::TipDistributionAccount,
+    };
+    use jito_tip_distribution_sdk::{
+        error::TipDistributionError,
+        sdk::{claim, upload_merkle_root},
+    };
+    use solana_account::AccountSharedData;
+    use solana_instruction::Instruction;
+    use solana_keypair::Keypair;
+    use solana_native_token::sol_to_lamports;
+    use solana_program_test::{BanksClientError, ProgramTestContext};
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::fixtures::{
+        config, custom_error, decode_program_account, program_account, program_account_data,
+        program_id, setup, tip_distribution_account,
+    };
+
+    /// Balance of the TDA the tests create: its rent plus the tips to distribute.
+    const TIP_DISTRIBUTION_ACCOUNT_LAMPORTS: u64 = 10_000_000;
+
+    /// Lamports the single claimant of the uploaded merkle tree is entitled to.
+    const CLAIM_AMOUNT: u64 = 1_000_000;
+
+    struct TestAccounts {
+        program_id: Pubkey,
+        config: Pubkey,
+        tip_distribution_account: Pubkey,
+        validator_vote_account: Pubkey,
+        merkle_root_upload_authority: Keypair,
+        claimant: Keypair,
+        /// Lamports of the TDA above its rent-exempt minimum.
+        unclaimed_lamports: u64,
+    }
+
+    /// Starts a test validator with a TDA created at epoch 0 without a merkle root.
+    async fn start() -> (ProgramTestContext, TestAccounts) {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let (config_pubkey, config) = config(&program_id, &Pubkey::new_unique());
+        accounts.push((
+            config_pubkey,
+            program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        let claimant = Keypair::new();
+        let merkle_root_upload_authority = Keypair::new();
+        let validator_vote_account = Pubkey::new_unique();
+        let (tip_distribution_account_pubkey, mut tip_distribution_account) =
+            tip_distribution_account(
+                &program_id,
+                &validator_vote_account,
+                0,
+                3,
+                &validator_vote_account,
+                false,
+            );
+        tip_distribution_account.merkle_root_upload_authority =
+            merkle_root_upload_authority.pubkey().to_bytes();
+        accounts.push((
+            tip_distribution_account_pubkey,
+            program_account(
+                program_id,
+                tip_distribution_account,
+                TIP_DISTRIBUTION_ACCOUNT_LAMPORTS,
+            ),
+        ));
+
+        let context = setup(accounts).await;
+        let rent_lamports = context
+            .banks_client
+            .get_rent()
+            .await
+            .unwrap()
+            .minimum_balance(TipDistributionAccount::SPACE);
+
+        (
+            context,
+            TestAccounts {
+                program_id,
+                config: config_pubkey,
+                tip_distribution_account: tip_distribution_account_pubkey,
+                validator_vote_account,
+                merkle_root_upload_authority,
+                claimant,
+                unclaimed_lamports: TIP_DISTRIBUTION_ACCOUNT_LAMPORTS - rent_lamports,
+            },
+        )
+    }
+
+    /// Sets a merkle root for a single claimant of [CLAIM_AMOUNT] that promises `max_total_claim`
+    /// lamports, bypassing the checks of `UploadMerkleRoot`.
+    async fn set_merkle_root(
+        context: &mut ProgramTestContext,
+        accounts: &TestAccounts,
+        max_total_claim: u64,
+    ) {
+        let mut account = context
+            .banks_client
+            .get_account(accounts.tip_distribution_account)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut tip_distribution_account: TipDistributionAccount =
+            decode_program_account(&account.data);
+        tip_distribution_account.set_merkle_root(&MerkleRoot {
+            root: merkle_proof::leaf(&accounts.claimant.pubkey().to_bytes(), CLAIM_AMOUNT),
+            max_total_claim,
+            max_num_nodes: 2,
+            total_funds_claimed: 0,
+            num_nodes_claimed: 0,
+        });
+        account.data = program_account_data(tip_distribution_account);
+        context.set_account(
+            &accounts.tip_distribution_account,
+            &AccountSharedData::from(account),
+        );
+    }
+
+    async fn process(
+        context: &mut ProgramTestContext,
+        ix: Instruction,
+        signer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer, signer],
+                blockhash,
+            ))
+            .await
+    }
+
+    fn upload(accounts: &TestAccounts, max_total_claim: u64) -> Instruction {
+        upload_merkle_root(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.tip_distribution_account,
+            &accounts.merkle_root_upload_authority.pubkey(),
+            None,
+            [1; 32],
+            max_total_claim,
+            10,
+        )
+    }
+
+    fn claim_ix(context: &ProgramTestContext, accounts: &TestAccounts) -> Instruction {
+        let (claim_status, bump) = Pubkey::find_program_address(
+            &[
+                ClaimStatus::SEED,
+                accounts.claimant.pubkey().as_ref(),
+                accounts.tip_distribution_account.as_ref(),
+            ],
+            &accounts.program_id,
+        );
+
+        claim(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.tip_distribution_account,
+            &accounts.claimant.pubkey(),
+            &claim_status,
+            &accounts.claimant.pubkey(),
+            &context.payer.pubkey(),
+            &accounts.validator_vote_account,
+            None,
+            bump,
+            CLAIM_AMOUNT,
+            &[],
+        )
+    }
+
+    #[tokio::test]
+    async fn upload_merkle_root_requires_funds_for_max_total_claim() {
+        let (mut context, accounts) = start().await;
+        context.warp_to_epoch(1).unwrap();
+
+        let error = process(
+            &mut context,
+            upload(&accounts, accounts.unclaimed_lamports + 1),
+            &accounts.merkle_root_upload_authority,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::InsufficientFundsForMaxClaim as u32)
+        );
+
+        process(
+            &mut context,
+            upload(&accounts, accounts.unclaimed_lamports),
+            &accounts.merkle_root_upload_authority,
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn claim_fails_if_remaining_claims_are_underfunded() {
+        let (mut context, accounts) = start().await;
+        set_merkle_root(&mut context, &accounts, accounts.unclaimed_lamports + 1).await;
+
+        let ix = claim_ix(&context, &accounts);
+        let error = process(&mut context, ix, &accounts.claimant)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::InsufficientFundsForRemainingClaims as u32)
+        );
+    }
+
+    #[tokio::test]
+    async fn claim_succeeds_if_remaining_claims_are_funded() {
+        let (mut context, accounts) = start().await;
+        set_merkle_root(&mut context, &accounts, accounts.unclaimed_lamports).await;
+
+        let ix = claim_ix(&context, &accounts);
+        process(&mut context, ix, &accounts.claimant).await.unwrap();
+
+        assert_eq!(
+            context
+                .banks_client
+                .get_balance(accounts.claimant.pubkey())
+                .await
+                .unwrap(),
+            CLAIM_AMOUNT
+        );
+    }
+}
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use jito_tip_core::program_account::DISCRIMINATOR_LEN;
     use jito_tip_distribution_core::config::Config;
     use jito_tip_distribution_sdk::{self, sdk::initialize_config};
     use solana_commitment_config::CommitmentLevel;
@@ -40,9 +41,21 @@ mod tests {
             .await
             .unwrap();
 
-        let (config_pubkey, _) = Pubkey::find_program_address(&[Config::SEED], &program_id);
+        let (config_pubkey, config_bump) =
+            Pubkey::find_program_address(&[Config::SEED], &program_id);
 
-        let ix = initialize_config(&program_id, &config_pubkey, &user_kp.pubkey());
+        let authority = Pubkey::new_unique();
+        let expired_funds_account = Pubkey::new_unique();
+        let ix = initialize_config(
+            &program_id,
+            &config_pubkey,
+            &user_kp.pubkey(),
+            &authority,
+            &expired_funds_account,
+            3,
+            10_000,
+            config_bump,
+        );
 
         let transaction = Transaction::new_signed_with_payer(
             &[ix],
@@ -51,10 +64,29 @@ mod tests {
             blockhash,
         );
 
-        let _tx_resp = context
+        context
             .banks_client
-            .send_transaction(transaction)
+            .process_transaction(transaction)
             .await
             .unwrap();
+
+        let data = context
+            .banks_client
+            .get_account(config_pubkey)
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        let config = unsafe {
+            core::ptr::read_unaligned(data[DISCRIMINATOR_LEN..].as_ptr() as *const Config)
+        };
+        assert_eq!(config.authority, authority.to_bytes());
+        assert_eq!(
+            config.expired_funds_account,
+            expired_funds_account.to_bytes()
+        );
+        assert_eq!(config.num_epochs_valid, 3);
+        assert_eq!(config.max_validator_commission_bps, 10_000);
+        assert_eq!(config.bump, config_bump);
     }
 }
@@ -0,0 +1,222 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_distribution_core::tip_distribution_account::TipDistributionAccount;
+    use jito_tip_distribution_sdk::{
+        error::TipDistributionError, sdk::initialize_tip_distribution_account,
+    };
+    use solana_account::Account;
+    use solana_instruction::Instruction;
+    use solana_keypair::Keypair;
+    use solana_native_token::sol_to_lamports;
+    use solana_program_test::{BanksClientError, ProgramTestContext};
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::fixtures::{
+        config, custom_error, decode_program_account, program_account, program_id, setup,
+        vote_account,
+    };
+
+    struct TestAccounts {
+        program_id: Pubkey,
+        config: Pubkey,
+        validator_vote_account: Pubkey,
+        node_identity: Keypair,
+        authorized_withdrawer: Keypair,
+        /// Signs without any authority over the vote account.
+        stranger: Keypair,
+    }
+
+    /// Starts a test validator with the config and a vote account, and funds every signer to pay
+    /// for the TipDistributionAccount.
+    async fn start() -> (ProgramTestContext, TestAccounts) {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let (config_pubkey, config) = config(&program_id, &Pubkey::new_unique());
+        accounts.push((
+            config_pubkey,
+            program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        let node_identity = Keypair::new();
+        let authorized_withdrawer = Keypair::new();
+        let stranger = Keypair::new();
+        let validator_vote_account = Pubkey::new_unique();
+        accounts.push((
+            validator_vote_account,
+            vote_account(&node_identity.pubkey(), &authorized_withdrawer.pubkey()),
+        ));
+        for signer in [&node_identity, &authorized_withdrawer, &stranger] {
+            accounts.push((
+                signer.pubkey(),
+                Account {
+                    lamports: sol_to_lamports(1.0),
+                    ..Account::default()
+                },
+            ));
+        }
+
+        let context = setup(accounts).await;
+
+        (
+            context,
+            TestAccounts {
+                program_id,
+                config: config_pubkey,
+                validator_vote_account,
+                node_identity,
+                authorized_withdrawer,
+                stranger,
+            },
+        )
+    }
+
+    /// The TipDistributionAccount of the vote account for epoch 0.
+    fn tip_distribution_account(accounts: &TestAccounts) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                TipDistributionAccount::SEED,
+                accounts.validator_vote_account.as_ref(),
+                &0u64.to_le_bytes(),
+            ],
+            &accounts.program_id,
+        )
+    }
+
+    fn initialize(
+        accounts: &TestAccounts,
+        signer: &Keypair,
+        rent_refund_destination: Option<&Pubkey>,
+        roll_over_unclaimed_funds: bool,
+    ) -> Instruction {
+        let (tip_distribution_account, bump) = tip_distribution_account(accounts);
+        initialize_tip_distribution_account(
+            &accounts.program_id,
+            &accounts.config,
+            &tip_distribution_account,
+            &accounts.validator_vote_account,
+            &signer.pubkey(),
+            &Pubkey::new_unique(),
+            500,
+            bump,
+            rent_refund_destination,
+            roll_over_unclaimed_funds,
+        )
+    }
+
+    async fn process(
+        context: &mut ProgramTestContext,
+        ix: Instruction,
+        signer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer, signer],
+                blockhash,
+            ))
+            .await
+    }
+
+    async fn get_tip_distribution_account(
+        context: &mut ProgramTestContext,
+        accounts: &TestAccounts,
+    ) -> TipDistributionAccount {
+        let data = context
+            .banks_client
+            .get_account(tip_distribution_account(accounts).0)
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        decode_program_account(&data)
+    }
+
+    #[tokio::test]
+    async fn initialize_tip_distribution_account_with_defaults_is_permissionless() {
+        let (mut context, accounts) = start().await;
+
+        let ix = initialize(&accounts, &accounts.stranger, None, false);
+        process(&mut context, ix, &accounts.stranger).await.unwrap();
+
+        let tip_distribution_account = get_tip_distribution_account(&mut context, &accounts).await;
+        assert_eq!(
+            tip_distribution_account.rent_refund_destination,
+            accounts.validator_vote_account.to_bytes()
+        );
+        assert!(!tip_distribution_account.roll_over_unclaimed_funds());
+    }
+
+    #[tokio::test]
+    async fn node_identity_can_set_rent_refund_destination() {
+        let (mut context, accounts) = start().await;
+        let rent_refund_destination = Pubkey::new_unique();
+
+        let ix = initialize(
+            &accounts,
+            &accounts.node_identity,
+            Some(&rent_refund_destination),
+            false,
+        );
+        process(&mut context, ix, &accounts.node_identity)
+            .await
+            .unwrap();
+
+        let tip_distribution_account = get_tip_distribution_account(&mut context, &accounts).await;
+        assert_eq!(
+            tip_distribution_account.rent_refund_destination,
+            rent_refund_destination.to_bytes()
+        );
+    }
+
+    #[tokio::test]
+    async fn authorized_withdrawer_can_roll_over_unclaimed_funds() {
+        let (mut context, accounts) = start().await;
+
+        let ix = initialize(&accounts, &accounts.authorized_withdrawer, None, true);
+        process(&mut context, ix, &accounts.authorized_withdrawer)
+            .await
+            .unwrap();
+
+        let tip_distribution_account = get_tip_distribution_account(&mut context, &accounts).await;
+        assert!(tip_distribution_account.roll_over_unclaimed_funds());
+    }
+
+    #[tokio::test]
+    async fn stranger_cannot_set_rent_refund_destination() {
+        let (mut context, accounts) = start().await;
+
+        let ix = initialize(
+            &accounts,
+            &accounts.stranger,
+            Some(&accounts.stranger.pubkey()),
+            false,
+        );
+        let error = process(&mut context, ix, &accounts.stranger)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::Unauthorized as u32)
+        );
+    }
+
+    #[tokio::test]
+    async fn stranger_cannot_roll_over_unclaimed_funds() {
+        let (mut context, accounts) = start().await;
+
+        let ix = initialize(&accounts, &accounts.stranger, None, true);
+        let error = process(&mut context, ix, &accounts.stranger)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::Unauthorized as u32)
+        );
+    }
+}
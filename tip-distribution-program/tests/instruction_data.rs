@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_distribution_sdk::{
+        instruction::JitoTipDistributionInstruction,
+        sdk::{
+            initialize_config, initialize_merkle_root_upload_config, update_config,
+            update_merkle_root_upload_config,
+        },
+    };
+    use solana_pubkey::Pubkey;
+
+    #[test]
+    fn decode_initialize() {
+        let authority = Pubkey::new_unique();
+        let expired_funds_account = Pubkey::new_unique();
+        let ix = initialize_config(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &authority,
+            &expired_funds_account,
+            3,
+            500,
+            254,
+        );
+
+        assert_eq!(
+            JitoTipDistributionInstruction::try_from_slice(&ix.data),
+            Ok(JitoTipDistributionInstruction::Initialize {
+                authority: authority.to_bytes(),
+                expired_funds_account: expired_funds_account.to_bytes(),
+                num_epochs_valid: 3,
+                max_validator_commission_bps: 500,
+                bump: 254,
+            })
+        );
+
+        // Truncated arguments are rejected instead of panicking.
+        assert!(
+            JitoTipDistributionInstruction::try_from_slice(&ix.data[..ix.data.len() - 1]).is_err()
+        );
+    }
+
+    #[test]
+    fn decode_update_config() {
+        let authority = Pubkey::new_unique();
+        let expired_funds_account = Pubkey::new_unique();
+
+        for merkle_root_upload_deadline_epochs in [None, Some(2)] {
+            let ix = update_config(
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &authority,
+                &expired_funds_account,
+                3,
+                500,
+                merkle_root_upload_deadline_epochs,
+            );
+
+            assert_eq!(
+                JitoTipDistributionInstruction::try_from_slice(&ix.data),
+                Ok(JitoTipDistributionInstruction::UpdateConfig {
+                    authority: authority.to_bytes(),
+                    expired_funds_account: expired_funds_account.to_bytes(),
+                    num_epochs_valid: 3,
+                    max_validator_commission_bps: 500,
+                    merkle_root_upload_deadline_epochs,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn decode_merkle_root_upload_config() {
+        let override_authority = Pubkey::new_unique();
+        let original_upload_authority = Pubkey::new_unique();
+
+        let ix = initialize_merkle_root_upload_config(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &override_authority,
+            &original_upload_authority,
+        );
+        assert_eq!(
+            JitoTipDistributionInstruction::try_from_slice(&ix.data),
+            Ok(
+                JitoTipDistributionInstruction::InitializeMerkleRootUploadConfig {
+                    authority: override_authority.to_bytes(),
+                    original_authority: original_upload_authority.to_bytes(),
+                }
+            )
+        );
+
+        let ix = update_merkle_root_upload_config(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &override_authority,
+            &original_upload_authority,
+        );
+        assert_eq!(
+            JitoTipDistributionInstruction::try_from_slice(&ix.data),
+            Ok(
+                JitoTipDistributionInstruction::UpdateMerkleRootUploadConfig {
+                    authority: override_authority.to_bytes(),
+                    original_authority: original_upload_authority.to_bytes(),
+                }
+            )
+        );
+        assert!(
+            JitoTipDistributionInstruction::try_from_slice(&ix.data[..ix.data.len() - 1]).is_err()
+        );
+    }
+}
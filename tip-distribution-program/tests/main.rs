@@ -0,0 +1,22 @@
+//! The program tests, built as a single binary that shares the fixtures.
+
+mod fixtures;
+
+mod claim_compute_units;
+mod claim_delegate;
+mod claim_token;
+mod close_claim_statuses;
+mod close_tip_distribution_account;
+mod discriminators;
+mod error_codes;
+mod funding_invariants;
+mod initialize;
+mod initialize_tip_distribution_account;
+mod instruction_data;
+mod merkle_proof;
+mod merkle_root_upload_deadline;
+mod migrate_account;
+mod migrate_tda_merkle_root_upload_authorities;
+mod permitted_uploaders;
+mod priority_fee_distribution_account;
+mod roll_over_unclaimed_funds;
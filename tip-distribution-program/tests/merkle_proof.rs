@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::hash::hashv;
+    use jito_tip_distribution_core::merkle_proof::{intermediate, leaf, verify};
+    use jito_tip_distribution_sdk::{
+        instruction::JitoTipDistributionInstruction,
+        sdk::{claim_priority_fee, transfer_priority_fees},
+    };
+    use solana_pubkey::Pubkey;
+
+    #[test]
+    fn hashv_matches_sha256() {
+        assert_eq!(
+            hashv(&[b"a", b"bc"]),
+            [
+                0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+                0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+                0xf2, 0x00, 0x15, 0xad,
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_proof() {
+        let leaves = [
+            leaf(&[1; 32], 100),
+            leaf(&[2; 32], 200),
+            leaf(&[3; 32], 300),
+            leaf(&[4; 32], 400),
+        ];
+        let left = intermediate(&leaves[0], &leaves[1]);
+        let right = intermediate(&leaves[2], &leaves[3]);
+        let root = intermediate(&left, &right);
+
+        assert!(verify(&[leaves[1], right], &root, leaves[0]));
+        assert!(verify(&[leaves[2], left], &root, leaves[3]));
+
+        // Wrong amount, wrong sibling and truncated proof.
+        assert!(!verify(&[leaves[1], right], &root, leaf(&[1; 32], 101)));
+        assert!(!verify(&[leaves[2], right], &root, leaves[0]));
+        assert!(!verify(&[leaves[1]], &root, leaves[0]));
+    }
+
+    #[test]
+    fn decode_claim() {
+        let proof = [[7; 32], [8; 32]];
+        let data = [
+            JitoTipDistributionInstruction::CLAIM.as_slice(),
+            &[254],
+            &42u64.to_le_bytes(),
+            &2u32.to_le_bytes(),
+            proof.as_flattened(),
+        ]
+        .concat();
+
+        assert_eq!(
+            JitoTipDistributionInstruction::try_from_slice(&data),
+            Ok(JitoTipDistributionInstruction::Claim {
+                bump: 254,
+                amount: 42,
+                proof: &proof,
+            })
+        );
+
+        // The proof length must match the number of trailing nodes.
+        assert!(JitoTipDistributionInstruction::try_from_slice(&data[..data.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn decode_priority_fee_instructions() {
+        let proof = [[7; 32], [9; 32]];
+        let key = Pubkey::new_unique();
+
+        let ix = claim_priority_fee(
+            &key, &key, &key, &key, &key, &key, &key, &key, None, 252, 42, &proof,
+        );
+        assert_eq!(
+            JitoTipDistributionInstruction::try_from_slice(&ix.data),
+            Ok(JitoTipDistributionInstruction::ClaimPriorityFee {
+                bump: 252,
+                amount: 42,
+                proof: &proof,
+            })
+        );
+
+        let ix = transfer_priority_fees(&key, &key, &key, &key, 1_000);
+        assert_eq!(
+            JitoTipDistributionInstruction::try_from_slice(&ix.data),
+            Ok(JitoTipDistributionInstruction::TransferPriorityFees { lamports: 1_000 })
+        );
+    }
+}
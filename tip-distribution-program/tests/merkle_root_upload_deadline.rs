@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_distribution_sdk::{error::TipDistributionError, sdk::upload_merkle_root};
+    use solana_keypair::Keypair;
+    use solana_native_token::sol_to_lamports;
+    use solana_program_test::{BanksClientError, ProgramTestContext};
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::fixtures::{
+        config, custom_error, merkle_root_upload_config, program_account, program_id, setup,
+        tip_distribution_account,
+    };
+
+    struct TestAccounts {
+        program_id: Pubkey,
+        config: Pubkey,
+        merkle_root_upload_config: Pubkey,
+        tip_distribution_account: Pubkey,
+        merkle_root_upload_authority: Keypair,
+        override_authority: Keypair,
+    }
+
+    /// Starts a test validator with a TDA created at epoch 0 whose merkle root upload deadline
+    /// passes after epoch 1.
+    async fn start() -> (ProgramTestContext, TestAccounts) {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let (config_pubkey, mut config) = config(&program_id, &Pubkey::new_unique());
+        config.merkle_root_upload_deadline_epochs = 1;
+        accounts.push((
+            config_pubkey,
+            program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        let override_authority = Keypair::new();
+        let (merkle_root_upload_config_pubkey, merkle_root_upload_config) =
+            merkle_root_upload_config(&program_id, &override_authority.pubkey());
+        accounts.push((
+            merkle_root_upload_config_pubkey,
+            program_account(program_id, merkle_root_upload_config, sol_to_lamports(0.01)),
+        ));
+
+        let merkle_root_upload_authority = Keypair::new();
+        let validator_vote_account = Pubkey::new_unique();
+        let (tip_distribution_account_pubkey, mut tip_distribution_account) =
+            tip_distribution_account(
+                &program_id,
+                &validator_vote_account,
+                0,
+                3,
+                &validator_vote_account,
+                false,
+            );
+        tip_distribution_account.merkle_root_upload_authority =
+            merkle_root_upload_authority.pubkey().to_bytes();
+        accounts.push((
+            tip_distribution_account_pubkey,
+            program_account(program_id, tip_distribution_account, sol_to_lamports(0.01)),
+        ));
+
+        let context = setup(accounts).await;
+
+        (
+            context,
+            TestAccounts {
+                program_id,
+                config: config_pubkey,
+                merkle_root_upload_config: merkle_root_upload_config_pubkey,
+                tip_distribution_account: tip_distribution_account_pubkey,
+                merkle_root_upload_authority,
+                override_authority,
+            },
+        )
+    }
+
+    async fn upload(
+        context: &mut ProgramTestContext,
+        accounts: &TestAccounts,
+        uploader: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let ix = upload_merkle_root(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.tip_distribution_account,
+            &uploader.pubkey(),
+            Some(&accounts.merkle_root_upload_config),
+            [1; 32],
+            100,
+            10,
+        );
+
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer, uploader],
+                blockhash,
+            ))
+            .await
+    }
+
+    #[tokio::test]
+    async fn override_authority_can_only_upload_after_the_deadline() {
+        let (mut context, accounts) = start().await;
+        context.warp_to_epoch(1).unwrap();
+
+        let error = upload(&mut context, &accounts, &accounts.override_authority)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::Unauthorized as u32)
+        );
+        upload(
+            &mut context,
+            &accounts,
+            &accounts.merkle_root_upload_authority,
+        )
+        .await
+        .unwrap();
+
+        context.warp_to_epoch(2).unwrap();
+
+        let error = upload(
+            &mut context,
+            &accounts,
+            &accounts.merkle_root_upload_authority,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::MerkleRootUploadDeadlinePassed as u32)
+        );
+        upload(&mut context, &accounts, &accounts.override_authority)
+            .await
+            .unwrap();
+    }
+}
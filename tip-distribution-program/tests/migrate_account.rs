@@ -0,0 +1,395 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::{program_account::ProgramAccount, versioned_account::VersionedAccount};
+    use jito_tip_distribution_core::{
+        claim_status::ClaimStatus, config::Config, merkle_proof, merkle_root::MerkleRoot,
+        merkle_root_upload_config::MerkleRootUploadConfig,
+        tip_distribution_account::TipDistributionAccount,
+    };
+    use jito_tip_distribution_sdk::sdk::{claim, close_tip_distribution_account, migrate_account};
+    use solana_instruction::Instruction;
+    use solana_keypair::Keypair;
+    use solana_native_token::sol_to_lamports;
+    use solana_program_test::ProgramTestContext;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::fixtures::{
+        config, decode_program_account, merkle_root_upload_config, program_account, program_id,
+        setup, tip_distribution_account, v0_program_account,
+    };
+
+    /// Lamports the single claimant of the version 0 TDA's merkle tree is entitled to.
+    const CLAIM_AMOUNT: u64 = 1_000_000;
+
+    struct TestAccounts {
+        program_id: Pubkey,
+        config: Pubkey,
+        tip_distribution_account: Pubkey,
+        validator_vote_account: Pubkey,
+        claim_status: Pubkey,
+        claim_status_payer: Pubkey,
+        merkle_root_upload_config: Pubkey,
+        override_authority: Pubkey,
+    }
+
+    /// Starts a test validator with a version 0 account of every versioned type.
+    async fn start() -> (ProgramTestContext, TestAccounts) {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let (config_pubkey, config) = config(&program_id, &Pubkey::new_unique());
+        accounts.push((
+            config_pubkey,
+            v0_program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        // The fields added by version 1 are truncated away.
+        let validator_vote_account = Pubkey::new_unique();
+        let (tip_distribution_account_pubkey, tip_distribution_account) = tip_distribution_account(
+            &program_id,
+            &validator_vote_account,
+            0,
+            3,
+            &Pubkey::new_unique(),
+            true,
+        );
+        accounts.push((
+            tip_distribution_account_pubkey,
+            v0_program_account(program_id, tip_distribution_account, sol_to_lamports(0.01)),
+        ));
+
+        let claim_status_payer = Pubkey::new_unique();
+        let claim_status_pubkey = Pubkey::new_unique();
+        accounts.push((
+            claim_status_pubkey,
+            v0_program_account(
+                program_id,
+                ClaimStatus {
+                    is_claimed: true,
+                    claimant: Pubkey::new_unique().to_bytes(),
+                    claim_status_payer: claim_status_payer.to_bytes(),
+                    slot_claimed_at: 1,
+                    amount: 2,
+                    expires_at: 3,
+                    bump: 255,
+                    version: 0,
+                },
+                sol_to_lamports(0.01),
+            ),
+        ));
+
+        let override_authority = Pubkey::new_unique();
+        let (merkle_root_upload_config_pubkey, merkle_root_upload_config) =
+            merkle_root_upload_config(&program_id, &override_authority);
+        accounts.push((
+            merkle_root_upload_config_pubkey,
+            v0_program_account(program_id, merkle_root_upload_config, sol_to_lamports(0.01)),
+        ));
+
+        let context = setup(accounts).await;
+
+        (
+            context,
+            TestAccounts {
+                program_id,
+                config: config_pubkey,
+                tip_distribution_account: tip_distribution_account_pubkey,
+                validator_vote_account,
+                claim_status: claim_status_pubkey,
+                claim_status_payer,
+                merkle_root_upload_config: merkle_root_upload_config_pubkey,
+                override_authority,
+            },
+        )
+    }
+
+    struct V0TipDistributionAccount {
+        program_id: Pubkey,
+        config: Pubkey,
+        expired_funds_account: Pubkey,
+        tip_distribution_account: Pubkey,
+        validator_vote_account: Pubkey,
+        claimant: Keypair,
+    }
+
+    /// Starts a test validator with a current config and a version 0 TDA created at epoch 0 whose
+    /// merkle root pays [CLAIM_AMOUNT] to a single claimant.
+    async fn start_v0_tip_distribution_account() -> (ProgramTestContext, V0TipDistributionAccount) {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let expired_funds_account = Pubkey::new_unique();
+        let (config_pubkey, config) = config(&program_id, &expired_funds_account);
+        accounts.push((
+            config_pubkey,
+            program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        let claimant = Keypair::new();
+        let validator_vote_account = Pubkey::new_unique();
+        let (tip_distribution_account_pubkey, mut tip_distribution_account) =
+            tip_distribution_account(
+                &program_id,
+                &validator_vote_account,
+                0,
+                3,
+                &Pubkey::new_unique(),
+                true,
+            );
+        tip_distribution_account.set_merkle_root(&MerkleRoot {
+            root: merkle_proof::leaf(&claimant.pubkey().to_bytes(), CLAIM_AMOUNT),
+            max_total_claim: CLAIM_AMOUNT,
+            max_num_nodes: 1,
+            total_funds_claimed: 0,
+            num_nodes_claimed: 0,
+        });
+        accounts.push((
+            tip_distribution_account_pubkey,
+            v0_program_account(program_id, tip_distribution_account, sol_to_lamports(0.01)),
+        ));
+
+        let context = setup(accounts).await;
+
+        (
+            context,
+            V0TipDistributionAccount {
+                program_id,
+                config: config_pubkey,
+                expired_funds_account,
+                tip_distribution_account: tip_distribution_account_pubkey,
+                validator_vote_account,
+                claimant,
+            },
+        )
+    }
+
+    async fn process(
+        context: &mut ProgramTestContext,
+        ix: Instruction,
+        signer: &Keypair,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer, signer],
+                blockhash,
+            ))
+            .await
+    }
+
+    async fn migrate(
+        context: &mut ProgramTestContext,
+        program_id: &Pubkey,
+        accounts: &[Pubkey],
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let ixs: Vec<_> = accounts
+            .iter()
+            .map(|account| migrate_account(program_id, account, &context.payer.pubkey()))
+            .collect();
+
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &ixs,
+                Some(&context.payer.pubkey()),
+                &[&context.payer],
+                blockhash,
+            ))
+            .await
+    }
+
+    async fn account_data(context: &mut ProgramTestContext, pubkey: Pubkey) -> Vec<u8> {
+        context
+            .banks_client
+            .get_account(pubkey)
+            .await
+            .unwrap()
+            .unwrap()
+            .data
+    }
+
+    #[tokio::test]
+    async fn migrate_account_upgrades_v0_accounts() {
+        let (mut context, accounts) = start().await;
+        let versioned_accounts = [
+            accounts.config,
+            accounts.tip_distribution_account,
+            accounts.claim_status,
+            accounts.merkle_root_upload_config,
+        ];
+
+        migrate(&mut context, &accounts.program_id, &versioned_accounts)
+            .await
+            .unwrap();
+
+        let data = account_data(&mut context, accounts.config).await;
+        assert_eq!(data[Config::VERSION_INDEX], Config::VERSION);
+        let config: Config = decode_program_account(&data);
+        assert_eq!(config.num_epochs_valid, 3);
+
+        let data = account_data(&mut context, accounts.tip_distribution_account).await;
+        assert_eq!(data.len(), TipDistributionAccount::SPACE);
+        assert_eq!(
+            data[TipDistributionAccount::VERSION_INDEX],
+            TipDistributionAccount::VERSION
+        );
+        let tip_distribution_account: TipDistributionAccount = decode_program_account(&data);
+        assert_eq!(
+            tip_distribution_account.validator_vote_account,
+            accounts.validator_vote_account.to_bytes()
+        );
+        assert_eq!(tip_distribution_account.epoch_created_at(), 0);
+        assert_eq!(tip_distribution_account.expires_at(), 3);
+        // Backfilled like the Anchor program behaved.
+        assert_eq!(
+            tip_distribution_account.rent_refund_destination,
+            accounts.validator_vote_account.to_bytes()
+        );
+        assert!(!tip_distribution_account.roll_over_unclaimed_funds());
+
+        let data = account_data(&mut context, accounts.claim_status).await;
+        assert_eq!(data[ClaimStatus::VERSION_INDEX], ClaimStatus::VERSION);
+        let claim_status: ClaimStatus = decode_program_account(&data);
+        assert_eq!(
+            claim_status.claim_status_payer,
+            accounts.claim_status_payer.to_bytes()
+        );
+        assert_eq!(claim_status.amount, 2);
+
+        let data = account_data(&mut context, accounts.merkle_root_upload_config).await;
+        assert_eq!(data.len(), MerkleRootUploadConfig::SPACE);
+        assert_eq!(
+            data[MerkleRootUploadConfig::VERSION_INDEX],
+            MerkleRootUploadConfig::VERSION
+        );
+        let merkle_root_upload_config: MerkleRootUploadConfig = decode_program_account(&data);
+        assert_eq!(
+            merkle_root_upload_config.override_authority,
+            accounts.override_authority.to_bytes()
+        );
+        assert!(merkle_root_upload_config.permitted_uploaders().is_empty());
+
+        // Migrating again leaves the accounts untouched.
+        let before = account_data(&mut context, accounts.tip_distribution_account).await;
+        migrate(&mut context, &accounts.program_id, &versioned_accounts)
+            .await
+            .unwrap();
+        assert_eq!(
+            account_data(&mut context, accounts.tip_distribution_account).await,
+            before
+        );
+    }
+
+    #[tokio::test]
+    async fn migrate_account_rejects_other_accounts() {
+        let (mut context, accounts) = start().await;
+
+        let payer = context.payer.pubkey();
+        assert!(migrate(&mut context, &accounts.program_id, &[payer])
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn claim_migrates_a_v0_tip_distribution_account() {
+        let (mut context, accounts) = start_v0_tip_distribution_account().await;
+
+        let (claim_status, bump) = Pubkey::find_program_address(
+            &[
+                ClaimStatus::SEED,
+                accounts.claimant.pubkey().as_ref(),
+                accounts.tip_distribution_account.as_ref(),
+            ],
+            &accounts.program_id,
+        );
+        let ix = claim(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.tip_distribution_account,
+            &accounts.claimant.pubkey(),
+            &claim_status,
+            &accounts.claimant.pubkey(),
+            &context.payer.pubkey(),
+            &accounts.validator_vote_account,
+            None,
+            bump,
+            CLAIM_AMOUNT,
+            &[],
+        );
+        process(&mut context, ix, &accounts.claimant).await.unwrap();
+
+        let claimant = context
+            .banks_client
+            .get_account(accounts.claimant.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(claimant.lamports, CLAIM_AMOUNT);
+
+        let data = account_data(&mut context, accounts.tip_distribution_account).await;
+        assert_eq!(data.len(), TipDistributionAccount::SPACE);
+        let tip_distribution_account: TipDistributionAccount = decode_program_account(&data);
+        assert_eq!(
+            tip_distribution_account.version,
+            TipDistributionAccount::VERSION
+        );
+        assert_eq!(
+            tip_distribution_account.rent_refund_destination,
+            accounts.validator_vote_account.to_bytes()
+        );
+        assert_eq!(
+            tip_distribution_account
+                .merkle_root()
+                .unwrap()
+                .total_funds_claimed,
+            CLAIM_AMOUNT
+        );
+    }
+
+    #[tokio::test]
+    async fn close_tip_distribution_account_closes_a_v0_account() {
+        let (mut context, accounts) = start_v0_tip_distribution_account().await;
+        context.warp_to_epoch(4).unwrap();
+
+        // The rent goes to the vote account, like the Anchor program did.
+        let ix = close_tip_distribution_account(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.expired_funds_account,
+            &accounts.tip_distribution_account,
+            &accounts.validator_vote_account,
+            &context.payer.pubkey(),
+            None,
+        );
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer],
+                blockhash,
+            ))
+            .await
+            .unwrap();
+
+        assert!(context
+            .banks_client
+            .get_account(accounts.tip_distribution_account)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(context
+            .banks_client
+            .get_account(accounts.validator_vote_account)
+            .await
+            .unwrap()
+            .is_some());
+    }
+}
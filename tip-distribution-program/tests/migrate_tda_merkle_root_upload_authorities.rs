@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_distribution_core::{
+        merkle_root::MerkleRoot, tip_distribution_account::TipDistributionAccount,
+    };
+    use jito_tip_distribution_sdk::sdk::migrate_tda_merkle_root_upload_authorities;
+    use solana_native_token::sol_to_lamports;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::fixtures::{
+        config, decode_program_account, merkle_root_upload_config, program_account, program_id,
+        setup, tip_distribution_account,
+    };
+
+    #[tokio::test]
+    async fn migrate_tda_merkle_root_upload_authorities_skips_ineligible_accounts() {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let (config_pubkey, config) = config(&program_id, &Pubkey::new_unique());
+        accounts.push((
+            config_pubkey,
+            program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        let override_authority = Pubkey::new_unique();
+        let (merkle_root_upload_config_pubkey, merkle_root_upload_config) =
+            merkle_root_upload_config(&program_id, &override_authority);
+        let original_upload_authority =
+            Pubkey::new_from_array(merkle_root_upload_config.original_upload_authority);
+        accounts.push((
+            merkle_root_upload_config_pubkey,
+            program_account(program_id, merkle_root_upload_config, sol_to_lamports(0.01)),
+        ));
+
+        // `(tip_distribution_account, expected_merkle_root_upload_authority)`
+        let mut tip_distribution_accounts = Vec::new();
+        for (merkle_root_upload_authority, has_merkle_root, eligible) in [
+            (original_upload_authority, false, true),
+            (original_upload_authority, false, true),
+            (original_upload_authority, true, false),
+            (Pubkey::new_unique(), false, false),
+        ] {
+            let validator_vote_account = Pubkey::new_unique();
+            let (pubkey, mut tda) = tip_distribution_account(
+                &program_id,
+                &validator_vote_account,
+                0,
+                3,
+                &validator_vote_account,
+                false,
+            );
+            tda.merkle_root_upload_authority = merkle_root_upload_authority.to_bytes();
+            if has_merkle_root {
+                tda.set_merkle_root(&MerkleRoot {
+                    root: [1; 32],
+                    max_total_claim: 100,
+                    max_num_nodes: 10,
+                    total_funds_claimed: 0,
+                    num_nodes_claimed: 0,
+                });
+            }
+            accounts.push((
+                pubkey,
+                program_account(program_id, tda, sol_to_lamports(0.01)),
+            ));
+
+            let expected = if eligible {
+                override_authority
+            } else {
+                merkle_root_upload_authority
+            };
+            tip_distribution_accounts.push((pubkey, expected));
+        }
+
+        let mut context = setup(accounts).await;
+
+        // The config isn't a TDA and is skipped too.
+        let mut accounts: Vec<Pubkey> = tip_distribution_accounts
+            .iter()
+            .map(|(pubkey, _)| *pubkey)
+            .collect();
+        accounts.push(config_pubkey);
+
+        let ix = migrate_tda_merkle_root_upload_authorities(
+            &program_id,
+            &merkle_root_upload_config_pubkey,
+            &accounts,
+        );
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer],
+                blockhash,
+            ))
+            .await
+            .unwrap();
+
+        for (pubkey, expected_merkle_root_upload_authority) in tip_distribution_accounts {
+            let data = context
+                .banks_client
+                .get_account(pubkey)
+                .await
+                .unwrap()
+                .unwrap()
+                .data;
+            let tda: TipDistributionAccount = decode_program_account(&data);
+            assert_eq!(
+                tda.merkle_root_upload_authority,
+                expected_merkle_root_upload_authority.to_bytes()
+            );
+        }
+    }
+}
@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_distribution_core::tip_distribution_account::TipDistributionAccount;
+    use jito_tip_distribution_sdk::sdk::{
+        add_permitted_uploader, remove_permitted_uploader, upload_merkle_root,
+    };
+    use solana_instruction::Instruction;
+    use solana_keypair::Keypair;
+    use solana_native_token::sol_to_lamports;
+    use solana_program_test::{BanksClientError, ProgramTestContext};
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::fixtures::{
+        config, decode_program_account, merkle_root_upload_config, program_account, program_id,
+        setup, tip_distribution_account,
+    };
+
+    struct TestAccounts {
+        program_id: Pubkey,
+        config: Pubkey,
+        authority: Keypair,
+        merkle_root_upload_config: Pubkey,
+        tip_distribution_account: Pubkey,
+    }
+
+    /// Starts a test validator at epoch 1 with a TDA whose upload authority is the override
+    /// authority of the [MerkleRootUploadConfig].
+    async fn start() -> (ProgramTestContext, TestAccounts) {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let authority = Keypair::new();
+        let (config_pubkey, mut config) = config(&program_id, &Pubkey::new_unique());
+        config.authority = authority.pubkey().to_bytes();
+        accounts.push((
+            config_pubkey,
+            program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        let override_authority = Pubkey::new_unique();
+        let (merkle_root_upload_config_pubkey, merkle_root_upload_config) =
+            merkle_root_upload_config(&program_id, &override_authority);
+        accounts.push((
+            merkle_root_upload_config_pubkey,
+            program_account(program_id, merkle_root_upload_config, sol_to_lamports(0.01)),
+        ));
+
+        let validator_vote_account = Pubkey::new_unique();
+        let (tip_distribution_account_pubkey, mut tip_distribution_account) =
+            tip_distribution_account(
+                &program_id,
+                &validator_vote_account,
+                0,
+                3,
+                &validator_vote_account,
+                false,
+            );
+        tip_distribution_account.merkle_root_upload_authority = override_authority.to_bytes();
+        accounts.push((
+            tip_distribution_account_pubkey,
+            program_account(program_id, tip_distribution_account, sol_to_lamports(0.01)),
+        ));
+
+        let mut context = setup(accounts).await;
+        context.warp_to_epoch(1).unwrap();
+
+        (
+            context,
+            TestAccounts {
+                program_id,
+                config: config_pubkey,
+                authority,
+                merkle_root_upload_config: merkle_root_upload_config_pubkey,
+                tip_distribution_account: tip_distribution_account_pubkey,
+            },
+        )
+    }
+
+    async fn process(
+        context: &mut ProgramTestContext,
+        ix: Instruction,
+        signer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer, signer],
+                blockhash,
+            ))
+            .await
+    }
+
+    fn upload(accounts: &TestAccounts, uploader: &Keypair, root: [u8; 32]) -> Instruction {
+        upload_merkle_root(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.tip_distribution_account,
+            &uploader.pubkey(),
+            Some(&accounts.merkle_root_upload_config),
+            root,
+            100,
+            10,
+        )
+    }
+
+    #[tokio::test]
+    async fn permitted_uploader_can_upload_until_removed() {
+        let (mut context, accounts) = start().await;
+        let uploader = Keypair::new();
+
+        assert!(process(
+            &mut context,
+            upload(&accounts, &uploader, [1; 32]),
+            &uploader
+        )
+        .await
+        .is_err());
+
+        let ix = add_permitted_uploader(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.merkle_root_upload_config,
+            &accounts.authority.pubkey(),
+            &uploader.pubkey(),
+        );
+        process(&mut context, ix, &accounts.authority)
+            .await
+            .unwrap();
+
+        process(
+            &mut context,
+            upload(&accounts, &uploader, [1; 32]),
+            &uploader,
+        )
+        .await
+        .unwrap();
+        let data = context
+            .banks_client
+            .get_account(accounts.tip_distribution_account)
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        let tip_distribution_account: TipDistributionAccount = decode_program_account(&data);
+        assert_eq!(
+            tip_distribution_account.merkle_root().unwrap().root,
+            [1; 32]
+        );
+
+        let ix = remove_permitted_uploader(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.merkle_root_upload_config,
+            &accounts.authority.pubkey(),
+            &uploader.pubkey(),
+        );
+        process(&mut context, ix, &accounts.authority)
+            .await
+            .unwrap();
+
+        assert!(process(
+            &mut context,
+            upload(&accounts, &uploader, [2; 32]),
+            &uploader
+        )
+        .await
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn only_config_authority_can_add_permitted_uploaders() {
+        let (mut context, accounts) = start().await;
+        let uploader = Keypair::new();
+
+        let ix = add_permitted_uploader(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.merkle_root_upload_config,
+            &uploader.pubkey(),
+            &uploader.pubkey(),
+        );
+        assert!(process(&mut context, ix, &uploader).await.is_err());
+    }
+}
@@ -0,0 +1,515 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::program_account::ProgramAccount;
+    use jito_tip_distribution_core::{
+        claim_status::ClaimStatus, merkle_proof, merkle_root::MerkleRoot,
+        priority_fee_distribution_account::PriorityFeeDistributionAccount,
+    };
+    use jito_tip_distribution_sdk::{
+        error::TipDistributionError,
+        sdk::{
+            claim_priority_fee, close_priority_fee_distribution_account,
+            initialize_priority_fee_distribution_account, transfer_priority_fees,
+            upload_priority_fee_merkle_root,
+        },
+    };
+    use solana_account::AccountSharedData;
+    use solana_instruction::Instruction;
+    use solana_keypair::Keypair;
+    use solana_native_token::sol_to_lamports;
+    use solana_program_test::{BanksClientError, ProgramTestContext};
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::fixtures::{
+        config, custom_error, decode_program_account, priority_fee_distribution_account,
+        program_account, program_account_data, program_id, setup,
+    };
+
+    /// Balance of the PFDA the tests create: its rent plus the priority fees to distribute.
+    const PRIORITY_FEE_DISTRIBUTION_ACCOUNT_LAMPORTS: u64 = 10_000_000;
+
+    /// Lamports the single claimant of the uploaded merkle tree is entitled to.
+    const CLAIM_AMOUNT: u64 = 1_000_000;
+
+    struct TestAccounts {
+        program_id: Pubkey,
+        config: Pubkey,
+        expired_funds_account: Pubkey,
+        priority_fee_distribution_account: Pubkey,
+        validator_vote_account: Pubkey,
+        merkle_root_upload_authority: Keypair,
+        claimant: Keypair,
+    }
+
+    /// Starts a test validator with a PFDA created at epoch 0 without a merkle root.
+    async fn start() -> (ProgramTestContext, TestAccounts) {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let expired_funds_account = Pubkey::new_unique();
+        let (config_pubkey, config) = config(&program_id, &expired_funds_account);
+        accounts.push((
+            config_pubkey,
+            program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        let merkle_root_upload_authority = Keypair::new();
+        let validator_vote_account = Pubkey::new_unique();
+        let (priority_fee_distribution_account_pubkey, priority_fee_distribution_account) =
+            priority_fee_distribution_account(
+                &program_id,
+                &validator_vote_account,
+                &merkle_root_upload_authority.pubkey(),
+                0,
+            );
+        accounts.push((
+            priority_fee_distribution_account_pubkey,
+            program_account(
+                program_id,
+                priority_fee_distribution_account,
+                PRIORITY_FEE_DISTRIBUTION_ACCOUNT_LAMPORTS,
+            ),
+        ));
+
+        let context = setup(accounts).await;
+
+        (
+            context,
+            TestAccounts {
+                program_id,
+                config: config_pubkey,
+                expired_funds_account,
+                priority_fee_distribution_account: priority_fee_distribution_account_pubkey,
+                validator_vote_account,
+                merkle_root_upload_authority,
+                claimant: Keypair::new(),
+            },
+        )
+    }
+
+    /// Processes `ix` signed by the payer and `signers`.
+    async fn process(
+        context: &mut ProgramTestContext,
+        ix: Instruction,
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        let signers = [&[&context.payer], signers].concat();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &signers,
+                blockhash,
+            ))
+            .await
+    }
+
+    async fn load_priority_fee_distribution_account(
+        context: &mut ProgramTestContext,
+        pubkey: Pubkey,
+    ) -> PriorityFeeDistributionAccount {
+        let account = context
+            .banks_client
+            .get_account(pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+        decode_program_account(&account.data)
+    }
+
+    /// Sets a merkle root for a single claimant of [CLAIM_AMOUNT], bypassing the checks of
+    /// `UploadPriorityFeeMerkleRoot`.
+    async fn set_merkle_root(context: &mut ProgramTestContext, accounts: &TestAccounts) {
+        let mut account = context
+            .banks_client
+            .get_account(accounts.priority_fee_distribution_account)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut priority_fee_distribution_account: PriorityFeeDistributionAccount =
+            decode_program_account(&account.data);
+        priority_fee_distribution_account.set_merkle_root(&MerkleRoot {
+            root: merkle_proof::leaf(&accounts.claimant.pubkey().to_bytes(), CLAIM_AMOUNT),
+            max_total_claim: CLAIM_AMOUNT,
+            max_num_nodes: 1,
+            total_funds_claimed: 0,
+            num_nodes_claimed: 0,
+        });
+        account.data = program_account_data(priority_fee_distribution_account);
+        context.set_account(
+            &accounts.priority_fee_distribution_account,
+            &AccountSharedData::from(account),
+        );
+    }
+
+    /// Overwrites the PFDA so that it has received `total_lamports_transferred` in priority fees
+    /// and holds `lamports`, bypassing `TransferPriorityFees`.
+    async fn set_total_lamports_transferred(
+        context: &mut ProgramTestContext,
+        accounts: &TestAccounts,
+        total_lamports_transferred: u64,
+        lamports: u64,
+    ) {
+        let mut account = context
+            .banks_client
+            .get_account(accounts.priority_fee_distribution_account)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut priority_fee_distribution_account: PriorityFeeDistributionAccount =
+            decode_program_account(&account.data);
+        priority_fee_distribution_account.total_lamports_transferred = total_lamports_transferred;
+        account.data = program_account_data(priority_fee_distribution_account);
+        account.lamports = lamports;
+        context.set_account(
+            &accounts.priority_fee_distribution_account,
+            &AccountSharedData::from(account),
+        );
+    }
+
+    fn transfer_ix(
+        context: &ProgramTestContext,
+        accounts: &TestAccounts,
+        lamports: u64,
+    ) -> Instruction {
+        transfer_priority_fees(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.priority_fee_distribution_account,
+            &context.payer.pubkey(),
+            lamports,
+        )
+    }
+
+    fn upload_ix(accounts: &TestAccounts, merkle_root_upload_authority: &Pubkey) -> Instruction {
+        upload_priority_fee_merkle_root(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.priority_fee_distribution_account,
+            merkle_root_upload_authority,
+            [1; 32],
+            CLAIM_AMOUNT,
+            1,
+        )
+    }
+
+    /// Claims [CLAIM_AMOUNT] for the claimant, the single leaf of the root set by
+    /// `set_merkle_root`.
+    fn claim_ix(context: &ProgramTestContext, accounts: &TestAccounts) -> Instruction {
+        let (claim_status, bump) = Pubkey::find_program_address(
+            &[
+                ClaimStatus::SEED,
+                accounts.claimant.pubkey().as_ref(),
+                accounts.priority_fee_distribution_account.as_ref(),
+            ],
+            &accounts.program_id,
+        );
+        claim_priority_fee(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.priority_fee_distribution_account,
+            &accounts.claimant.pubkey(),
+            &claim_status,
+            &accounts.claimant.pubkey(),
+            &context.payer.pubkey(),
+            &accounts.validator_vote_account,
+            None,
+            bump,
+            CLAIM_AMOUNT,
+            &[],
+        )
+    }
+
+    #[tokio::test]
+    async fn initialize_priority_fee_distribution_account_at_current_epoch() {
+        let (mut context, accounts) = start().await;
+
+        let validator_vote_account = Pubkey::new_unique();
+        let merkle_root_upload_authority = Pubkey::new_unique();
+        let (pubkey, bump) = Pubkey::find_program_address(
+            &[
+                PriorityFeeDistributionAccount::SEED,
+                validator_vote_account.as_ref(),
+                &0u64.to_le_bytes(),
+            ],
+            &accounts.program_id,
+        );
+
+        let ix = initialize_priority_fee_distribution_account(
+            &accounts.program_id,
+            &accounts.config,
+            &pubkey,
+            &validator_vote_account,
+            &context.payer.pubkey(),
+            &merkle_root_upload_authority,
+            500,
+            bump,
+        );
+        process(&mut context, ix, &[]).await.unwrap();
+
+        let priority_fee_distribution_account =
+            load_priority_fee_distribution_account(&mut context, pubkey).await;
+        assert_eq!(
+            priority_fee_distribution_account.validator_vote_account,
+            validator_vote_account.to_bytes()
+        );
+        assert_eq!(
+            priority_fee_distribution_account.merkle_root_upload_authority,
+            merkle_root_upload_authority.to_bytes()
+        );
+        assert_eq!(priority_fee_distribution_account.epoch_created_at(), 0);
+        assert_eq!(priority_fee_distribution_account.expires_at, 3);
+        assert_eq!(
+            priority_fee_distribution_account.validator_commission_bps,
+            500
+        );
+        assert_eq!(priority_fee_distribution_account.bump, bump);
+        assert!(priority_fee_distribution_account.merkle_root().is_none());
+    }
+
+    #[tokio::test]
+    async fn transfer_priority_fees_adds_to_total_transferred() {
+        let (mut context, accounts) = start().await;
+
+        for _ in 0..2 {
+            let ix = transfer_ix(&context, &accounts, 1_000);
+            process(&mut context, ix, &[]).await.unwrap();
+        }
+
+        let priority_fee_distribution_account = load_priority_fee_distribution_account(
+            &mut context,
+            accounts.priority_fee_distribution_account,
+        )
+        .await;
+        assert_eq!(
+            priority_fee_distribution_account.total_lamports_transferred,
+            2_000
+        );
+        assert_eq!(
+            context
+                .banks_client
+                .get_balance(accounts.priority_fee_distribution_account)
+                .await
+                .unwrap(),
+            PRIORITY_FEE_DISTRIBUTION_ACCOUNT_LAMPORTS + 2_000
+        );
+    }
+
+    #[tokio::test]
+    async fn transfer_priority_fees_fails_after_merkle_root_upload() {
+        let (mut context, accounts) = start().await;
+        set_merkle_root(&mut context, &accounts).await;
+
+        let ix = transfer_ix(&context, &accounts, 1_000);
+        let error = process(&mut context, ix, &[]).await.unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::MerkleRootAlreadyUploaded as u32)
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_priority_fee_merkle_root_requires_upload_authority() {
+        let (mut context, accounts) = start().await;
+        let ix = transfer_ix(&context, &accounts, CLAIM_AMOUNT);
+        process(&mut context, ix, &[]).await.unwrap();
+        context.warp_to_epoch(1).unwrap();
+
+        let other_authority = Keypair::new();
+        let ix = upload_ix(&accounts, &other_authority.pubkey());
+        let error = process(&mut context, ix, &[&other_authority])
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::Unauthorized as u32)
+        );
+
+        let ix = upload_ix(&accounts, &accounts.merkle_root_upload_authority.pubkey());
+        process(&mut context, ix, &[&accounts.merkle_root_upload_authority])
+            .await
+            .unwrap();
+
+        let merkle_root = load_priority_fee_distribution_account(
+            &mut context,
+            accounts.priority_fee_distribution_account,
+        )
+        .await
+        .merkle_root()
+        .unwrap();
+        assert_eq!(merkle_root.root, [1; 32]);
+        assert_eq!(merkle_root.max_total_claim, CLAIM_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn upload_priority_fee_merkle_root_rejects_max_total_claim_above_transferred_fees() {
+        let (mut context, accounts) = start().await;
+        let ix = transfer_ix(&context, &accounts, CLAIM_AMOUNT - 1);
+        process(&mut context, ix, &[]).await.unwrap();
+        context.warp_to_epoch(1).unwrap();
+
+        let ix = upload_ix(&accounts, &accounts.merkle_root_upload_authority.pubkey());
+        let error = process(&mut context, ix, &[&accounts.merkle_root_upload_authority])
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::MaxTotalClaimExceedsTransferredFees as u32)
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_priority_fee_merkle_root_requires_funds_for_max_total_claim() {
+        let (mut context, accounts) = start().await;
+        let rent_lamports = context
+            .banks_client
+            .get_rent()
+            .await
+            .unwrap()
+            .minimum_balance(PriorityFeeDistributionAccount::SPACE);
+        set_total_lamports_transferred(
+            &mut context,
+            &accounts,
+            CLAIM_AMOUNT,
+            rent_lamports + CLAIM_AMOUNT - 1,
+        )
+        .await;
+        context.warp_to_epoch(1).unwrap();
+
+        let ix = upload_ix(&accounts, &accounts.merkle_root_upload_authority.pubkey());
+        let error = process(&mut context, ix, &[&accounts.merkle_root_upload_authority])
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::InsufficientFundsForMaxClaim as u32)
+        );
+
+        set_total_lamports_transferred(
+            &mut context,
+            &accounts,
+            CLAIM_AMOUNT,
+            rent_lamports + CLAIM_AMOUNT,
+        )
+        .await;
+        let ix = upload_ix(&accounts, &accounts.merkle_root_upload_authority.pubkey());
+        process(&mut context, ix, &[&accounts.merkle_root_upload_authority])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn claim_priority_fee_pays_claimant() {
+        let (mut context, accounts) = start().await;
+        set_merkle_root(&mut context, &accounts).await;
+
+        let ix = claim_ix(&context, &accounts);
+        process(&mut context, ix, &[&accounts.claimant])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            context
+                .banks_client
+                .get_balance(accounts.claimant.pubkey())
+                .await
+                .unwrap(),
+            CLAIM_AMOUNT
+        );
+        let merkle_root = load_priority_fee_distribution_account(
+            &mut context,
+            accounts.priority_fee_distribution_account,
+        )
+        .await
+        .merkle_root()
+        .unwrap();
+        assert_eq!(merkle_root.total_funds_claimed, CLAIM_AMOUNT);
+        assert_eq!(merkle_root.num_nodes_claimed, 1);
+    }
+
+    #[tokio::test]
+    async fn claim_priority_fee_fails_if_remaining_claims_are_underfunded() {
+        let (mut context, accounts) = start().await;
+        let rent_lamports = context
+            .banks_client
+            .get_rent()
+            .await
+            .unwrap()
+            .minimum_balance(PriorityFeeDistributionAccount::SPACE);
+        set_total_lamports_transferred(
+            &mut context,
+            &accounts,
+            CLAIM_AMOUNT,
+            rent_lamports + CLAIM_AMOUNT - 1,
+        )
+        .await;
+        set_merkle_root(&mut context, &accounts).await;
+
+        let ix = claim_ix(&context, &accounts);
+        let error = process(&mut context, ix, &[&accounts.claimant])
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::InsufficientFundsForRemainingClaims as u32)
+        );
+    }
+
+    #[tokio::test]
+    async fn close_priority_fee_distribution_account_after_expiry() {
+        let (mut context, accounts) = start().await;
+        let rent_lamports = context
+            .banks_client
+            .get_rent()
+            .await
+            .unwrap()
+            .minimum_balance(PriorityFeeDistributionAccount::SPACE);
+
+        let signer = Keypair::new();
+        let ix = close_priority_fee_distribution_account(
+            &accounts.program_id,
+            &accounts.config,
+            &accounts.expired_funds_account,
+            &accounts.priority_fee_distribution_account,
+            &accounts.validator_vote_account,
+            &signer.pubkey(),
+        );
+        let error = process(&mut context, ix.clone(), &[&signer])
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(error),
+            Some(TipDistributionError::PrematureCloseTipDistributionAccount as u32)
+        );
+
+        context.warp_to_epoch(4).unwrap();
+        process(&mut context, ix, &[&signer]).await.unwrap();
+
+        assert!(context
+            .banks_client
+            .get_account(accounts.priority_fee_distribution_account)
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            context
+                .banks_client
+                .get_balance(accounts.expired_funds_account)
+                .await
+                .unwrap(),
+            PRIORITY_FEE_DISTRIBUTION_ACCOUNT_LAMPORTS - rent_lamports
+        );
+        assert_eq!(
+            context
+                .banks_client
+                .get_balance(accounts.validator_vote_account)
+                .await
+                .unwrap(),
+            rent_lamports
+        );
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::program_account::ProgramAccount;
+    use jito_tip_distribution_core::tip_distribution_account::TipDistributionAccount;
+    use jito_tip_distribution_sdk::sdk::close_tip_distribution_account;
+    use solana_native_token::sol_to_lamports;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::fixtures::{config, program_account, program_id, setup, tip_distribution_account};
+
+    const TIP_DISTRIBUTION_ACCOUNT_LAMPORTS: u64 = 10_000_000;
+
+    #[tokio::test]
+    async fn close_tip_distribution_account_rolls_over_unclaimed_funds() {
+        let program_id = program_id();
+        let mut accounts = Vec::new();
+
+        let expired_funds_account = Pubkey::new_unique();
+        let (config_pubkey, config) = config(&program_id, &expired_funds_account);
+        accounts.push((
+            config_pubkey,
+            program_account(program_id, config, sol_to_lamports(0.01)),
+        ));
+
+        let validator_vote_account = Pubkey::new_unique();
+        let rent_refund_destination = Pubkey::new_unique();
+
+        // The expired account rolling over, the validator's next account, and another validator's.
+        let (expired_tda, tda) = tip_distribution_account(
+            &program_id,
+            &validator_vote_account,
+            0,
+            0,
+            &rent_refund_destination,
+            true,
+        );
+        let (next_tda, next) = tip_distribution_account(
+            &program_id,
+            &validator_vote_account,
+            1,
+            4,
+            &rent_refund_destination,
+            false,
+        );
+        let (other_tda, other) = tip_distribution_account(
+            &program_id,
+            &Pubkey::new_unique(),
+            1,
+            4,
+            &rent_refund_destination,
+            false,
+        );
+        for (pubkey, tda) in [(expired_tda, tda), (next_tda, next), (other_tda, other)] {
+            accounts.push((
+                pubkey,
+                program_account(program_id, tda, TIP_DISTRIBUTION_ACCOUNT_LAMPORTS),
+            ));
+        }
+
+        let mut context = setup(accounts).await;
+        let rent_lamports = context
+            .banks_client
+            .get_rent()
+            .await
+            .unwrap()
+            .minimum_balance(TipDistributionAccount::SPACE);
+        context.warp_to_epoch(1).unwrap();
+
+        // Without a target, or with another validator's account, the close fails.
+        for (roll_over_tip_distribution_account, should_succeed) in [
+            (None, false),
+            (Some(other_tda), false),
+            (Some(next_tda), true),
+        ] {
+            let ix = close_tip_distribution_account(
+                &program_id,
+                &config_pubkey,
+                &expired_funds_account,
+                &expired_tda,
+                &rent_refund_destination,
+                &context.payer.pubkey(),
+                roll_over_tip_distribution_account.as_ref(),
+            );
+
+            let blockhash = context.get_new_latest_blockhash().await.unwrap();
+            let result = context
+                .banks_client
+                .process_transaction(Transaction::new_signed_with_payer(
+                    &[ix],
+                    Some(&context.payer.pubkey()),
+                    &[&context.payer],
+                    blockhash,
+                ))
+                .await;
+            assert_eq!(result.is_ok(), should_succeed);
+        }
+
+        assert!(context
+            .banks_client
+            .get_account(expired_tda)
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            context.banks_client.get_balance(next_tda).await.unwrap(),
+            2 * TIP_DISTRIBUTION_ACCOUNT_LAMPORTS - rent_lamports
+        );
+        assert_eq!(
+            context
+                .banks_client
+                .get_balance(expired_funds_account)
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            context
+                .banks_client
+                .get_balance(rent_refund_destination)
+                .await
+                .unwrap(),
+            rent_lamports
+        );
+    }
+}
@@ -50,6 +50,41 @@ pub enum TipDistributionError {
 
     #[error("TDA not valid for migration.")]
     InvalidTdaForMigration,
+
+    #[error("Unclaimed funds must roll over into a later TipDistributionAccount of the same validator that has no merkle root yet.")]
+    InvalidRolloverTarget,
+
+    #[error("The MerkleRootUploadConfig already holds the maximum number of permitted uploaders.")]
+    PermittedUploadersFull,
+
+    #[error("The uploader is already permitted.")]
+    PermittedUploaderAlreadyExists,
+
+    #[error("The uploader is not permitted.")]
+    PermittedUploaderNotFound,
+
+    #[error("The merkle root upload deadline has passed, the upload falls back to the override authority.")]
+    MerkleRootUploadDeadlinePassed,
+
+    #[error("The funds to distribute (lamports above the rent-exempt minimum, or the token vault balance) don't cover the merkle root's max_total_claim.")]
+    InsufficientFundsForMaxClaim,
+
+    #[error("The claim would leave too few funds to cover the remaining claims.")]
+    InsufficientFundsForRemainingClaims,
+
+    #[error("Funds have already been claimed from the merkle root, so it can't be replaced.")]
+    ClaimsAlreadyStarted,
+
+    #[error("The TipDistributionAccount has token vaults that haven't been swept.")]
+    TokenVaultsNotSwept,
+
+    #[error(
+        "A merkle root has already been uploaded, so no more priority fees can be transferred."
+    )]
+    MerkleRootAlreadyUploaded,
+
+    #[error("The merkle root's max_total_claim exceeds the priority fees transferred to the PriorityFeeDistributionAccount.")]
+    MaxTotalClaimExceedsTransferredFees,
 }
 
 impl From<TipDistributionError> for ProgramError {
@@ -57,3 +92,49 @@ impl From<TipDistributionError> for ProgramError {
         Self::Custom(value as u32)
     }
 }
+
+impl TipDistributionError {
+    /// Every error, indexed by its `ProgramError::Custom` code.
+    pub const ALL: [Self; 27] = [
+        Self::AccountValidationFailure,
+        Self::ArithmeticError,
+        Self::ExceedsMaxClaim,
+        Self::ExceedsMaxNumNodes,
+        Self::ExpiredTipDistributionAccount,
+        Self::FundsAlreadyClaimed,
+        Self::InvalidParameters,
+        Self::InvalidProof,
+        Self::InvalidVoteAccountData,
+        Self::MaxValidatorCommissionFeeBpsExceeded,
+        Self::PrematureCloseTipDistributionAccount,
+        Self::PrematureCloseClaimStatus,
+        Self::PrematureMerkleRootUpload,
+        Self::RootNotUploaded,
+        Self::Unauthorized,
+        Self::InvalidTdaForMigration,
+        Self::InvalidRolloverTarget,
+        Self::PermittedUploadersFull,
+        Self::PermittedUploaderAlreadyExists,
+        Self::PermittedUploaderNotFound,
+        Self::MerkleRootUploadDeadlinePassed,
+        Self::InsufficientFundsForMaxClaim,
+        Self::InsufficientFundsForRemainingClaims,
+        Self::ClaimsAlreadyStarted,
+        Self::TokenVaultsNotSwept,
+        Self::MerkleRootAlreadyUploaded,
+        Self::MaxTotalClaimExceedsTransferredFees,
+    ];
+}
+
+impl TryFrom<u32> for TipDistributionError {
+    type Error = u32;
+
+    /// Maps a `ProgramError::Custom` code back to the error, handing back unknown codes.
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        usize::try_from(code)
+            .ok()
+            .and_then(|index| Self::ALL.get(index))
+            .cloned()
+            .ok_or(code)
+    }
+}
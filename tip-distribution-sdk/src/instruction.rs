@@ -1,10 +1,9 @@
+use jito_tip_core::discriminator::instruction_discriminator;
 use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
 
-// const MAX_PROOF_SIZE: usize = 32;
-
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[repr(C)]
-pub enum JitoTipDistributionInstruction {
+pub enum JitoTipDistributionInstruction<'a> {
     /// Initialize
     Initialize {
         authority: Pubkey,
@@ -19,6 +18,10 @@ pub enum JitoTipDistributionInstruction {
         merkle_root_upload_authority: Pubkey,
         validator_commission_bps: u16,
         bump: u8,
+        /// Defaults to the validator vote account when omitted.
+        rent_refund_destination: Option<Pubkey>,
+        /// Only sent along with `rent_refund_destination`, defaults to `false`.
+        roll_over_unclaimed_funds: bool,
     },
 
     /// Update config
@@ -27,6 +30,7 @@ pub enum JitoTipDistributionInstruction {
         expired_funds_account: Pubkey,
         num_epochs_valid: u64,
         max_validator_commission_bps: u16,
+        merkle_root_upload_deadline_epochs: Option<u64>,
     },
 
     /// Upload merkle root
@@ -42,11 +46,11 @@ pub enum JitoTipDistributionInstruction {
     /// Close claim status
     CloseTipDistributionAccount,
 
-    // Claim
+    /// Claim
     Claim {
         bump: u8,
         amount: u64,
-        proof: Vec<[u8; 32]>,
+        proof: &'a [[u8; 32]],
     },
 
     /// Initialize merkle root upload config
@@ -63,173 +67,485 @@ pub enum JitoTipDistributionInstruction {
 
     /// Initialize merkle root upload config
     MigrateTdaMerkleRootUploadAuthority,
+
+    /// Set the key allowed to claim on behalf of a claimant
+    SetClaimDelegate { delegate: Pubkey },
+
+    /// Close claim delegate
+    CloseClaimDelegate,
+
+    /// Create the token vault of a mint
+    InitializeTokenVault,
+
+    /// Upload the merkle root of a token vault
+    UploadTokenMerkleRoot {
+        root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+    },
+
+    /// Claim tokens from a token vault
+    ClaimToken {
+        bump: u8,
+        amount: u64,
+        proof: &'a [[u8; 32]],
+    },
+
+    /// Initialize priority fee distribution account
+    InitializePriorityFeeDistributionAccount {
+        merkle_root_upload_authority: Pubkey,
+        validator_commission_bps: u16,
+        bump: u8,
+    },
+
+    /// Deposit priority fees into a priority fee distribution account
+    TransferPriorityFees { lamports: u64 },
+
+    /// Upload priority fee merkle root
+    UploadPriorityFeeMerkleRoot {
+        root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+    },
+
+    /// Claim priority fees
+    ClaimPriorityFee {
+        bump: u8,
+        amount: u64,
+        proof: &'a [[u8; 32]],
+    },
+
+    /// Close priority fee distribution account
+    ClosePriorityFeeDistributionAccount,
+
+    /// Close many expired claim statuses, skipping the ones that can't be closed
+    CloseClaimStatuses,
+
+    /// Close many expired tip distribution accounts, skipping the ones that can't be closed
+    CloseTipDistributionAccounts,
+
+    /// Migrate a program account to the latest layout version
+    MigrateAccount,
+
+    /// Permit a key to upload merkle roots on behalf of the override authority
+    AddPermittedUploader { uploader: Pubkey },
+
+    /// Revoke a permitted uploader
+    RemovePermittedUploader { uploader: Pubkey },
+
+    /// Migrate many tip distribution accounts to the override authority, skipping the ineligible
+    /// ones
+    MigrateTdaMerkleRootUploadAuthorities,
 }
 
-impl JitoTipDistributionInstruction {
-    pub fn try_from_slice(instruction_data: &[u8]) -> Result<Self, ProgramError> {
-        let [discriminator0, discriminator1, discriminator2, discriminator3, discriminator4, discriminator5, discriminator6, discriminator7, remaining @ ..] =
-            instruction_data
-        else {
+impl<'a> JitoTipDistributionInstruction<'a> {
+    pub const INITIALIZE: [u8; 8] = instruction_discriminator("initialize");
+    pub const INITIALIZE_TIP_DISTRIBUTION_ACCOUNT: [u8; 8] =
+        instruction_discriminator("initialize_tip_distribution_account");
+    pub const UPDATE_CONFIG: [u8; 8] = instruction_discriminator("update_config");
+    pub const UPLOAD_MERKLE_ROOT: [u8; 8] = instruction_discriminator("upload_merkle_root");
+    pub const CLOSE_CLAIM_STATUS: [u8; 8] = instruction_discriminator("close_claim_status");
+    pub const CLOSE_TIP_DISTRIBUTION_ACCOUNT: [u8; 8] =
+        instruction_discriminator("close_tip_distribution_account");
+    pub const CLAIM: [u8; 8] = instruction_discriminator("claim");
+    pub const INITIALIZE_MERKLE_ROOT_UPLOAD_CONFIG: [u8; 8] =
+        instruction_discriminator("initialize_merkle_root_upload_config");
+    pub const UPDATE_MERKLE_ROOT_UPLOAD_CONFIG: [u8; 8] =
+        instruction_discriminator("update_merkle_root_upload_config");
+    pub const MIGRATE_TDA_MERKLE_ROOT_UPLOAD_AUTHORITY: [u8; 8] =
+        instruction_discriminator("migrate_tda_merkle_root_upload_authority");
+    pub const SET_CLAIM_DELEGATE: [u8; 8] = instruction_discriminator("set_claim_delegate");
+    pub const CLOSE_CLAIM_DELEGATE: [u8; 8] = instruction_discriminator("close_claim_delegate");
+    pub const INITIALIZE_TOKEN_VAULT: [u8; 8] = instruction_discriminator("initialize_token_vault");
+    pub const UPLOAD_TOKEN_MERKLE_ROOT: [u8; 8] =
+        instruction_discriminator("upload_token_merkle_root");
+    pub const CLAIM_TOKEN: [u8; 8] = instruction_discriminator("claim_token");
+    pub const INITIALIZE_PRIORITY_FEE_DISTRIBUTION_ACCOUNT: [u8; 8] =
+        instruction_discriminator("initialize_priority_fee_distribution_account");
+    pub const TRANSFER_PRIORITY_FEES: [u8; 8] = instruction_discriminator("transfer_priority_fees");
+    pub const UPLOAD_PRIORITY_FEE_MERKLE_ROOT: [u8; 8] =
+        instruction_discriminator("upload_priority_fee_merkle_root");
+    pub const CLAIM_PRIORITY_FEE: [u8; 8] = instruction_discriminator("claim_priority_fee");
+    pub const CLOSE_PRIORITY_FEE_DISTRIBUTION_ACCOUNT: [u8; 8] =
+        instruction_discriminator("close_priority_fee_distribution_account");
+    pub const CLOSE_CLAIM_STATUSES: [u8; 8] = instruction_discriminator("close_claim_statuses");
+    pub const CLOSE_TIP_DISTRIBUTION_ACCOUNTS: [u8; 8] =
+        instruction_discriminator("close_tip_distribution_accounts");
+    pub const MIGRATE_ACCOUNT: [u8; 8] = instruction_discriminator("migrate_account");
+    pub const ADD_PERMITTED_UPLOADER: [u8; 8] = instruction_discriminator("add_permitted_uploader");
+    pub const REMOVE_PERMITTED_UPLOADER: [u8; 8] =
+        instruction_discriminator("remove_permitted_uploader");
+    pub const MIGRATE_TDA_MERKLE_ROOT_UPLOAD_AUTHORITIES: [u8; 8] =
+        instruction_discriminator("migrate_tda_merkle_root_upload_authorities");
+
+    pub fn try_from_slice(instruction_data: &'a [u8]) -> Result<Self, ProgramError> {
+        let Some((discriminator, remaining)) = instruction_data.split_first_chunk::<8>() else {
             return Err(ProgramError::InvalidInstructionData);
         };
 
-        match [
-            discriminator0,
-            discriminator1,
-            discriminator2,
-            discriminator3,
-            discriminator4,
-            discriminator5,
-            discriminator6,
-            discriminator7,
-        ] {
+        match *discriminator {
             // Initialize
-            [175, 175, 109, 31, 13, 152, 155, 237] => {
-                let mut authority: Pubkey = [0; 32];
-                authority.copy_from_slice(&remaining[0..32]);
-
-                let mut expired_funds_account: Pubkey = [0; 32];
-                expired_funds_account.copy_from_slice(&remaining[32..64]);
-
-                let mut num_epochs_valid = [0; 8];
-                num_epochs_valid.copy_from_slice(&remaining[64..72]);
-
-                let mut max_validator_commission_bps = [0; 2];
-                max_validator_commission_bps.copy_from_slice(&remaining[72..74]);
+            Self::INITIALIZE => {
+                let (
+                    authority,
+                    expired_funds_account,
+                    num_epochs_valid,
+                    max_validator_commission_bps,
+                    bump,
+                ) = Self::config_args(remaining)?;
 
                 Ok(Self::Initialize {
                     authority,
                     expired_funds_account,
-                    num_epochs_valid: u64::from_le_bytes(num_epochs_valid),
-                    max_validator_commission_bps: u16::from_le_bytes(max_validator_commission_bps),
-                    bump: remaining[74],
+                    num_epochs_valid,
+                    max_validator_commission_bps,
+                    bump,
                 })
             }
 
             // Initialize tip distribution account
-            [120, 191, 25, 182, 111, 49, 179, 55] => {
-                let mut merkle_root_upload_authority: Pubkey = [0; 32];
-                merkle_root_upload_authority.copy_from_slice(&remaining[0..32]);
-
-                let mut validator_commission_bps = [0; 2];
-                validator_commission_bps.copy_from_slice(&remaining[32..34]);
+            Self::INITIALIZE_TIP_DISTRIBUTION_ACCOUNT => {
+                let (merkle_root_upload_authority, validator_commission_bps, bump) =
+                    Self::distribution_account_args(remaining)?;
+
+                // Clients built against the Anchor program don't send the trailing options.
+                let options = remaining
+                    .get(Self::DISTRIBUTION_ACCOUNT_ARGS_LEN..)
+                    .unwrap_or_default();
+                let (rent_refund_destination, roll_over_unclaimed_funds) =
+                    match options.split_first_chunk::<32>() {
+                        Some((rent_refund_destination, options)) => (
+                            Some(*rent_refund_destination),
+                            options.first().is_some_and(|&flag| flag != 0),
+                        ),
+                        None => (None, false),
+                    };
 
                 Ok(Self::InitializeTipDistributionAccount {
                     merkle_root_upload_authority,
-                    validator_commission_bps: u16::from_le_bytes(validator_commission_bps),
-                    bump: remaining[34],
+                    validator_commission_bps,
+                    bump,
+                    rent_refund_destination,
+                    roll_over_unclaimed_funds,
                 })
             }
 
             // Update config
-            [29, 158, 252, 191, 10, 83, 219, 99] => {
-                let mut authority: Pubkey = [0; 32];
-                authority.copy_from_slice(&remaining[0..32]);
-
-                let mut expired_funds_account: Pubkey = [0; 32];
-                expired_funds_account.copy_from_slice(&remaining[32..64]);
-
-                let mut num_epochs_valid = [0; 8];
-                num_epochs_valid.copy_from_slice(&remaining[64..72]);
+            Self::UPDATE_CONFIG => {
+                let (
+                    authority,
+                    expired_funds_account,
+                    num_epochs_valid,
+                    max_validator_commission_bps,
+                    _bump,
+                ) = Self::config_args(remaining)?;
 
-                let mut max_validator_commission_bps = [0; 2];
-                max_validator_commission_bps.copy_from_slice(&remaining[72..74]);
+                // Appended after the Anchor `Config` arguments, which end with the unused bump.
+                let merkle_root_upload_deadline_epochs = remaining
+                    .get(Self::UPDATE_CONFIG_ARGS_LEN..)
+                    .and_then(|remaining| remaining.first_chunk::<8>())
+                    .map(|epochs| u64::from_le_bytes(*epochs));
 
                 Ok(Self::UpdateConfig {
                     authority,
                     expired_funds_account,
-                    num_epochs_valid: u64::from_be_bytes(num_epochs_valid),
-                    max_validator_commission_bps: u16::from_le_bytes(max_validator_commission_bps),
+                    num_epochs_valid,
+                    max_validator_commission_bps,
+                    merkle_root_upload_deadline_epochs,
                 })
             }
 
             // Upload merkle root
-            [70, 3, 110, 29, 199, 190, 205, 176] => {
-                let mut root = [0; 32];
-                root.copy_from_slice(&remaining[0..32]);
-
-                let mut max_total_claim = [0; 8];
-                max_total_claim.copy_from_slice(&remaining[32..40]);
-
-                let mut max_num_nodes = [0; 8];
-                max_num_nodes.copy_from_slice(&remaining[40..48]);
+            Self::UPLOAD_MERKLE_ROOT => {
+                let (root, max_total_claim, max_num_nodes) = Self::merkle_root_args(remaining)?;
 
                 Ok(Self::UploadMerkleRoot {
                     root,
-                    max_total_claim: u64::from_le_bytes(max_total_claim),
-                    max_num_nodes: u64::from_le_bytes(max_num_nodes),
+                    max_total_claim,
+                    max_num_nodes,
                 })
             }
 
             // Close claim status
-            [163, 214, 191, 165, 245, 188, 17, 185] => Ok(Self::CloseClaimStatus),
+            Self::CLOSE_CLAIM_STATUS => Ok(Self::CloseClaimStatus),
 
             // Close tip distribution account
-            [47, 136, 208, 190, 125, 243, 74, 227] => Ok(Self::CloseTipDistributionAccount),
+            Self::CLOSE_TIP_DISTRIBUTION_ACCOUNT => Ok(Self::CloseTipDistributionAccount),
 
             // Claim
-            // [62, 198, 214, 193, 213, 159, 108, 210] => {
-            //     let bump = remaining[0];
-
-            //     let mut amount = [0; 8];
-            //     amount.copy_from_slice(&remaining[1..9]);
-
-            //     let mut proof_len_bytes = [0; 4];
-            //     proof_len_bytes.copy_from_slice(&remaining[9..13]);
-            //     let proof_len = u32::from_le_bytes(proof_len_bytes) as usize;
-
-            //     // Check if we have enough bytes for all proof elements
-            //     let expected_bytes = 13 + (proof_len * 32);
-            //     if remaining.len() < expected_bytes {
-            //         return Err(ProgramError::InvalidInstructionData);
-            //     }
-
-            //     let mut proof = [[0u8; 32]; MAX_PROOF_SIZE];
-            //     for i in 0..proof_len {
-            //         let start_idx = 13 + (i * 32); // Start after discriminator + bump + amount + proof_len
-            //         let end_idx = start_idx + 32;
-            //         proof[i].copy_from_slice(&remaining[start_idx..end_idx]);
-            //     }
-
-            //     Ok(Self::Claim {
-            //         bump,
-            //         amount: u64::from_le_bytes(amount),
-            //         proof,
-            //     })
-            // }
+            Self::CLAIM => {
+                let (bump, amount, proof) = Self::claim_args(remaining)?;
 
-            // Initialize merkle root upload config
-            [232, 87, 72, 14, 89, 40, 40, 27] => {
-                let mut authority: Pubkey = [0; 32];
-                authority.copy_from_slice(&remaining[0..32]);
+                Ok(Self::Claim {
+                    bump,
+                    amount,
+                    proof,
+                })
+            }
 
-                let mut original_authority: Pubkey = [0; 32];
-                original_authority.copy_from_slice(&remaining[0..32]);
+            // Initialize merkle root upload config
+            Self::INITIALIZE_MERKLE_ROOT_UPLOAD_CONFIG => {
+                let Some((authority, remaining)) = remaining.split_first_chunk::<32>() else {
+                    return Err(ProgramError::InvalidInstructionData);
+                };
+                let Some(original_authority) = remaining.first_chunk::<32>() else {
+                    return Err(ProgramError::InvalidInstructionData);
+                };
 
                 Ok(Self::InitializeMerkleRootUploadConfig {
-                    authority,
-                    original_authority,
+                    authority: *authority,
+                    original_authority: *original_authority,
                 })
             }
 
             // Update merkle root upload config
-            [128, 227, 159, 139, 176, 128, 118, 2] => {
-                let mut authority: Pubkey = [0; 32];
-                authority.copy_from_slice(&remaining[0..32]);
-
-                let mut original_authority: Pubkey = [0; 32];
-                original_authority.copy_from_slice(&remaining[0..32]);
+            Self::UPDATE_MERKLE_ROOT_UPLOAD_CONFIG => {
+                let Some((authority, remaining)) = remaining.split_first_chunk::<32>() else {
+                    return Err(ProgramError::InvalidInstructionData);
+                };
+                let Some(original_authority) = remaining.first_chunk::<32>() else {
+                    return Err(ProgramError::InvalidInstructionData);
+                };
 
                 Ok(Self::UpdateMerkleRootUploadConfig {
-                    authority,
-                    original_authority,
+                    authority: *authority,
+                    original_authority: *original_authority,
                 })
             }
 
             // Migrate tda merkle root upload authority
-            [13, 226, 163, 144, 56, 202, 214, 23] => Ok(Self::MigrateTdaMerkleRootUploadAuthority),
+            Self::MIGRATE_TDA_MERKLE_ROOT_UPLOAD_AUTHORITY => {
+                Ok(Self::MigrateTdaMerkleRootUploadAuthority)
+            }
+
+            // Set claim delegate
+            Self::SET_CLAIM_DELEGATE => {
+                let Some(delegate) = remaining.first_chunk::<32>() else {
+                    return Err(ProgramError::InvalidInstructionData);
+                };
+
+                Ok(Self::SetClaimDelegate {
+                    delegate: *delegate,
+                })
+            }
+
+            // Close claim delegate
+            Self::CLOSE_CLAIM_DELEGATE => Ok(Self::CloseClaimDelegate),
+
+            // Initialize token vault
+            Self::INITIALIZE_TOKEN_VAULT => Ok(Self::InitializeTokenVault),
+
+            // Upload token merkle root
+            Self::UPLOAD_TOKEN_MERKLE_ROOT => {
+                let (root, max_total_claim, max_num_nodes) = Self::merkle_root_args(remaining)?;
+
+                Ok(Self::UploadTokenMerkleRoot {
+                    root,
+                    max_total_claim,
+                    max_num_nodes,
+                })
+            }
+
+            // Claim token
+            Self::CLAIM_TOKEN => {
+                let (bump, amount, proof) = Self::claim_args(remaining)?;
+
+                Ok(Self::ClaimToken {
+                    bump,
+                    amount,
+                    proof,
+                })
+            }
+
+            // Initialize priority fee distribution account
+            Self::INITIALIZE_PRIORITY_FEE_DISTRIBUTION_ACCOUNT => {
+                let (merkle_root_upload_authority, validator_commission_bps, bump) =
+                    Self::distribution_account_args(remaining)?;
+
+                Ok(Self::InitializePriorityFeeDistributionAccount {
+                    merkle_root_upload_authority,
+                    validator_commission_bps,
+                    bump,
+                })
+            }
+
+            // Transfer priority fees
+            Self::TRANSFER_PRIORITY_FEES => {
+                let Some(lamports) = remaining.first_chunk::<8>() else {
+                    return Err(ProgramError::InvalidInstructionData);
+                };
+
+                Ok(Self::TransferPriorityFees {
+                    lamports: u64::from_le_bytes(*lamports),
+                })
+            }
+
+            // Upload priority fee merkle root
+            Self::UPLOAD_PRIORITY_FEE_MERKLE_ROOT => {
+                let (root, max_total_claim, max_num_nodes) = Self::merkle_root_args(remaining)?;
+
+                Ok(Self::UploadPriorityFeeMerkleRoot {
+                    root,
+                    max_total_claim,
+                    max_num_nodes,
+                })
+            }
+
+            // Claim priority fee
+            Self::CLAIM_PRIORITY_FEE => {
+                let (bump, amount, proof) = Self::claim_args(remaining)?;
+
+                Ok(Self::ClaimPriorityFee {
+                    bump,
+                    amount,
+                    proof,
+                })
+            }
+
+            // Close priority fee distribution account
+            Self::CLOSE_PRIORITY_FEE_DISTRIBUTION_ACCOUNT => {
+                Ok(Self::ClosePriorityFeeDistributionAccount)
+            }
+
+            // Close claim statuses
+            Self::CLOSE_CLAIM_STATUSES => Ok(Self::CloseClaimStatuses),
+
+            // Close tip distribution accounts
+            Self::CLOSE_TIP_DISTRIBUTION_ACCOUNTS => Ok(Self::CloseTipDistributionAccounts),
+
+            // Migrate account
+            Self::MIGRATE_ACCOUNT => Ok(Self::MigrateAccount),
+
+            // Add permitted uploader
+            Self::ADD_PERMITTED_UPLOADER => {
+                let Some(uploader) = remaining.first_chunk::<32>() else {
+                    return Err(ProgramError::InvalidInstructionData);
+                };
+
+                Ok(Self::AddPermittedUploader {
+                    uploader: *uploader,
+                })
+            }
+
+            // Remove permitted uploader
+            Self::REMOVE_PERMITTED_UPLOADER => {
+                let Some(uploader) = remaining.first_chunk::<32>() else {
+                    return Err(ProgramError::InvalidInstructionData);
+                };
+
+                Ok(Self::RemovePermittedUploader {
+                    uploader: *uploader,
+                })
+            }
+
+            // Migrate TDA merkle root upload authorities
+            Self::MIGRATE_TDA_MERKLE_ROOT_UPLOAD_AUTHORITIES => {
+                Ok(Self::MigrateTdaMerkleRootUploadAuthorities)
+            }
 
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
+
+    /// Length of the Borsh-encoded `Config` taken by `UpdateConfig`.
+    const UPDATE_CONFIG_ARGS_LEN: usize = 32 + 32 + 8 + 2 + 1;
+
+    /// Decodes the `(authority, expired_funds_account, num_epochs_valid,
+    /// max_validator_commission_bps, bump)` arguments of `Initialize` and `UpdateConfig`.
+    const fn config_args(remaining: &[u8]) -> Result<(Pubkey, Pubkey, u64, u16, u8), ProgramError> {
+        let Some((authority, remaining)) = remaining.split_first_chunk::<32>() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let Some((expired_funds_account, remaining)) = remaining.split_first_chunk::<32>() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let Some((num_epochs_valid, remaining)) = remaining.split_first_chunk::<8>() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let Some((max_validator_commission_bps, remaining)) = remaining.split_first_chunk::<2>()
+        else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let Some(&bump) = remaining.first() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        Ok((
+            *authority,
+            *expired_funds_account,
+            u64::from_le_bytes(*num_epochs_valid),
+            u16::from_le_bytes(*max_validator_commission_bps),
+            bump,
+        ))
+    }
+
+    /// Length of the arguments decoded by [Self::distribution_account_args].
+    const DISTRIBUTION_ACCOUNT_ARGS_LEN: usize = 32 + 2 + 1;
+
+    /// Decodes the `(merkle_root_upload_authority, validator_commission_bps, bump)` arguments of a
+    /// distribution account initialization.
+    const fn distribution_account_args(
+        remaining: &[u8],
+    ) -> Result<(Pubkey, u16, u8), ProgramError> {
+        let Some((merkle_root_upload_authority, remaining)) = remaining.split_first_chunk::<32>()
+        else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let Some((validator_commission_bps, remaining)) = remaining.split_first_chunk::<2>() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let Some(&bump) = remaining.first() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        Ok((
+            *merkle_root_upload_authority,
+            u16::from_le_bytes(*validator_commission_bps),
+            bump,
+        ))
+    }
+
+    /// Decodes the `(root, max_total_claim, max_num_nodes)` arguments of a merkle root upload.
+    const fn merkle_root_args(remaining: &[u8]) -> Result<([u8; 32], u64, u64), ProgramError> {
+        let Some((root, remaining)) = remaining.split_first_chunk::<32>() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let Some((max_total_claim, remaining)) = remaining.split_first_chunk::<8>() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let Some(max_num_nodes) = remaining.first_chunk::<8>() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        Ok((
+            *root,
+            u64::from_le_bytes(*max_total_claim),
+            u64::from_le_bytes(*max_num_nodes),
+        ))
+    }
+
+    /// Decodes the `(bump, amount, proof)` arguments of a claim.
+    fn claim_args(remaining: &'a [u8]) -> Result<(u8, u64, &'a [[u8; 32]]), ProgramError> {
+        let Some((&bump, remaining)) = remaining.split_first() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let Some((amount, remaining)) = remaining.split_first_chunk::<8>() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let Some((proof_len, proof)) = remaining.split_first_chunk::<4>() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        // The proof is a Borsh `Vec<[u8; 32]>`: a u32 length followed by the nodes.
+        let proof_len = u32::from_le_bytes(*proof_len) as usize;
+        if proof_len.checked_mul(32) != Some(proof.len()) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // SAFETY: `[u8; 32]` has an alignment of 1 and `proof` holds exactly `proof_len` nodes.
+        let proof =
+            unsafe { core::slice::from_raw_parts(proof.as_ptr() as *const [u8; 32], proof_len) };
+
+        Ok((bump, u64::from_le_bytes(*amount), proof))
+    }
 }
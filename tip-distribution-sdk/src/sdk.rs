@@ -1,11 +1,22 @@
 use solana_instruction::{AccountMeta, Instruction};
 use solana_pubkey::Pubkey;
 
+use crate::instruction::JitoTipDistributionInstruction;
+
+/// The SPL token program, which owns the token vaults.
+pub const TOKEN_PROGRAM_ID: Pubkey =
+    solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
 #[allow(clippy::too_many_arguments)]
 pub fn initialize_config(
     program_id: &Pubkey,
     config: &Pubkey,
     initializer: &Pubkey,
+    authority: &Pubkey,
+    expired_funds_account: &Pubkey,
+    num_epochs_valid: u64,
+    max_validator_commission_bps: u16,
+    bump: u8,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*config, false),
@@ -15,6 +26,756 @@ pub fn initialize_config(
     Instruction {
         program_id: *program_id,
         accounts,
-        data: vec![175, 175, 109, 31, 13, 152, 155, 237],
+        data: config_data(
+            JitoTipDistributionInstruction::INITIALIZE,
+            authority,
+            expired_funds_account,
+            num_epochs_valid,
+            max_validator_commission_bps,
+            bump,
+        ),
+    }
+}
+
+/// Replaces the config fields. The merkle root upload deadline is left unchanged when
+/// `merkle_root_upload_deadline_epochs` is `None`.
+#[allow(clippy::too_many_arguments)]
+pub fn update_config(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    authority: &Pubkey,
+    new_authority: &Pubkey,
+    expired_funds_account: &Pubkey,
+    num_epochs_valid: u64,
+    max_validator_commission_bps: u16,
+    merkle_root_upload_deadline_epochs: Option<u64>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*authority, true),
+    ];
+
+    // The bump of the Anchor `Config` argument is ignored by the program.
+    let mut data = config_data(
+        JitoTipDistributionInstruction::UPDATE_CONFIG,
+        new_authority,
+        expired_funds_account,
+        num_epochs_valid,
+        max_validator_commission_bps,
+        0,
+    );
+    if let Some(merkle_root_upload_deadline_epochs) = merkle_root_upload_deadline_epochs {
+        data.extend_from_slice(&merkle_root_upload_deadline_epochs.to_le_bytes());
+    }
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    }
+}
+
+/// Initializes the current epoch's TDA for `validator_vote_account`.
+///
+/// The rent is refunded to `rent_refund_destination` when the account is closed, or to the vote
+/// account if it's `None`. With `roll_over_unclaimed_funds`, unclaimed funds go to a later TDA of
+/// the validator instead of the `expired_funds_account`.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_tip_distribution_account(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    tip_distribution_account: &Pubkey,
+    validator_vote_account: &Pubkey,
+    signer: &Pubkey,
+    merkle_root_upload_authority: &Pubkey,
+    validator_commission_bps: u16,
+    bump: u8,
+    rent_refund_destination: Option<&Pubkey>,
+    roll_over_unclaimed_funds: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*tip_distribution_account, false),
+        AccountMeta::new_readonly(*validator_vote_account, false),
+        AccountMeta::new(*signer, true),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+
+    let mut data = JitoTipDistributionInstruction::INITIALIZE_TIP_DISTRIBUTION_ACCOUNT.to_vec();
+    data.extend_from_slice(merkle_root_upload_authority.as_ref());
+    data.extend_from_slice(&validator_commission_bps.to_le_bytes());
+    data.push(bump);
+    if roll_over_unclaimed_funds {
+        // The flag can only be sent after a destination.
+        data.extend_from_slice(
+            rent_refund_destination
+                .unwrap_or(validator_vote_account)
+                .as_ref(),
+        );
+        data.push(1);
+    } else if let Some(rent_refund_destination) = rent_refund_destination {
+        data.extend_from_slice(rent_refund_destination.as_ref());
+    }
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    }
+}
+
+/// Closes an expired TDA.
+///
+/// Unclaimed funds go to the `expired_funds_account`, or to `roll_over_tip_distribution_account`
+/// if the TDA rolls them over, and the rent to the TDA's `rent_refund_destination`.
+pub fn close_tip_distribution_account(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    expired_funds_account: &Pubkey,
+    tip_distribution_account: &Pubkey,
+    rent_refund_destination: &Pubkey,
+    signer: &Pubkey,
+    roll_over_tip_distribution_account: Option<&Pubkey>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*expired_funds_account, false),
+        AccountMeta::new(*tip_distribution_account, false),
+        AccountMeta::new(*rent_refund_destination, false),
+        AccountMeta::new(*signer, true),
+    ];
+    if let Some(roll_over_tip_distribution_account) = roll_over_tip_distribution_account {
+        accounts.push(AccountMeta::new(*roll_over_tip_distribution_account, false));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: JitoTipDistributionInstruction::CLOSE_TIP_DISTRIBUTION_ACCOUNT.to_vec(),
+    }
+}
+
+/// Closes an expired `claim_status`, refunding the rent to its `claim_status_payer`.
+pub fn close_claim_status(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    claim_status: &Pubkey,
+    claim_status_payer: &Pubkey,
+    claimant: &Pubkey,
+    tip_distribution_account: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*claim_status, false),
+        AccountMeta::new(*claim_status_payer, false),
+        AccountMeta::new_readonly(*claimant, false),
+        AccountMeta::new_readonly(*tip_distribution_account, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: JitoTipDistributionInstruction::CLOSE_CLAIM_STATUS.to_vec(),
+    }
+}
+
+/// Claims `amount` for `claimant`. `authority` is the TDA's merkle root upload authority, the
+/// claimant itself, or the claimant's registered delegate, in which case `claim_delegate` must be
+/// set.
+#[allow(clippy::too_many_arguments)]
+pub fn claim(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    tip_distribution_account: &Pubkey,
+    authority: &Pubkey,
+    claim_status: &Pubkey,
+    claimant: &Pubkey,
+    payer: &Pubkey,
+    validator_vote_account: &Pubkey,
+    claim_delegate: Option<&Pubkey>,
+    bump: u8,
+    amount: u64,
+    proof: &[[u8; 32]],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*tip_distribution_account, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*claim_status, false),
+        AccountMeta::new(*claimant, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+        AccountMeta::new_readonly(*validator_vote_account, false),
+    ];
+    if let Some(claim_delegate) = claim_delegate {
+        accounts.push(AccountMeta::new_readonly(*claim_delegate, false));
+    }
+
+    let data = claim_data(JitoTipDistributionInstruction::CLAIM, bump, amount, proof);
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_merkle_root_upload_config(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    merkle_root_upload_config: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    override_authority: &Pubkey,
+    original_upload_authority: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*merkle_root_upload_config, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: [
+            JitoTipDistributionInstruction::INITIALIZE_MERKLE_ROOT_UPLOAD_CONFIG.as_slice(),
+            override_authority.as_ref(),
+            original_upload_authority.as_ref(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn update_merkle_root_upload_config(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    merkle_root_upload_config: &Pubkey,
+    authority: &Pubkey,
+    override_authority: &Pubkey,
+    original_upload_authority: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*merkle_root_upload_config, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: [
+            JitoTipDistributionInstruction::UPDATE_MERKLE_ROOT_UPLOAD_CONFIG.as_slice(),
+            override_authority.as_ref(),
+            original_upload_authority.as_ref(),
+        ]
+        .concat(),
+    }
+}
+
+/// Hands the merkle root upload of `tip_distribution_account` over to the override authority
+/// if it still carries the original upload authority.
+pub fn migrate_tda_merkle_root_upload_authority(
+    program_id: &Pubkey,
+    tip_distribution_account: &Pubkey,
+    merkle_root_upload_config: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*tip_distribution_account, false),
+        AccountMeta::new_readonly(*merkle_root_upload_config, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: JitoTipDistributionInstruction::MIGRATE_TDA_MERKLE_ROOT_UPLOAD_AUTHORITY.to_vec(),
+    }
+}
+
+pub fn set_claim_delegate(
+    program_id: &Pubkey,
+    claim_delegate: &Pubkey,
+    claimant: &Pubkey,
+    delegate: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*claim_delegate, false),
+        AccountMeta::new(*claimant, true),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: [
+            JitoTipDistributionInstruction::SET_CLAIM_DELEGATE.as_slice(),
+            delegate.as_ref(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn close_claim_delegate(
+    program_id: &Pubkey,
+    claim_delegate: &Pubkey,
+    claimant: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*claim_delegate, false),
+        AccountMeta::new(*claimant, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: JitoTipDistributionInstruction::CLOSE_CLAIM_DELEGATE.to_vec(),
+    }
+}
+
+/// Uploads a merkle root to `tip_distribution_account`.
+///
+/// Pass the `merkle_root_upload_config` when `merkle_root_upload_authority` is one of its
+/// permitted uploaders rather than the account's upload authority.
+#[allow(clippy::too_many_arguments)]
+pub fn upload_merkle_root(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    tip_distribution_account: &Pubkey,
+    merkle_root_upload_authority: &Pubkey,
+    merkle_root_upload_config: Option<&Pubkey>,
+    root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*tip_distribution_account, false),
+        AccountMeta::new_readonly(*merkle_root_upload_authority, true),
+    ];
+    if let Some(merkle_root_upload_config) = merkle_root_upload_config {
+        accounts.push(AccountMeta::new_readonly(*merkle_root_upload_config, false));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: [
+            JitoTipDistributionInstruction::UPLOAD_MERKLE_ROOT.as_slice(),
+            &root,
+            &max_total_claim.to_le_bytes(),
+            &max_num_nodes.to_le_bytes(),
+        ]
+        .concat(),
+    }
+}
+
+/// Creates the `mint` token vault of `tip_distribution_account`, `payer` funding its rent.
+pub fn initialize_token_vault(
+    program_id: &Pubkey,
+    tip_distribution_account: &Pubkey,
+    token_vault: &Pubkey,
+    mint: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*tip_distribution_account, false),
+            AccountMeta::new(*token_vault, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: JitoTipDistributionInstruction::INITIALIZE_TOKEN_VAULT.to_vec(),
+    }
+}
+
+/// Uploads the merkle root of the `mint` token vault of `tip_distribution_account`. See
+/// [upload_merkle_root] for when to pass the `merkle_root_upload_config`.
+#[allow(clippy::too_many_arguments)]
+pub fn upload_token_merkle_root(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    tip_distribution_account: &Pubkey,
+    token_merkle_root: &Pubkey,
+    mint: &Pubkey,
+    token_vault: &Pubkey,
+    merkle_root_upload_authority: &Pubkey,
+    merkle_root_upload_config: Option<&Pubkey>,
+    root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*tip_distribution_account, false),
+        AccountMeta::new(*token_merkle_root, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*token_vault, false),
+        AccountMeta::new(*merkle_root_upload_authority, true),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    if let Some(merkle_root_upload_config) = merkle_root_upload_config {
+        accounts.push(AccountMeta::new_readonly(*merkle_root_upload_config, false));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: [
+            JitoTipDistributionInstruction::UPLOAD_TOKEN_MERKLE_ROOT.as_slice(),
+            &root,
+            &max_total_claim.to_le_bytes(),
+            &max_num_nodes.to_le_bytes(),
+        ]
+        .concat(),
+    }
+}
+
+/// Claims `amount` tokens from `token_vault` for `claimant`. See [claim] for who may sign as
+/// `authority`.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_token(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    tip_distribution_account: &Pubkey,
+    token_merkle_root: &Pubkey,
+    authority: &Pubkey,
+    claim_status: &Pubkey,
+    claimant: &Pubkey,
+    claimant_token_account: &Pubkey,
+    token_vault: &Pubkey,
+    payer: &Pubkey,
+    validator_vote_account: &Pubkey,
+    claim_delegate: Option<&Pubkey>,
+    bump: u8,
+    amount: u64,
+    proof: &[[u8; 32]],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*tip_distribution_account, false),
+        AccountMeta::new(*token_merkle_root, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*claim_status, false),
+        AccountMeta::new_readonly(*claimant, false),
+        AccountMeta::new(*claimant_token_account, false),
+        AccountMeta::new(*token_vault, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+        AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        AccountMeta::new_readonly(*validator_vote_account, false),
+    ];
+    if let Some(claim_delegate) = claim_delegate {
+        accounts.push(AccountMeta::new_readonly(*claim_delegate, false));
+    }
+
+    let data = claim_data(
+        JitoTipDistributionInstruction::CLAIM_TOKEN,
+        bump,
+        amount,
+        proof,
+    );
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_priority_fee_distribution_account(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    priority_fee_distribution_account: &Pubkey,
+    validator_vote_account: &Pubkey,
+    signer: &Pubkey,
+    merkle_root_upload_authority: &Pubkey,
+    validator_commission_bps: u16,
+    bump: u8,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*priority_fee_distribution_account, false),
+        AccountMeta::new_readonly(*validator_vote_account, false),
+        AccountMeta::new(*signer, true),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: [
+            JitoTipDistributionInstruction::INITIALIZE_PRIORITY_FEE_DISTRIBUTION_ACCOUNT.as_slice(),
+            merkle_root_upload_authority.as_ref(),
+            &validator_commission_bps.to_le_bytes(),
+            &[bump],
+        ]
+        .concat(),
+    }
+}
+
+pub fn transfer_priority_fees(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    priority_fee_distribution_account: &Pubkey,
+    from: &Pubkey,
+    lamports: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*priority_fee_distribution_account, false),
+        AccountMeta::new(*from, true),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: [
+            JitoTipDistributionInstruction::TRANSFER_PRIORITY_FEES.as_slice(),
+            &lamports.to_le_bytes(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn upload_priority_fee_merkle_root(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    priority_fee_distribution_account: &Pubkey,
+    merkle_root_upload_authority: &Pubkey,
+    root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*priority_fee_distribution_account, false),
+        AccountMeta::new_readonly(*merkle_root_upload_authority, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: [
+            JitoTipDistributionInstruction::UPLOAD_PRIORITY_FEE_MERKLE_ROOT.as_slice(),
+            &root,
+            &max_total_claim.to_le_bytes(),
+            &max_num_nodes.to_le_bytes(),
+        ]
+        .concat(),
+    }
+}
+
+/// Claims `amount` priority fees for `claimant`. See [claim] for who may sign as `authority`.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_priority_fee(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    priority_fee_distribution_account: &Pubkey,
+    authority: &Pubkey,
+    claim_status: &Pubkey,
+    claimant: &Pubkey,
+    payer: &Pubkey,
+    validator_vote_account: &Pubkey,
+    claim_delegate: Option<&Pubkey>,
+    bump: u8,
+    amount: u64,
+    proof: &[[u8; 32]],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*priority_fee_distribution_account, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*claim_status, false),
+        AccountMeta::new(*claimant, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+        AccountMeta::new_readonly(*validator_vote_account, false),
+    ];
+    if let Some(claim_delegate) = claim_delegate {
+        accounts.push(AccountMeta::new_readonly(*claim_delegate, false));
+    }
+
+    let data = claim_data(
+        JitoTipDistributionInstruction::CLAIM_PRIORITY_FEE,
+        bump,
+        amount,
+        proof,
+    );
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn close_priority_fee_distribution_account(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    expired_funds_account: &Pubkey,
+    priority_fee_distribution_account: &Pubkey,
+    validator_vote_account: &Pubkey,
+    signer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*expired_funds_account, false),
+        AccountMeta::new(*priority_fee_distribution_account, false),
+        AccountMeta::new(*validator_vote_account, false),
+        AccountMeta::new(*signer, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: JitoTipDistributionInstruction::CLOSE_PRIORITY_FEE_DISTRIBUTION_ACCOUNT.to_vec(),
+    }
+}
+
+/// Closes every expired `(claim_status, claim_status_payer)` pair in `claim_statuses`, refunding
+/// the rent to each payer. Pairs that can't be closed yet are skipped.
+pub fn close_claim_statuses(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    claim_statuses: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    let mut accounts = vec![AccountMeta::new_readonly(*config, false)];
+    for (claim_status, claim_status_payer) in claim_statuses {
+        accounts.push(AccountMeta::new(*claim_status, false));
+        accounts.push(AccountMeta::new(*claim_status_payer, false));
+    }
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: JitoTipDistributionInstruction::CLOSE_CLAIM_STATUSES.to_vec(),
+    }
+}
+
+/// Closes every expired `(tip_distribution_account, rent_refund_destination)` pair in
+/// `tip_distribution_accounts`.
+///
+/// Unclaimed funds go to the `expired_funds_account` and the rent to each TDA's
+/// `rent_refund_destination`. Pairs that can't be closed yet are skipped.
+pub fn close_tip_distribution_accounts(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    expired_funds_account: &Pubkey,
+    tip_distribution_accounts: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*expired_funds_account, false),
+    ];
+    for (tip_distribution_account, rent_refund_destination) in tip_distribution_accounts {
+        accounts.push(AccountMeta::new(*tip_distribution_account, false));
+        accounts.push(AccountMeta::new(*rent_refund_destination, false));
+    }
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: JitoTipDistributionInstruction::CLOSE_TIP_DISTRIBUTION_ACCOUNTS.to_vec(),
     }
 }
+
+pub fn migrate_account(program_id: &Pubkey, account: &Pubkey, payer: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*account, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+        ],
+        data: JitoTipDistributionInstruction::MIGRATE_ACCOUNT.to_vec(),
+    }
+}
+
+pub fn add_permitted_uploader(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    merkle_root_upload_config: &Pubkey,
+    authority: &Pubkey,
+    uploader: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*merkle_root_upload_config, false),
+        AccountMeta::new_readonly(*authority, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: [
+            JitoTipDistributionInstruction::ADD_PERMITTED_UPLOADER.as_slice(),
+            uploader.as_ref(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn remove_permitted_uploader(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    merkle_root_upload_config: &Pubkey,
+    authority: &Pubkey,
+    uploader: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*merkle_root_upload_config, false),
+        AccountMeta::new_readonly(*authority, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: [
+            JitoTipDistributionInstruction::REMOVE_PERMITTED_UPLOADER.as_slice(),
+            uploader.as_ref(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn migrate_tda_merkle_root_upload_authorities(
+    program_id: &Pubkey,
+    merkle_root_upload_config: &Pubkey,
+    tip_distribution_accounts: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![AccountMeta::new_readonly(*merkle_root_upload_config, false)];
+    for tip_distribution_account in tip_distribution_accounts {
+        accounts.push(AccountMeta::new(*tip_distribution_account, false));
+    }
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: JitoTipDistributionInstruction::MIGRATE_TDA_MERKLE_ROOT_UPLOAD_AUTHORITIES.to_vec(),
+    }
+}
+
+/// Encodes the Borsh `Config` argument of `Initialize` and `UpdateConfig`.
+fn config_data(
+    discriminator: [u8; 8],
+    authority: &Pubkey,
+    expired_funds_account: &Pubkey,
+    num_epochs_valid: u64,
+    max_validator_commission_bps: u16,
+    bump: u8,
+) -> Vec<u8> {
+    [
+        discriminator.as_slice(),
+        authority.as_ref(),
+        expired_funds_account.as_ref(),
+        &num_epochs_valid.to_le_bytes(),
+        &max_validator_commission_bps.to_le_bytes(),
+        &[bump],
+    ]
+    .concat()
+}
+
+/// Encodes the arguments of `Claim`, `ClaimToken` and `ClaimPriorityFee` like Borsh.
+fn claim_data(discriminator: [u8; 8], bump: u8, amount: u64, proof: &[[u8; 32]]) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    data.push(bump);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&(proof.len() as u32).to_le_bytes());
+    data.extend_from_slice(proof.as_flattened());
+    data
+}
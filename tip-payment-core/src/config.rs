@@ -1,4 +1,7 @@
-use jito_tip_core::transmutable::Transmutable;
+use jito_tip_core::{
+    discriminator::account_discriminator, program_account::ProgramAccount,
+    transmutable::Transmutable, versioned_account::VersionedAccount,
+};
 use pinocchio::pubkey::{find_program_address, Pubkey};
 use shank::ShankAccount;
 
@@ -19,13 +22,39 @@ pub struct Config {
 
     /// Bumps used to derive PDAs
     pub bumps: InitBumps,
+
+    /// The layout version, see [VersionedAccount]
+    pub version: u8,
 }
 
 unsafe impl Transmutable for Config {
-    // header, fields, and InitBumps
+    // header, fields, InitBumps and version
     const LEN: usize = core::mem::size_of::<Self>();
 }
 
+impl ProgramAccount for Config {
+    const DISCRIMINATOR: [u8; 8] = account_discriminator("Config");
+
+    type SeedArgs<'a> = ();
+
+    type Seeds<'a> = &'a [&'a [u8]];
+
+    fn pda_seeds(&self, _args: ()) -> Self::Seeds<'_> {
+        Self::seeds()
+    }
+}
+
+impl VersionedAccount for Config {
+    const VERSION: u8 = 1;
+
+    // The version byte fits in the padding at the end of the version 0 layout.
+    const V0_SPACE: usize = Self::SPACE;
+
+    const VERSION_OFFSET: usize = core::mem::offset_of!(Self, version);
+
+    fn backfill(&mut self, _from_version: u8) {}
+}
+
 impl Config {
     /// Initialize a [`Config`]
     pub fn new(
@@ -38,6 +67,7 @@ impl Config {
             block_builder,
             block_builder_commission_pct,
             bumps: InitBumps::default(),
+            version: Self::VERSION,
         }
     }
 
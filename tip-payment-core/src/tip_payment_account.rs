@@ -1,3 +1,7 @@
+use jito_tip_core::{
+    discriminator::account_discriminator, program_account::ProgramAccount,
+    transmutable::Transmutable,
+};
 use jito_tip_payment_sdk::error::TipPaymentError;
 use pinocchio::{
     account_info::AccountInfo,
@@ -8,15 +12,41 @@ use pinocchio::{
 };
 use pinocchio_system::instructions::CreateAccount;
 
+/// The hardcoded seeds of the eight tip payment accounts.
+pub const TIP_ACCOUNT_SEEDS: [&[u8]; 8] = [
+    b"TIP_ACCOUNT_0",
+    b"TIP_ACCOUNT_1",
+    b"TIP_ACCOUNT_2",
+    b"TIP_ACCOUNT_3",
+    b"TIP_ACCOUNT_4",
+    b"TIP_ACCOUNT_5",
+    b"TIP_ACCOUNT_6",
+    b"TIP_ACCOUNT_7",
+];
+
+/// Holds the tips paid to the validator; the account only stores its discriminator.
 #[derive(Debug, Default, Clone)]
 #[repr(C)]
-pub struct TipPaymentAccount {
-    sample: u8,
+pub struct TipPaymentAccount {}
+
+unsafe impl Transmutable for TipPaymentAccount {
+    const LEN: usize = core::mem::size_of::<Self>();
 }
 
-impl TipPaymentAccount {
-    pub const SIZE: usize = 8;
+impl ProgramAccount for TipPaymentAccount {
+    const DISCRIMINATOR: [u8; 8] = account_discriminator("TipPaymentAccount");
+
+    /// The hardcoded seed of the tip payment account, e.g. `TIP_ACCOUNT_0`.
+    type SeedArgs<'a> = &'a [u8];
+
+    type Seeds<'a> = [&'a [u8]; 1];
 
+    fn pda_seeds<'a>(&'a self, seed: &'a [u8]) -> Self::Seeds<'a> {
+        [seed]
+    }
+}
+
+impl TipPaymentAccount {
     /// Initialize a [`TipPaymentAccount`]
     pub fn initialize(
         seeds: &[u8],
@@ -26,7 +56,7 @@ impl TipPaymentAccount {
         _system_program: &AccountInfo,
         rent: &Rent,
     ) -> Result<u8, ProgramError> {
-        let space = Self::SIZE;
+        let space = Self::SPACE;
 
         // Validate PDA
         let (pubkey, bump) = find_program_address(&[seeds], program_id);
@@ -35,29 +65,11 @@ impl TipPaymentAccount {
         }
 
         // CPI to system program to create account
-        // let current_lamports = account_info.lamports();
         if account_info.lamports().ne(&0) {
             return Err(ProgramError::InvalidAccountData);
         }
 
         let required_lamports = rent.minimum_balance(space);
-        // let cpi_accounts = anchor_lang::system_program::CreateAccount {
-        //     from: payer.to_account_info(),
-        //     to: account_info.to_account_info(),
-        // };
-        // let cpi_context = CpiContext::new(system_program.to_account_info(), cpi_accounts);
-        // anchor_lang::system_program::create_account(
-        //     cpi_context.with_signer(&[&[seeds, &[bump]]]),
-        //     required_lamports,
-        //     space as u64,
-        //     program_id,
-        // )?;
-
-        // // set the discriminator
-        // let mut account_data: std::cell::RefMut<'_, &mut [u8]> =
-        //     account_info.try_borrow_mut_data()?;
-        // account_data[..TipPaymentAccount::DISCRIMINATOR.len()]
-        //     .copy_from_slice(TipPaymentAccount::DISCRIMINATOR);
 
         let bindings = [bump];
         let seeds = [Seed::from(seeds), Seed::from(&bindings)];
@@ -71,6 +83,11 @@ impl TipPaymentAccount {
         }
         .invoke_signed(&signers)?;
 
+        // set the discriminator
+        unsafe {
+            Self::init(program_id, account_info)?;
+        }
+
         Ok(bump)
     }
 
@@ -1,4 +1,5 @@
-use jito_tip_payment_core::{config::Config, load_mut_unchecked};
+use jito_tip_core::program_account::ProgramAccount;
+use jito_tip_payment_core::config::Config;
 use pinocchio::{
     account_info::AccountInfo,
     program_error::ProgramError,
@@ -14,7 +15,7 @@ use crate::handle_payments;
 /// this program. In order for the block builder to be changed, all previous tips must have been
 /// drained.
 pub fn process_change_block_builder(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     block_builder_commission: u64,
 ) -> Result<(), ProgramError> {
@@ -53,7 +54,7 @@ pub fn process_change_block_builder(
         tip_payment_account_7,
     ];
 
-    let config = unsafe { load_mut_unchecked::<Config>(config.borrow_mut_data_unchecked())? };
+    let config = unsafe { Config::load_mut(program_id, config, ())? };
 
     unsafe {
         handle_payments(
@@ -1,4 +1,5 @@
-use jito_tip_payment_core::{config::Config, load_mut_unchecked};
+use jito_tip_core::program_account::ProgramAccount;
+use jito_tip_payment_core::config::Config;
 use pinocchio::{
     account_info::AccountInfo,
     program_error::ProgramError,
@@ -11,7 +12,7 @@ use crate::handle_payments;
 /// Validator should invoke this instruction before executing any transactions that contain tips.
 /// Validator should also ensure it calls it if there's a fork detected.
 pub fn process_change_tip_receiver(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> Result<(), ProgramError> {
     let [config, old_tip_receiver, new_tip_receiver, block_builder, tip_payment_account_0, tip_payment_account_1, tip_payment_account_2, tip_payment_account_3, tip_payment_account_4, tip_payment_account_5, tip_payment_account_6, tip_payment_account_7, _signer] =
@@ -49,7 +50,7 @@ pub fn process_change_tip_receiver(
         tip_payment_account_7,
     ];
 
-    let config = unsafe { load_mut_unchecked::<Config>(config.borrow_mut_data_unchecked())? };
+    let config = unsafe { Config::load_mut(program_id, config, ())? };
 
     unsafe {
         handle_payments(
@@ -1,10 +1,10 @@
 use jito_tip_core::{
     loader::{load_signer, load_system_account, load_system_program},
-    transmutable::Transmutable,
+    program_account::ProgramAccount,
+    versioned_account::VersionedAccount,
 };
 use jito_tip_payment_core::{
-    config::Config, init_bumps::InitBumps, load_mut_unchecked,
-    tip_payment_account::TipPaymentAccount,
+    config::Config, init_bumps::InitBumps, tip_payment_account::TipPaymentAccount,
 };
 use pinocchio::{
     account_info::AccountInfo,
@@ -47,7 +47,7 @@ pub fn process_initialize(
 
     let rent = Rent::get()?;
 
-    let space = Config::LEN;
+    let space = Config::SPACE;
     let required_lamports = rent.minimum_balance(space);
 
     let (_config_pubkey, config_bump) = find_program_address(&[CONFIG_ACCOUNT_SEED], program_id);
@@ -64,7 +64,7 @@ pub fn process_initialize(
     }
     .invoke_signed(&signers)?;
 
-    let config = unsafe { load_mut_unchecked::<Config>(config.borrow_mut_data_unchecked())? };
+    let config = unsafe { Config::init_versioned(program_id, config)? };
     config.tip_receiver = *payer.key();
     config.block_builder = *payer.key();
 
@@ -1,8 +1,12 @@
 use change_block_builder::process_change_block_builder;
 use change_tip_receiver::process_change_tip_receiver;
 use initialize::process_initialize;
-use jito_tip_payment_core::{fees::Fees, tip_payment_account::TipPaymentAccount};
+use jito_tip_payment_core::{
+    fees::Fees,
+    tip_payment_account::{TipPaymentAccount, TIP_ACCOUNT_SEEDS},
+};
 use jito_tip_payment_sdk::{error::TipPaymentError, instruction::JitoTipPaymentInstruction};
+use migrate_account::process_migrate_account;
 use pinocchio::{
     account_info::AccountInfo, entrypoint, msg, program_error::ProgramError, pubkey::Pubkey,
     sysvars::rent::Rent, ProgramResult,
@@ -15,6 +19,7 @@ use solana_sdk_ids::{
 mod change_block_builder;
 mod change_tip_receiver;
 mod initialize;
+mod migrate_account;
 
 entrypoint!(process_instruction);
 // nostd_panic_handler!();
@@ -28,14 +33,14 @@ pinocchio_pubkey::declare_id!("3YsnULkzMZ3pJcN1zX2uSyDRdY1RKKhiC32QvhPoUJ3c");
 /// otherwise the tx would fail since the accounts would have
 /// already been initialized on subsequent calls.
 pub const CONFIG_ACCOUNT_SEED: &[u8] = b"CONFIG_ACCOUNT";
-pub const TIP_ACCOUNT_SEED_0: &[u8] = b"TIP_ACCOUNT_0";
-pub const TIP_ACCOUNT_SEED_1: &[u8] = b"TIP_ACCOUNT_1";
-pub const TIP_ACCOUNT_SEED_2: &[u8] = b"TIP_ACCOUNT_2";
-pub const TIP_ACCOUNT_SEED_3: &[u8] = b"TIP_ACCOUNT_3";
-pub const TIP_ACCOUNT_SEED_4: &[u8] = b"TIP_ACCOUNT_4";
-pub const TIP_ACCOUNT_SEED_5: &[u8] = b"TIP_ACCOUNT_5";
-pub const TIP_ACCOUNT_SEED_6: &[u8] = b"TIP_ACCOUNT_6";
-pub const TIP_ACCOUNT_SEED_7: &[u8] = b"TIP_ACCOUNT_7";
+pub const TIP_ACCOUNT_SEED_0: &[u8] = TIP_ACCOUNT_SEEDS[0];
+pub const TIP_ACCOUNT_SEED_1: &[u8] = TIP_ACCOUNT_SEEDS[1];
+pub const TIP_ACCOUNT_SEED_2: &[u8] = TIP_ACCOUNT_SEEDS[2];
+pub const TIP_ACCOUNT_SEED_3: &[u8] = TIP_ACCOUNT_SEEDS[3];
+pub const TIP_ACCOUNT_SEED_4: &[u8] = TIP_ACCOUNT_SEEDS[4];
+pub const TIP_ACCOUNT_SEED_5: &[u8] = TIP_ACCOUNT_SEEDS[5];
+pub const TIP_ACCOUNT_SEED_6: &[u8] = TIP_ACCOUNT_SEEDS[6];
+pub const TIP_ACCOUNT_SEED_7: &[u8] = TIP_ACCOUNT_SEEDS[7];
 
 #[inline(always)]
 pub fn process_instruction(
@@ -64,6 +69,10 @@ pub fn process_instruction(
             msg!("Instruction: ChangeBlockBuilder");
             process_change_block_builder(program_id, accounts, block_builder_commission)
         }
+        JitoTipPaymentInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
     }
 }
 
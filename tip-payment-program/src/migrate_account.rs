@@ -0,0 +1,73 @@
+use jito_tip_core::{
+    loader::{load_signer, load_system_program},
+    program_account::{ProgramAccount, DISCRIMINATOR_LEN},
+    realloc,
+    transmutable::Transmutable,
+    versioned_account::VersionedAccount,
+};
+use jito_tip_payment_core::config::Config;
+use pinocchio::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+};
+
+/// Migrates the [Config] to the latest layout version, growing it if needed with `payer` funding
+/// the extra rent.
+///
+/// A [Config] created before it was prefixed with its discriminator is [Config::LEN] bytes long
+/// and is first moved behind the discriminator.
+pub fn process_migrate_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let [config, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    if config.data_len() == Config::LEN {
+        unsafe { prefix_discriminator(program_id, config, payer)? };
+    }
+
+    unsafe { Config::migrate(program_id, config, payer) }
+}
+
+/// Grows a [Config] written without a discriminator by [DISCRIMINATOR_LEN] bytes, shifts its
+/// fields behind the discriminator and writes it.
+///
+/// # Safety
+///
+/// The caller must ensure that no other reference to the account data is alive.
+unsafe fn prefix_discriminator(
+    program_id: &Pubkey,
+    config: &AccountInfo,
+    payer: &AccountInfo,
+) -> Result<(), ProgramError> {
+    if config.owner().ne(program_id) {
+        msg!("Account has an invalid owner");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !config.is_writable() {
+        msg!("Account is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let (config_pubkey, _) = Config::find_program_address(program_id);
+    if config.key().ne(&config_pubkey) {
+        msg!("Account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    realloc(config, Config::SPACE, payer, &Rent::get()?)?;
+
+    let data = config.borrow_mut_data_unchecked();
+    data.copy_within(..Config::LEN, DISCRIMINATOR_LEN);
+    data[..DISCRIMINATOR_LEN].copy_from_slice(&Config::DISCRIMINATOR);
+
+    Ok(())
+}
@@ -83,7 +83,7 @@ mod tests {
             blockhash,
         );
 
-        let _tx_resp = context
+        context
             .banks_client
             .send_transaction(transaction)
             .await
@@ -117,7 +117,7 @@ mod tests {
             blockhash,
         );
 
-        let _tx_resp = context
+        context
             .banks_client
             .send_transaction(transaction)
             .await
@@ -140,6 +140,7 @@ mod tests {
             &tip_payment_6_pubkey,
             &tip_payment_7_pubkey,
             &user_kp.pubkey(),
+            5,
         );
 
         let transaction = Transaction::new_signed_with_payer(
@@ -149,7 +150,7 @@ mod tests {
             blockhash,
         );
 
-        let _tx_resp = context
+        context
             .banks_client
             .send_transaction(transaction)
             .await
@@ -83,7 +83,7 @@ mod tests {
             blockhash,
         );
 
-        let _tx_resp = context
+        context
             .banks_client
             .send_transaction(transaction)
             .await
@@ -117,7 +117,7 @@ mod tests {
             blockhash,
         );
 
-        let _tx_resp = context
+        context
             .banks_client
             .send_transaction(transaction)
             .await
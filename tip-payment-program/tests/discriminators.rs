@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::program_account::ProgramAccount;
+    use jito_tip_payment_core::{config::Config, tip_payment_account::TipPaymentAccount};
+    use jito_tip_payment_sdk::{instruction::JitoTipPaymentInstruction, sdk::change_block_builder};
+    use solana_pubkey::Pubkey;
+
+    #[test]
+    fn instruction_discriminators_match_anchor() {
+        assert_eq!(
+            JitoTipPaymentInstruction::INITIALIZE,
+            [175, 175, 109, 31, 13, 152, 155, 237]
+        );
+        assert_eq!(
+            JitoTipPaymentInstruction::CHANGE_TIP_RECEIVER,
+            [69, 99, 22, 71, 11, 231, 86, 143]
+        );
+        assert_eq!(
+            JitoTipPaymentInstruction::CHANGE_BLOCK_BUILDER,
+            [134, 80, 38, 137, 165, 21, 114, 123]
+        );
+    }
+
+    #[test]
+    fn account_discriminators_match_anchor() {
+        assert_eq!(
+            Config::DISCRIMINATOR,
+            [155, 12, 170, 224, 30, 250, 204, 130]
+        );
+        assert_eq!(
+            TipPaymentAccount::DISCRIMINATOR,
+            [201, 33, 244, 116, 224, 68, 97, 40]
+        );
+    }
+
+    #[test]
+    fn change_block_builder_round_trips() {
+        let key = Pubkey::new_unique();
+        let ix = change_block_builder(
+            &key, &key, &key, &key, &key, &key, &key, &key, &key, &key, &key, &key, &key, &key, 5,
+        );
+
+        assert_eq!(
+            JitoTipPaymentInstruction::try_from_slice(&ix.data),
+            Ok(JitoTipPaymentInstruction::ChangeBlockBuilder {
+                block_builder_commission: 5
+            })
+        );
+    }
+}
@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod tests {
+    use jito_tip_core::{program_account::DISCRIMINATOR_LEN, versioned_account::VersionedAccount};
+    use jito_tip_payment_core::config::Config;
     use jito_tip_payment_program::{
         CONFIG_ACCOUNT_SEED, TIP_ACCOUNT_SEED_0, TIP_ACCOUNT_SEED_1, TIP_ACCOUNT_SEED_2,
         TIP_ACCOUNT_SEED_3, TIP_ACCOUNT_SEED_4, TIP_ACCOUNT_SEED_5, TIP_ACCOUNT_SEED_6,
@@ -83,10 +85,23 @@ mod tests {
             blockhash,
         );
 
-        let _tx_resp = context
+        context
             .banks_client
-            .send_transaction(transaction)
+            .process_transaction(transaction)
             .await
             .unwrap();
+
+        let data = context
+            .banks_client
+            .get_account(config_pubkey)
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        let config = unsafe {
+            core::ptr::read_unaligned(data[DISCRIMINATOR_LEN..].as_ptr() as *const Config)
+        };
+        assert_eq!(config.tip_receiver, user_kp.pubkey().to_bytes());
+        assert_eq!(config.version, Config::VERSION);
     }
 }
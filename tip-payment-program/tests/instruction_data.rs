@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_payment_sdk::{instruction::JitoTipPaymentInstruction, sdk::change_block_builder};
+    use pinocchio::program_error::ProgramError;
+    use solana_pubkey::Pubkey;
+
+    #[test]
+    fn change_block_builder_commission_is_little_endian() {
+        // Borsh, like the Anchor program and its clients, encodes the commission little-endian.
+        let data = [
+            JitoTipPaymentInstruction::CHANGE_BLOCK_BUILDER.as_slice(),
+            &[5, 0, 0, 0, 0, 0, 0, 0],
+        ]
+        .concat();
+        assert_eq!(
+            JitoTipPaymentInstruction::try_from_slice(&data),
+            Ok(JitoTipPaymentInstruction::ChangeBlockBuilder {
+                block_builder_commission: 5
+            })
+        );
+
+        let key = Pubkey::new_unique();
+        let ix = change_block_builder(
+            &key, &key, &key, &key, &key, &key, &key, &key, &key, &key, &key, &key, &key, &key,
+            0x0102,
+        );
+        assert_eq!(&ix.data[8..], &[2, 1, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn change_block_builder_requires_the_commission() {
+        assert_eq!(
+            JitoTipPaymentInstruction::try_from_slice(
+                &JitoTipPaymentInstruction::CHANGE_BLOCK_BUILDER
+            ),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_core::{
+        program_account::{ProgramAccount, DISCRIMINATOR_LEN},
+        transmutable::Transmutable,
+        versioned_account::VersionedAccount,
+    };
+    use jito_tip_payment_core::config::Config;
+    use jito_tip_payment_program::CONFIG_ACCOUNT_SEED;
+    use jito_tip_payment_sdk::sdk::migrate_account;
+    use solana_account::Account;
+    use solana_native_token::sol_to_lamports;
+    use solana_program_test::ProgramTest;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    #[tokio::test]
+    async fn migrate_account_upgrades_v0_config() {
+        let program_id = Pubkey::new_from_array(jito_tip_payment_program::id());
+        let mut program_test = ProgramTest::new("jito_tip_payment_program", program_id, None);
+
+        // A version 0 config, without the version byte.
+        let tip_receiver = Pubkey::new_unique();
+        let mut config = Config::new(tip_receiver.to_bytes(), tip_receiver.to_bytes(), 5);
+        config.version = 0;
+        let mut data = vec![0; Config::SPACE];
+        data[..DISCRIMINATOR_LEN].copy_from_slice(&Config::DISCRIMINATOR);
+        unsafe {
+            core::ptr::write_unaligned(
+                data[DISCRIMINATOR_LEN..].as_mut_ptr() as *mut Config,
+                config,
+            );
+        }
+        let (config_pubkey, _) = Pubkey::find_program_address(&[CONFIG_ACCOUNT_SEED], &program_id);
+        program_test.add_account(
+            config_pubkey,
+            Account {
+                lamports: sol_to_lamports(0.01),
+                data,
+                owner: program_id,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        let mut context = program_test.start_with_context().await;
+
+        for _ in 0..2 {
+            let blockhash = context.get_new_latest_blockhash().await.unwrap();
+            context
+                .banks_client
+                .process_transaction(Transaction::new_signed_with_payer(
+                    &[migrate_account(
+                        &program_id,
+                        &config_pubkey,
+                        &context.payer.pubkey(),
+                    )],
+                    Some(&context.payer.pubkey()),
+                    &[&context.payer],
+                    blockhash,
+                ))
+                .await
+                .unwrap();
+
+            let data = context
+                .banks_client
+                .get_account(config_pubkey)
+                .await
+                .unwrap()
+                .unwrap()
+                .data;
+            assert_eq!(data.len(), Config::SPACE);
+            assert_eq!(data[Config::VERSION_INDEX], Config::VERSION);
+
+            let config = unsafe {
+                core::ptr::read_unaligned(data[DISCRIMINATOR_LEN..].as_ptr() as *const Config)
+            };
+            assert_eq!(config.tip_receiver, tip_receiver.to_bytes());
+            assert_eq!(config.block_builder_commission_pct, 5);
+        }
+    }
+
+    #[tokio::test]
+    async fn migrate_account_prefixes_legacy_config_with_discriminator() {
+        let program_id = Pubkey::new_from_array(jito_tip_payment_program::id());
+        let mut program_test = ProgramTest::new("jito_tip_payment_program", program_id, None);
+
+        // A config written before the discriminator was added, at offset 0.
+        let tip_receiver = Pubkey::new_unique();
+        let block_builder = Pubkey::new_unique();
+        let mut config = Config::new(tip_receiver.to_bytes(), block_builder.to_bytes(), 5);
+        config.version = 0;
+        let mut data = vec![0; Config::LEN];
+        unsafe {
+            core::ptr::write_unaligned(data.as_mut_ptr() as *mut Config, config);
+        }
+        let (config_pubkey, _) = Pubkey::find_program_address(&[CONFIG_ACCOUNT_SEED], &program_id);
+        program_test.add_account(
+            config_pubkey,
+            Account {
+                lamports: sol_to_lamports(0.01),
+                data,
+                owner: program_id,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        let mut context = program_test.start_with_context().await;
+
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+        context
+            .banks_client
+            .process_transaction(Transaction::new_signed_with_payer(
+                &[migrate_account(
+                    &program_id,
+                    &config_pubkey,
+                    &context.payer.pubkey(),
+                )],
+                Some(&context.payer.pubkey()),
+                &[&context.payer],
+                blockhash,
+            ))
+            .await
+            .unwrap();
+
+        let data = context
+            .banks_client
+            .get_account(config_pubkey)
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        assert_eq!(data.len(), Config::SPACE);
+        assert_eq!(data[..DISCRIMINATOR_LEN], Config::DISCRIMINATOR);
+        assert_eq!(data[Config::VERSION_INDEX], Config::VERSION);
+
+        let config = unsafe {
+            core::ptr::read_unaligned(data[DISCRIMINATOR_LEN..].as_ptr() as *const Config)
+        };
+        assert_eq!(config.tip_receiver, tip_receiver.to_bytes());
+        assert_eq!(config.block_builder, block_builder.to_bytes());
+        assert_eq!(config.block_builder_commission_pct, 5);
+    }
+}
@@ -21,3 +21,26 @@ impl From<TipPaymentError> for ProgramError {
         Self::Custom(value as u32)
     }
 }
+
+impl TipPaymentError {
+    /// Every error, indexed by its `ProgramError::Custom` code.
+    pub const ALL: [Self; 4] = [
+        Self::ArithmeticError,
+        Self::InvalidFee,
+        Self::InvalidTipReceiver,
+        Self::InvalidBlockBuilder,
+    ];
+}
+
+impl TryFrom<u32> for TipPaymentError {
+    type Error = u32;
+
+    /// Maps a `ProgramError::Custom` code back to the error, handing back unknown codes.
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        usize::try_from(code)
+            .ok()
+            .and_then(|index| Self::ALL.get(index))
+            .cloned()
+            .ok_or(code)
+    }
+}
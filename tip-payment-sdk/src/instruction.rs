@@ -1,3 +1,4 @@
+use jito_tip_core::discriminator::instruction_discriminator;
 use pinocchio::program_error::ProgramError;
 use shank::ShankInstruction;
 
@@ -49,42 +50,47 @@ pub enum JitoTipPaymentInstruction {
     #[account(11, writable, name = "tip_payment_account_7")]
     #[account(12, writable, signer, name = "signer")]
     ChangeBlockBuilder { block_builder_commission: u64 },
+
+    /// Migrate the config to the latest layout version
+    #[account(0, writable, name = "config")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program")]
+    MigrateAccount,
 }
 
 impl JitoTipPaymentInstruction {
-    pub fn try_from_slice(instruction_data: &[u8]) -> Result<Self, ProgramError> {
-        let [discriminator0, discriminator1, discriminator2, discriminator3, discriminator4, discriminator5, discriminator6, discriminator7, remaining @ ..] =
-            instruction_data
-        else {
+    pub const INITIALIZE: [u8; 8] = instruction_discriminator("initialize");
+    pub const CHANGE_TIP_RECEIVER: [u8; 8] = instruction_discriminator("change_tip_receiver");
+    pub const CHANGE_BLOCK_BUILDER: [u8; 8] = instruction_discriminator("change_block_builder");
+    pub const MIGRATE_ACCOUNT: [u8; 8] = instruction_discriminator("migrate_account");
+
+    pub const fn try_from_slice(instruction_data: &[u8]) -> Result<Self, ProgramError> {
+        let Some((discriminator, remaining)) = instruction_data.split_first_chunk::<8>() else {
             return Err(ProgramError::InvalidInstructionData);
         };
 
-        match [
-            discriminator0,
-            discriminator1,
-            discriminator2,
-            discriminator3,
-            discriminator4,
-            discriminator5,
-            discriminator6,
-            discriminator7,
-        ] {
+        match *discriminator {
             // Initialize
-            [175, 175, 109, 31, 13, 152, 155, 237] => Ok(Self::Initialize),
+            Self::INITIALIZE => Ok(Self::Initialize),
 
             // ChangeTipReceiver
-            [69, 99, 22, 71, 11, 231, 86, 143] => Ok(Self::ChangeTipReceiver),
+            Self::CHANGE_TIP_RECEIVER => Ok(Self::ChangeTipReceiver),
 
             // ChangeBlockBuilder
-            [134, 80, 38, 137, 165, 21, 114, 123] => {
-                let mut slice = [0; 8];
-                slice.copy_from_slice(remaining);
-                let block_builder_commission = u64::from_be_bytes(slice);
+            Self::CHANGE_BLOCK_BUILDER => {
+                let Some(block_builder_commission) = remaining.first_chunk::<8>() else {
+                    return Err(ProgramError::InvalidInstructionData);
+                };
+                let block_builder_commission = u64::from_le_bytes(*block_builder_commission);
 
                 Ok(Self::ChangeBlockBuilder {
                     block_builder_commission,
                 })
             }
+
+            // MigrateAccount
+            Self::MIGRATE_ACCOUNT => Ok(Self::MigrateAccount),
+
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
@@ -1,6 +1,8 @@
 use solana_instruction::{AccountMeta, Instruction};
 use solana_pubkey::Pubkey;
 
+use crate::instruction::JitoTipPaymentInstruction;
+
 #[allow(clippy::too_many_arguments)]
 pub fn initialize_config(
     program_id: &Pubkey,
@@ -25,13 +27,13 @@ pub fn initialize_config(
         AccountMeta::new(*tip_payment_account_5, false),
         AccountMeta::new(*tip_payment_account_6, false),
         AccountMeta::new(*tip_payment_account_7, false),
-        AccountMeta::new(*payer, true),
         AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+        AccountMeta::new(*payer, true),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: vec![175, 175, 109, 31, 13, 152, 155, 237],
+        data: JitoTipPaymentInstruction::INITIALIZE.to_vec(),
     }
 }
 
@@ -70,7 +72,7 @@ pub fn change_tip_receiver(
     Instruction {
         program_id: *program_id,
         accounts,
-        data: vec![69, 99, 22, 71, 11, 231, 86, 143],
+        data: JitoTipPaymentInstruction::CHANGE_TIP_RECEIVER.to_vec(),
     }
 }
 
@@ -90,6 +92,7 @@ pub fn change_block_builder(
     tip_payment_account_6: &Pubkey,
     tip_payment_account_7: &Pubkey,
     signer: &Pubkey,
+    block_builder_commission: u64,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*config, false),
@@ -109,6 +112,23 @@ pub fn change_block_builder(
     Instruction {
         program_id: *program_id,
         accounts,
-        data: vec![69, 99, 22, 71, 11, 231, 86, 143],
+        data: [
+            JitoTipPaymentInstruction::CHANGE_BLOCK_BUILDER.as_slice(),
+            &block_builder_commission.to_le_bytes(),
+        ]
+        .concat(),
+    }
+}
+
+pub fn migrate_account(program_id: &Pubkey, config: &Pubkey, payer: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: JitoTipPaymentInstruction::MIGRATE_ACCOUNT.to_vec(),
     }
 }
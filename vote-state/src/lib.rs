@@ -265,6 +265,61 @@ impl VoteState {
     }
 }
 
+/// The keys that control a vote account, read from the start of its serialized
+/// [VoteStateVersions] without deserializing the rest.
+pub struct VoteAuthorities {
+    /// the node that votes in this account
+    pub node_pubkey: Pubkey,
+
+    /// the signer for withdrawals, `None` for [VoteState0_23_5] where it follows the prior voters
+    pub authorized_withdrawer: Option<Pubkey>,
+}
+
+impl VoteAuthorities {
+    /// Offset of `node_pubkey`, after the `u32` [VoteStateVersions] tag.
+    const NODE_PUBKEY_OFFSET: usize = 4;
+
+    /// Offset of `authorized_withdrawer` in every version after [VoteState0_23_5].
+    const AUTHORIZED_WITHDRAWER_OFFSET: usize = Self::NODE_PUBKEY_OFFSET + 32;
+
+    /// Reads the authorities of a vote account.
+    pub fn load(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        if account_info.owner() != &(solana_program::vote::program::id().to_bytes() as Pubkey) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data = account_info.try_borrow_data()?;
+        let read_pubkey = |offset: usize| -> Result<Pubkey, ProgramError> {
+            offset
+                .checked_add(32)
+                .and_then(|end| data.get(offset..end))
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or(ProgramError::InvalidAccountData)
+        };
+
+        let version = data
+            .get(..Self::NODE_PUBKEY_OFFSET)
+            .map(|tag| u32::from_le_bytes([tag[0], tag[1], tag[2], tag[3]]))
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let authorized_withdrawer = match version {
+            0 => None,
+            // V1_14_11, Current and the V4 layout that follows it.
+            1..=3 => Some(read_pubkey(Self::AUTHORIZED_WITHDRAWER_OFFSET)?),
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        Ok(Self {
+            node_pubkey: read_pubkey(Self::NODE_PUBKEY_OFFSET)?,
+            authorized_withdrawer,
+        })
+    }
+
+    /// Returns `true` if `key` is the node identity or the withdrawer of the vote account.
+    pub fn is_authority(&self, key: &Pubkey) -> bool {
+        self.node_pubkey.eq(key) || self.authorized_withdrawer.as_ref() == Some(key)
+    }
+}
+
 /// Return a `T` reference from the given bytes.
 ///
 /// This function does not check if the data is initialized.